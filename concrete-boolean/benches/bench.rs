@@ -1,8 +1,9 @@
+use concrete_boolean::ciphertext::Ciphertext;
 use concrete_boolean::client_key::ClientKey;
 use concrete_boolean::parameters::{BooleanParameters, DEFAULT_PARAMETERS, TFHE_LIB_PARAMETERS};
 use concrete_boolean::prelude::BinaryBooleanGates;
 use concrete_boolean::server_key::ServerKey;
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 
 criterion_group!(
     gates_benches,
@@ -10,7 +11,16 @@ criterion_group!(
     bench_tfhe_lib_parameters
 );
 
-criterion_main!(gates_benches);
+criterion_group!(
+    name = batched_gates_benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_batched_default_parameters, bench_batched_tfhe_lib_parameters
+);
+
+criterion_main!(gates_benches, batched_gates_benches);
+
+/// Number of independent gates evaluated in one batch
+const BATCH_SIZE: usize = 1 << 10;
 
 // Put all `bench_function` in one place
 // so the keygen is only run once per parameters saving time.
@@ -42,6 +52,12 @@ fn bench_gates(c: &mut Criterion, params: BooleanParameters, parameter_name: &st
 
     let id = format!("MUX gate {}", parameter_name);
     c.bench_function(&id, |b| b.iter(|| black_box(sks.mux(&ct1, &ct2, &ct3))));
+
+    let id = format!("MAJ3 gate {}", parameter_name);
+    c.bench_function(&id, |b| b.iter(|| black_box(sks.maj3(&ct1, &ct2, &ct3))));
+
+    let id = format!("XOR3 gate {}", parameter_name);
+    c.bench_function(&id, |b| b.iter(|| black_box(sks.xor3(&ct1, &ct2, &ct3))));
 }
 
 #[cfg(not(feature = "cuda"))]
@@ -58,3 +74,48 @@ fn bench_default_parameters(_: &mut Criterion) {
 fn bench_tfhe_lib_parameters(c: &mut Criterion) {
     bench_gates(c, TFHE_LIB_PARAMETERS, "TFHE_LIB_PARAMETERS");
 }
+
+fn bench_batched_gates(c: &mut Criterion, params: BooleanParameters, parameter_name: &str) {
+    let cks = ClientKey::new(&params);
+    let sks = ServerKey::new(&cks);
+
+    let cts: Vec<Ciphertext> = (0..BATCH_SIZE).map(|i| cks.encrypt(i % 2 == 0)).collect();
+    let pairs: Vec<(&Ciphertext, &Ciphertext)> = cts.iter().zip(cts.iter().rev()).collect();
+    let triplets: Vec<(&Ciphertext, &Ciphertext, &Ciphertext)> = cts
+        .iter()
+        .zip(cts.iter().rev())
+        .zip(cts.iter().skip(1).chain(cts.iter().take(1)))
+        .map(|((ct1, ct2), ct3)| (ct1, ct2, ct3))
+        .collect();
+
+    let mut group = c.benchmark_group(format!("batched gates {}", parameter_name));
+    group.throughput(Throughput::Elements(BATCH_SIZE as u64));
+
+    group.bench_function("AND gate", |b| b.iter(|| black_box(sks.and_many(&pairs))));
+
+    group.bench_function("XOR gate", |b| b.iter(|| black_box(sks.xor_many(&pairs))));
+
+    group.bench_function("MUX gate", |b| {
+        b.iter(|| black_box(sks.mux_many(&triplets)))
+    });
+
+    group.bench_function("MAJ3 gate", |b| {
+        b.iter(|| black_box(sks.maj3_many(&triplets)))
+    });
+
+    group.finish();
+}
+
+#[cfg(not(feature = "cuda"))]
+fn bench_batched_default_parameters(c: &mut Criterion) {
+    bench_batched_gates(c, DEFAULT_PARAMETERS, "DEFAULT_PARAMETERS");
+}
+
+#[cfg(feature = "cuda")]
+fn bench_batched_default_parameters(_: &mut Criterion) {
+    println!("DEFAULT_PARAMETERS not benched as they are not compatible with the cuda feature.");
+}
+
+fn bench_batched_tfhe_lib_parameters(c: &mut Criterion) {
+    bench_batched_gates(c, TFHE_LIB_PARAMETERS, "TFHE_LIB_PARAMETERS");
+}
@@ -0,0 +1,283 @@
+//! Encrypted bit vectors.
+//!
+//! This module implements the [`FheBits`] type, a vector of encrypted bits representing an
+//! unsigned integer, together with the usual integer operations (addition, subtraction,
+//! comparisons, selection and shifts) built on top of the Boolean gates of the [`ServerKey`].
+//!
+//! Trivially encrypted bits are handled by the gates shortcuts, so mixing encrypted and clear
+//! bits only costs bootstraps for the encrypted ones.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(not(feature = "cuda"))]
+//! # fn main() {
+//! use concrete_boolean::prelude::*;
+//!
+//! let (cks, sks) = gen_keys();
+//!
+//! let a = cks.encrypt_bits(13, 8);
+//! let b = cks.encrypt_bits(29, 8);
+//!
+//! let sum = sks.add(&a, &b);
+//! let is_lower = sks.lt(&a, &b);
+//!
+//! assert_eq!(cks.decrypt_bits(&sum), 42);
+//! assert_eq!(cks.decrypt(&is_lower), true);
+//! # }
+//! # #[cfg(feature = "cuda")]
+//! # fn main() {}
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use crate::ciphertext::Ciphertext;
+use crate::client_key::ClientKey;
+use crate::server_key::{BinaryBooleanGates, ServerKey};
+
+/// A vector of encrypted bits, the least significant bit first.
+#[derive(Clone, Debug)]
+pub struct FheBits {
+    bits: Vec<Ciphertext>,
+}
+
+impl FheBits {
+    /// Creates a bit vector, the least significant bit being the first one.
+    pub fn new(bits: Vec<Ciphertext>) -> Self {
+        Self { bits }
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    /// Returns `true` if the vector contains no bits.
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    /// Returns the bits, the least significant bit first.
+    pub fn bits(&self) -> &[Ciphertext] {
+        &self.bits
+    }
+
+    /// Consumes the vector and returns its bits, the least significant bit first.
+    pub fn into_bits(self) -> Vec<Ciphertext> {
+        self.bits
+    }
+}
+
+impl From<Vec<Ciphertext>> for FheBits {
+    fn from(bits: Vec<Ciphertext>) -> Self {
+        Self::new(bits)
+    }
+}
+
+impl ClientKey {
+    /// Encrypts the `num_bits` least significant bits of `value`.
+    pub fn encrypt_bits(&self, value: u64, num_bits: usize) -> FheBits {
+        assert!(num_bits <= 64, "At most 64 bits can be encrypted");
+        FheBits::new(
+            (0..num_bits)
+                .map(|i| self.encrypt((value >> i) & 1 == 1))
+                .collect(),
+        )
+    }
+
+    /// Decrypts a bit vector of at most 64 bits.
+    pub fn decrypt_bits(&self, ct: &FheBits) -> u64 {
+        assert!(ct.len() <= 64, "At most 64 bits can be decrypted");
+        ct.bits
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, bit)| acc | ((self.decrypt(bit) as u64) << i))
+    }
+}
+
+impl ServerKey {
+    /// Trivially encrypts the `num_bits` least significant bits of `value`.
+    pub fn trivial_encrypt_bits(&self, value: u64, num_bits: usize) -> FheBits {
+        assert!(num_bits <= 64, "At most 64 bits can be encrypted");
+        FheBits::new(
+            (0..num_bits)
+                .map(|i| self.trivial_encrypt((value >> i) & 1 == 1))
+                .collect(),
+        )
+    }
+
+    /// Computes the wrapping addition of two bit vectors of the same length.
+    ///
+    /// Each bit costs one bootstrap for the sum and one for the carry.
+    pub fn add(&self, ct_left: &FheBits, ct_right: &FheBits) -> FheBits {
+        assert_eq!(
+            ct_left.len(),
+            ct_right.len(),
+            "Bit vectors must have the same length"
+        );
+        self.add_with_carry(ct_left, ct_right, self.trivial_encrypt(false))
+            .0
+    }
+
+    /// Computes the wrapping subtraction of two bit vectors of the same length.
+    pub fn sub(&self, ct_left: &FheBits, ct_right: &FheBits) -> FheBits {
+        assert_eq!(
+            ct_left.len(),
+            ct_right.len(),
+            "Bit vectors must have the same length"
+        );
+        // a - b = a + NOT(b) + 1, the negation is free
+        let ct_not_right = self.bitwise_not(ct_right);
+        self.add_with_carry(ct_left, &ct_not_right, self.trivial_encrypt(true))
+            .0
+    }
+
+    /// Computes the equality of two bit vectors of the same length.
+    pub fn eq(&self, ct_left: &FheBits, ct_right: &FheBits) -> Ciphertext {
+        assert_eq!(
+            ct_left.len(),
+            ct_right.len(),
+            "Bit vectors must have the same length"
+        );
+        let pairs: Vec<_> = ct_left.bits.iter().zip(ct_right.bits.iter()).collect();
+        let ct_equal_bits = self.xnor_many(&pairs);
+        self.and_reduce(ct_equal_bits)
+    }
+
+    /// Computes the unsigned comparison `ct_left < ct_right` of two bit vectors of the same
+    /// length.
+    pub fn lt(&self, ct_left: &FheBits, ct_right: &FheBits) -> Ciphertext {
+        assert_eq!(
+            ct_left.len(),
+            ct_right.len(),
+            "Bit vectors must have the same length"
+        );
+        // a < b iff computing a + NOT(b) + 1 does not produce a carry,
+        // only the carries are needed so each bit costs a single bootstrap
+        let mut ct_carry = self.trivial_encrypt(true);
+        for (ct_left_bit, ct_right_bit) in ct_left.bits.iter().zip(ct_right.bits.iter()) {
+            let ct_not_right_bit = self.not(ct_right_bit);
+            ct_carry = self.maj3(ct_left_bit, &ct_not_right_bit, &ct_carry);
+        }
+        self.not(&ct_carry)
+    }
+
+    /// Selects `ct_then` if `ct_condition` is true and `ct_else` otherwise.
+    pub fn select(
+        &self,
+        ct_condition: &Ciphertext,
+        ct_then: &FheBits,
+        ct_else: &FheBits,
+    ) -> FheBits {
+        assert_eq!(
+            ct_then.len(),
+            ct_else.len(),
+            "Bit vectors must have the same length"
+        );
+        let triplets: Vec<_> = ct_then
+            .bits
+            .iter()
+            .zip(ct_else.bits.iter())
+            .map(|(ct_then_bit, ct_else_bit)| (ct_condition, ct_then_bit, ct_else_bit))
+            .collect();
+        FheBits::new(self.mux_many(&triplets))
+    }
+
+    /// Shifts a bit vector to the left by an encrypted amount.
+    ///
+    /// The amount is an unsigned integer, shifting by the length of the vector or more returns
+    /// zero. The shift is done by a barrel shifter: one stage of multiplexers per bit of the
+    /// amount.
+    pub fn shift_left_by_encrypted(&self, ct: &FheBits, ct_amount: &FheBits) -> FheBits {
+        let len = ct.len();
+
+        // Number of bits of the amount handled by a multiplexer stage, any higher bit shifts
+        // every bit out
+        let num_stages = usize::BITS as usize - len.leading_zeros() as usize;
+        let num_stages = num_stages.min(ct_amount.len());
+
+        let mut ct_result = ct.clone();
+        for (stage, ct_amount_bit) in ct_amount.bits[..num_stages].iter().enumerate() {
+            let shift = 1 << stage;
+            let ct_shifted = self.shift_left(&ct_result, shift);
+            ct_result = self.select(ct_amount_bit, &ct_shifted, &ct_result);
+        }
+
+        // Any remaining bit of the amount shifts every bit out
+        if ct_amount.len() > num_stages {
+            let ct_overflow = self.or_reduce(ct_amount.bits[num_stages..].to_vec());
+            let ct_zero = self.trivial_encrypt_bits(0, len);
+            ct_result = self.select(&ct_overflow, &ct_zero, &ct_result);
+        }
+
+        ct_result
+    }
+
+    /// Shifts a bit vector to the left by a clear amount, the bits shifted in are trivial zeros.
+    pub fn shift_left(&self, ct: &FheBits, shift: usize) -> FheBits {
+        let len = ct.len();
+        let shift = shift.min(len);
+        FheBits::new(
+            (0..shift)
+                .map(|_| self.trivial_encrypt(false))
+                .chain(ct.bits[..len - shift].iter().cloned())
+                .collect(),
+        )
+    }
+
+    /// Computes the bitwise negation of a bit vector, without any bootstrap.
+    pub fn bitwise_not(&self, ct: &FheBits) -> FheBits {
+        FheBits::new(ct.bits.iter().map(|bit| self.not(bit)).collect())
+    }
+
+    /// Ripple carry addition, returning the sum and the output carry.
+    fn add_with_carry(
+        &self,
+        ct_left: &FheBits,
+        ct_right: &FheBits,
+        ct_carry: Ciphertext,
+    ) -> (FheBits, Ciphertext) {
+        let mut ct_carry = ct_carry;
+        let mut bits = Vec::with_capacity(ct_left.len());
+        for (ct_left_bit, ct_right_bit) in ct_left.bits.iter().zip(ct_right.bits.iter()) {
+            bits.push(self.xor3(ct_left_bit, ct_right_bit, &ct_carry));
+            ct_carry = self.maj3(ct_left_bit, ct_right_bit, &ct_carry);
+        }
+        (FheBits::new(bits), ct_carry)
+    }
+
+    /// AND of all the ciphertexts, computed as a balanced tree.
+    fn and_reduce(&self, cts: Vec<Ciphertext>) -> Ciphertext {
+        let mut cts = cts;
+        while cts.len() > 1 {
+            let pairs: Vec<_> = cts
+                .chunks_exact(2)
+                .map(|pair| (&pair[0], &pair[1]))
+                .collect();
+            let mut next = self.and_many(&pairs);
+            if cts.len() % 2 == 1 {
+                next.push(cts.pop().unwrap());
+            }
+            cts = next;
+        }
+        cts.pop().unwrap_or_else(|| self.trivial_encrypt(true))
+    }
+
+    /// OR of all the ciphertexts, computed as a balanced tree.
+    fn or_reduce(&self, cts: Vec<Ciphertext>) -> Ciphertext {
+        let mut cts = cts;
+        while cts.len() > 1 {
+            let pairs: Vec<_> = cts
+                .chunks_exact(2)
+                .map(|pair| (&pair[0], &pair[1]))
+                .collect();
+            let mut next = self.or_many(&pairs);
+            if cts.len() % 2 == 1 {
+                next.push(cts.pop().unwrap());
+            }
+            cts = next;
+        }
+        cts.pop().unwrap_or_else(|| self.trivial_encrypt(false))
+    }
+}
@@ -0,0 +1,179 @@
+use crate::bitvec::FheBits;
+use crate::client_key::ClientKey;
+use crate::parameters::BooleanParameters;
+use crate::server_key::ServerKey;
+use crate::{random_boolean, random_integer};
+
+/// Number of assert in randomized tests
+const NB_TEST: usize = 2;
+
+/// Number of bits of the tested integers
+const NB_BITS: usize = 32;
+
+/// Number of bits of the shift amounts, large enough to shift every bit out
+const NB_BITS_AMOUNT: usize = 6;
+
+#[cfg(not(feature = "cuda"))]
+mod default_parameters_tests {
+    use super::*;
+    use crate::parameters::DEFAULT_PARAMETERS;
+
+    #[test]
+    fn test_add_sub_default_parameters() {
+        test_add_sub(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_comparisons_default_parameters() {
+        test_comparisons(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_select_default_parameters() {
+        test_select(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_shift_left_by_encrypted_default_parameters() {
+        test_shift_left_by_encrypted(DEFAULT_PARAMETERS);
+    }
+}
+
+mod tfhe_lib_parameters_tests {
+    use super::*;
+    use crate::parameters::TFHE_LIB_PARAMETERS;
+
+    #[test]
+    fn test_add_sub_tfhe_lib_parameters() {
+        test_add_sub(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_comparisons_tfhe_lib_parameters() {
+        test_comparisons(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_select_tfhe_lib_parameters() {
+        test_select(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_shift_left_by_encrypted_tfhe_lib_parameters() {
+        test_shift_left_by_encrypted(TFHE_LIB_PARAMETERS);
+    }
+}
+
+/// This function randomly either encrypts the value or trivially encrypts it
+fn random_enum_encryption_bits(cks: &ClientKey, sks: &ServerKey, value: u32) -> FheBits {
+    if random_boolean() {
+        cks.encrypt_bits(value as u64, NB_BITS)
+    } else {
+        sks.trivial_encrypt_bits(value as u64, NB_BITS)
+    }
+}
+
+fn test_add_sub(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        let clear_1 = random_integer();
+        let clear_2 = random_integer();
+
+        let ct_1 = random_enum_encryption_bits(&cks, &sks, clear_1);
+        let ct_2 = cks.encrypt_bits(clear_2 as u64, NB_BITS);
+
+        let ct_res = sks.add(&ct_1, &ct_2);
+        assert_eq!(ct_res.len(), NB_BITS);
+        assert_eq!(
+            clear_1.wrapping_add(clear_2) as u64,
+            cks.decrypt_bits(&ct_res)
+        );
+
+        let ct_res = sks.sub(&ct_1, &ct_2);
+        assert_eq!(
+            clear_1.wrapping_sub(clear_2) as u64,
+            cks.decrypt_bits(&ct_res)
+        );
+    }
+}
+
+fn test_comparisons(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        let clear_1 = random_integer();
+        // make equal values likely enough
+        let clear_2 = if random_boolean() {
+            clear_1
+        } else {
+            random_integer()
+        };
+
+        let ct_1 = random_enum_encryption_bits(&cks, &sks, clear_1);
+        let ct_2 = cks.encrypt_bits(clear_2 as u64, NB_BITS);
+
+        let ct_res = sks.eq(&ct_1, &ct_2);
+        assert_eq!(clear_1 == clear_2, cks.decrypt(&ct_res));
+
+        let ct_res = sks.lt(&ct_1, &ct_2);
+        assert_eq!(clear_1 < clear_2, cks.decrypt(&ct_res));
+
+        let ct_res = sks.lt(&ct_2, &ct_1);
+        assert_eq!(clear_2 < clear_1, cks.decrypt(&ct_res));
+    }
+}
+
+fn test_select(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        let clear_1 = random_integer();
+        let clear_2 = random_integer();
+        let condition = random_boolean();
+
+        let ct_1 = random_enum_encryption_bits(&cks, &sks, clear_1);
+        let ct_2 = random_enum_encryption_bits(&cks, &sks, clear_2);
+        let ct_condition = cks.encrypt(condition);
+
+        let ct_res = sks.select(&ct_condition, &ct_1, &ct_2);
+        let expected = if condition { clear_1 } else { clear_2 };
+        assert_eq!(expected as u64, cks.decrypt_bits(&ct_res));
+    }
+}
+
+fn test_shift_left_by_encrypted(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        let clear = random_integer();
+        let amount = random_integer() % (1 << NB_BITS_AMOUNT);
+
+        let ct = cks.encrypt_bits(clear as u64, NB_BITS);
+        let ct_amount = cks.encrypt_bits(amount as u64, NB_BITS_AMOUNT);
+
+        let ct_res = sks.shift_left_by_encrypted(&ct, &ct_amount);
+        let expected = clear.checked_shl(amount).unwrap_or(0);
+        assert_eq!(expected as u64, cks.decrypt_bits(&ct_res));
+    }
+}
+
+#[test]
+fn test_shift_left_clear() {
+    let cks = ClientKey::new(&crate::parameters::TFHE_LIB_PARAMETERS);
+    let sks = ServerKey::new(&cks);
+
+    let ct = sks.trivial_encrypt_bits(0b1011, 8);
+    assert_eq!(cks.decrypt_bits(&sks.shift_left(&ct, 2)), 0b101100);
+    assert_eq!(cks.decrypt_bits(&sks.shift_left(&ct, 8)), 0);
+}
@@ -2,12 +2,18 @@
 //!
 //! This module implements the ciphertext structure containing an encryption of a Boolean message.
 
+use crate::engine::{CpuBooleanEngine, WithThreadLocalEngine};
+use crate::parameters::BooleanParameters;
+use crate::serialization::{SerializationError, Versioned};
 use concrete_core::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// A structure containing a ciphertext, meant to encrypt a Boolean message.
 ///
 /// It is used to evaluate a Boolean circuits homomorphically.
+///
+/// A ciphertext does not keep the parameters of the key it was encrypted with, so they are not
+/// written when it is serialized.
 #[derive(Clone, Debug)]
 pub enum Ciphertext {
     Encrypted(LweCiphertext32),
@@ -20,8 +26,14 @@ enum SerializableCiphertext {
     Trivial(bool),
 }
 
-impl Serialize for Ciphertext {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+impl Versioned for Ciphertext {
+    const MAGIC: [u8; 4] = *b"CBCT";
+
+    fn envelope_parameters(&self) -> Option<BooleanParameters> {
+        None
+    }
+
+    fn serialize_payload<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
@@ -36,10 +48,8 @@ impl Serialize for Ciphertext {
         }
         .serialize(serializer)
     }
-}
 
-impl<'de> Deserialize<'de> for Ciphertext {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    fn deserialize_payload<'de, D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
@@ -58,3 +68,113 @@ impl<'de> Deserialize<'de> for Ciphertext {
         })
     }
 }
+
+impl_versioned_serde!(Ciphertext);
+
+/// A structure containing a compressed ciphertext, meant to encrypt a Boolean message.
+///
+/// The random mask of the LWE ciphertext is replaced by the seed used to generate it, so it is
+/// much smaller to send over the network. It has to be decompressed into a [`Ciphertext`] before
+/// being used in a Boolean circuit.
+#[derive(Clone, Debug)]
+pub struct CompressedCiphertext {
+    pub(crate) ciphertext: LweSeededCiphertext32,
+    // Unknown for the ciphertexts written before the format was versioned
+    pub(crate) parameters: Option<BooleanParameters>,
+}
+
+impl CompressedCiphertext {
+    /// Regenerates the mask of the ciphertext from its seed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "cuda"))]
+    /// # fn main() {
+    /// use concrete_boolean::prelude::*;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // Encryption of one message:
+    /// let compressed_ct = cks.encrypt_compressed(true);
+    ///
+    /// // Decompression on the server side:
+    /// let ct = compressed_ct.decompress();
+    /// let ct_res = sks.not(&ct);
+    ///
+    /// // Decryption:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(false, dec);
+    /// # }
+    /// # #[cfg(feature = "cuda")]
+    /// # fn main() {}
+    /// ```
+    pub fn decompress(&self) -> Ciphertext {
+        CpuBooleanEngine::with_thread_local_mut(|engine| engine.decompress(self))
+    }
+}
+
+impl From<CompressedCiphertext> for Ciphertext {
+    fn from(compressed_ciphertext: CompressedCiphertext) -> Self {
+        compressed_ciphertext.decompress()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializableCompressedCiphertext {
+    ciphertext: Vec<u8>,
+}
+
+impl Versioned for CompressedCiphertext {
+    const MAGIC: [u8; 4] = *b"CBCC";
+
+    fn envelope_parameters(&self) -> Option<BooleanParameters> {
+        self.parameters
+    }
+
+    fn with_envelope_parameters(
+        self,
+        parameters: Option<BooleanParameters>,
+    ) -> Result<Self, SerializationError> {
+        match parameters {
+            Some(parameters) if self.ciphertext.lwe_dimension() != parameters.lwe_dimension => {
+                Err(SerializationError::IncompatibleParameters {
+                    envelope: parameters,
+                })
+            }
+            parameters => Ok(Self { parameters, ..self }),
+        }
+    }
+
+    fn serialize_payload<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_eng = DefaultSerializationEngine::new(()).map_err(serde::ser::Error::custom)?;
+
+        let ciphertext = ser_eng
+            .serialize(&self.ciphertext)
+            .map_err(serde::ser::Error::custom)?;
+
+        SerializableCompressedCiphertext { ciphertext }.serialize(serializer)
+    }
+
+    fn deserialize_payload<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let thing = SerializableCompressedCiphertext::deserialize(deserializer)?;
+
+        let mut de_eng = DefaultSerializationEngine::new(()).map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            ciphertext: de_eng
+                .deserialize(thing.ciphertext.as_slice())
+                .map_err(serde::de::Error::custom)?,
+            parameters: None,
+        })
+    }
+}
+
+impl_versioned_serde!(CompressedCiphertext);
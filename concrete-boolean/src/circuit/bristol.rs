@@ -0,0 +1,171 @@
+//! Parser for the Bristol Fashion netlist format.
+//!
+//! A netlist starts with three header lines:
+//! * the number of gates and the number of wires;
+//! * the number of input values followed by the number of bits of each of them;
+//! * the number of output values followed by the number of bits of each of them.
+//!
+//! Each following non-empty line describes a gate as `<#inputs> <#outputs> <input wires...>
+//! <output wires...> <operation>`, for example `2 1 0 1 2 XOR`.
+
+use super::{Circuit, CircuitError, Gate};
+
+pub(super) fn parse(netlist: &str) -> Result<Circuit, CircuitError> {
+    // The line reported when the netlist ends before its header is complete
+    let end_line = netlist.lines().count() + 1;
+    let mut lines = netlist
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+
+    let (header_line, header) = next_line(&mut lines, end_line, "the gate and wire counts")?;
+    let (num_gates, num_wires) = match parse_numbers(header_line, header)?.as_slice() {
+        [num_gates, num_wires] => (*num_gates, *num_wires),
+        _ => {
+            return Err(parse_error(
+                header_line,
+                "expected the gate and wire counts",
+            ))
+        }
+    };
+
+    let (line, inputs) = next_line(&mut lines, end_line, "the input sizes")?;
+    let input_sizes = parse_sizes(line, inputs)?;
+
+    let (line, outputs) = next_line(&mut lines, end_line, "the output sizes")?;
+    let output_sizes = parse_sizes(line, outputs)?;
+
+    let gates = lines
+        .map(|(line, gate)| parse_gate(line, gate))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if gates.len() != num_gates {
+        return Err(CircuitError::Parse {
+            line: header_line,
+            reason: format!("expected {} gates, found {}", num_gates, gates.len()),
+        });
+    }
+
+    Circuit::new(num_wires, input_sizes, output_sizes, gates)
+}
+
+fn parse_error(line: usize, reason: &str) -> CircuitError {
+    CircuitError::Parse {
+        line,
+        reason: reason.to_string(),
+    }
+}
+
+fn next_line<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    end_line: usize,
+    expected: &str,
+) -> Result<(usize, &'a str), CircuitError> {
+    lines.next().ok_or_else(|| CircuitError::Parse {
+        line: end_line,
+        reason: format!("unexpected end of netlist, expected {}", expected),
+    })
+}
+
+fn parse_numbers(line: usize, text: &str) -> Result<Vec<usize>, CircuitError> {
+    text.split_whitespace()
+        .map(|token| {
+            token
+                .parse()
+                .map_err(|_| parse_error(line, &format!("`{}` is not a number", token)))
+        })
+        .collect()
+}
+
+/// Parses a `<count> <size>...` line.
+fn parse_sizes(line: usize, text: &str) -> Result<Vec<usize>, CircuitError> {
+    let numbers = parse_numbers(line, text)?;
+    match numbers.split_first() {
+        Some((count, sizes)) if *count == sizes.len() => Ok(sizes.to_vec()),
+        _ => Err(parse_error(
+            line,
+            "the value count does not match the sizes",
+        )),
+    }
+}
+
+fn parse_gate(line: usize, text: &str) -> Result<Gate, CircuitError> {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let (operation, numbers) = tokens
+        .split_last()
+        .ok_or_else(|| parse_error(line, "empty gate"))?;
+    let numbers = parse_numbers(line, &numbers.join(" "))?;
+
+    let (num_inputs, num_outputs, wires) = match numbers.as_slice() {
+        [num_inputs, num_outputs, wires @ ..] => (*num_inputs, *num_outputs, wires),
+        _ => return Err(parse_error(line, "missing the input and output counts")),
+    };
+    if num_outputs != 1 || wires.len() != num_inputs + num_outputs {
+        return Err(parse_error(line, "wrong number of wires"));
+    }
+    let output = wires[num_inputs];
+
+    let gate = match (*operation, &wires[..num_inputs]) {
+        ("AND", [left, right]) => Gate::And {
+            left: *left,
+            right: *right,
+            output,
+        },
+        ("NAND", [left, right]) => Gate::Nand {
+            left: *left,
+            right: *right,
+            output,
+        },
+        ("NOR", [left, right]) => Gate::Nor {
+            left: *left,
+            right: *right,
+            output,
+        },
+        ("OR", [left, right]) => Gate::Or {
+            left: *left,
+            right: *right,
+            output,
+        },
+        ("XOR", [left, right]) => Gate::Xor {
+            left: *left,
+            right: *right,
+            output,
+        },
+        ("XNOR", [left, right]) => Gate::Xnor {
+            left: *left,
+            right: *right,
+            output,
+        },
+        ("INV", [input]) => Gate::Not {
+            input: *input,
+            output,
+        },
+        ("EQW", [input]) => Gate::Copy {
+            input: *input,
+            output,
+        },
+        // The input of EQ is a constant, not a wire
+        ("EQ", [value]) if *value <= 1 => Gate::Constant {
+            value: *value == 1,
+            output,
+        },
+        ("MUX", [condition, then, otherwise]) => Gate::Mux {
+            condition: *condition,
+            then: *then,
+            otherwise: *otherwise,
+            output,
+        },
+        _ => {
+            return Err(parse_error(
+                line,
+                &format!(
+                    "unsupported gate `{}` with {} inputs",
+                    operation, num_inputs
+                ),
+            ))
+        }
+    };
+
+    Ok(gate)
+}
@@ -0,0 +1,480 @@
+//! Boolean circuits described as netlists.
+//!
+//! This module implements a [`Circuit`] type, made of [`Gate`]s connecting numbered wires, which
+//! can be parsed from the [Bristol Fashion](https://homes.esat.kuleuven.be/~nsmart/MPC/) format and
+//! evaluated either in the clear or homomorphically with a [`ServerKey`].
+//!
+//! The gates are scheduled by levels when the circuit is built: all the gates of a level only
+//! depend on the circuit inputs or on gates of previous levels, so the gates of a level are
+//! evaluated in parallel.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(not(feature = "cuda"))]
+//! # fn main() {
+//! use concrete_boolean::circuit::Circuit;
+//! use concrete_boolean::prelude::*;
+//!
+//! // A 1-bit half adder: the outputs are the sum and the carry
+//! let netlist = "2 4\n2 1 1\n2 1 1\n\n2 1 0 1 2 XOR\n2 1 0 1 3 AND\n";
+//! let circuit = Circuit::from_bristol(netlist).unwrap();
+//!
+//! let (cks, sks) = gen_keys();
+//!
+//! let inputs = vec![cks.encrypt(true), cks.encrypt(true)];
+//! let outputs = sks.evaluate_circuit(&circuit, &inputs).unwrap();
+//!
+//! assert_eq!(cks.decrypt(&outputs[0]), false);
+//! assert_eq!(cks.decrypt(&outputs[1]), true);
+//! # }
+//! # #[cfg(feature = "cuda")]
+//! # fn main() {}
+//! ```
+
+mod bristol;
+#[cfg(test)]
+mod tests;
+
+use crate::ciphertext::Ciphertext;
+use crate::server_key::{map_batch, BinaryBooleanGates, ServerKey};
+use std::fmt::{Display, Formatter};
+
+/// Error returned when a circuit cannot be built, parsed or evaluated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CircuitError {
+    /// The netlist is malformed, `line` starts at 1.
+    Parse { line: usize, reason: String },
+    /// A wire index is not smaller than the number of wires of the circuit.
+    WireOutOfRange(usize),
+    /// A wire is read but is neither a circuit input nor the output of a gate.
+    UndefinedWire(usize),
+    /// A wire is a circuit input or the output of another gate.
+    WireAssignedTwice(usize),
+    /// The circuit declares more wires than its inputs and gates can assign.
+    TooManyWires { declared: usize, max: usize },
+    /// The gates cannot be ordered because they form a cycle.
+    Cycle,
+    /// The number of values given to the evaluation is not the number of circuit inputs.
+    InputCountMismatch { expected: usize, got: usize },
+}
+
+impl Display for CircuitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitError::Parse { line, reason } => {
+                write!(f, "Invalid netlist at line {}: {}", line, reason)
+            }
+            CircuitError::WireOutOfRange(wire) => {
+                write!(f, "The wire {} is out of range", wire)
+            }
+            CircuitError::UndefinedWire(wire) => {
+                write!(f, "The wire {} is read but never assigned", wire)
+            }
+            CircuitError::WireAssignedTwice(wire) => {
+                write!(f, "The wire {} is assigned more than once", wire)
+            }
+            CircuitError::TooManyWires { declared, max } => write!(
+                f,
+                "The circuit declares {} wires, but its inputs and gates assign at most {}",
+                declared, max
+            ),
+            CircuitError::Cycle => {
+                write!(f, "The circuit contains a cycle")
+            }
+            CircuitError::InputCountMismatch { expected, got } => {
+                write!(f, "Expected {} input values, got {}", expected, got)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CircuitError {}
+
+/// A gate of a circuit, reading and writing wires by index.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Gate {
+    And {
+        left: usize,
+        right: usize,
+        output: usize,
+    },
+    Nand {
+        left: usize,
+        right: usize,
+        output: usize,
+    },
+    Nor {
+        left: usize,
+        right: usize,
+        output: usize,
+    },
+    Or {
+        left: usize,
+        right: usize,
+        output: usize,
+    },
+    Xor {
+        left: usize,
+        right: usize,
+        output: usize,
+    },
+    Xnor {
+        left: usize,
+        right: usize,
+        output: usize,
+    },
+    Not {
+        input: usize,
+        output: usize,
+    },
+    /// Copies the input wire to the output wire.
+    Copy {
+        input: usize,
+        output: usize,
+    },
+    /// Assigns a constant to the output wire.
+    Constant {
+        value: bool,
+        output: usize,
+    },
+    Mux {
+        condition: usize,
+        then: usize,
+        otherwise: usize,
+        output: usize,
+    },
+}
+
+impl Gate {
+    /// Returns the wires read by the gate.
+    pub fn inputs(&self) -> Vec<usize> {
+        match *self {
+            Gate::And { left, right, .. }
+            | Gate::Nand { left, right, .. }
+            | Gate::Nor { left, right, .. }
+            | Gate::Or { left, right, .. }
+            | Gate::Xor { left, right, .. }
+            | Gate::Xnor { left, right, .. } => vec![left, right],
+            Gate::Not { input, .. } | Gate::Copy { input, .. } => vec![input],
+            Gate::Constant { .. } => vec![],
+            Gate::Mux {
+                condition,
+                then,
+                otherwise,
+                ..
+            } => vec![condition, then, otherwise],
+        }
+    }
+
+    /// Returns the wire written by the gate.
+    pub fn output(&self) -> usize {
+        match *self {
+            Gate::And { output, .. }
+            | Gate::Nand { output, .. }
+            | Gate::Nor { output, .. }
+            | Gate::Or { output, .. }
+            | Gate::Xor { output, .. }
+            | Gate::Xnor { output, .. }
+            | Gate::Not { output, .. }
+            | Gate::Copy { output, .. }
+            | Gate::Constant { output, .. }
+            | Gate::Mux { output, .. } => output,
+        }
+    }
+
+    fn evaluate_clear(&self, wires: &[Option<bool>]) -> bool {
+        // The schedule guarantees that the inputs of a gate are set before it is evaluated
+        let wire = |index: usize| wires[index].unwrap();
+        match *self {
+            Gate::And { left, right, .. } => wire(left) && wire(right),
+            Gate::Nand { left, right, .. } => !(wire(left) && wire(right)),
+            Gate::Nor { left, right, .. } => !(wire(left) || wire(right)),
+            Gate::Or { left, right, .. } => wire(left) || wire(right),
+            Gate::Xor { left, right, .. } => wire(left) ^ wire(right),
+            Gate::Xnor { left, right, .. } => !(wire(left) ^ wire(right)),
+            Gate::Not { input, .. } => !wire(input),
+            Gate::Copy { input, .. } => wire(input),
+            Gate::Constant { value, .. } => value,
+            Gate::Mux {
+                condition,
+                then,
+                otherwise,
+                ..
+            } => {
+                if wire(condition) {
+                    wire(then)
+                } else {
+                    wire(otherwise)
+                }
+            }
+        }
+    }
+
+    fn evaluate(&self, server_key: &ServerKey, wires: &[Option<Ciphertext>]) -> Ciphertext {
+        // The schedule guarantees that the inputs of a gate are set before it is evaluated
+        let wire = |index: usize| wires[index].as_ref().unwrap();
+        match *self {
+            Gate::And { left, right, .. } => server_key.and(wire(left), wire(right)),
+            Gate::Nand { left, right, .. } => server_key.nand(wire(left), wire(right)),
+            Gate::Nor { left, right, .. } => server_key.nor(wire(left), wire(right)),
+            Gate::Or { left, right, .. } => server_key.or(wire(left), wire(right)),
+            Gate::Xor { left, right, .. } => server_key.xor(wire(left), wire(right)),
+            Gate::Xnor { left, right, .. } => server_key.xnor(wire(left), wire(right)),
+            Gate::Not { input, .. } => server_key.not(wire(input)),
+            Gate::Copy { input, .. } => wire(input).clone(),
+            Gate::Constant { value, .. } => server_key.trivial_encrypt(value),
+            Gate::Mux {
+                condition,
+                then,
+                otherwise,
+                ..
+            } => server_key.mux(wire(condition), wire(then), wire(otherwise)),
+        }
+    }
+}
+
+/// A Boolean circuit.
+///
+/// The first wires of the circuit are its inputs and the last wires are its outputs, as in the
+/// Bristol Fashion format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Circuit {
+    num_wires: usize,
+    input_sizes: Vec<usize>,
+    output_sizes: Vec<usize>,
+    gates: Vec<Gate>,
+    // Indices of the gates, grouped by levels
+    levels: Vec<Vec<usize>>,
+}
+
+impl Circuit {
+    /// Builds a circuit from its gates, checking that they can be scheduled.
+    ///
+    /// `input_sizes` and `output_sizes` give the number of bits of each input and output value.
+    pub fn new(
+        num_wires: usize,
+        input_sizes: Vec<usize>,
+        output_sizes: Vec<usize>,
+        gates: Vec<Gate>,
+    ) -> Result<Self, CircuitError> {
+        // The sizes come from untrusted netlists, they must not overflow
+        let sum = |sizes: &[usize]| {
+            sizes
+                .iter()
+                .fold(0_usize, |sum, size| sum.saturating_add(*size))
+        };
+        let num_inputs = sum(&input_sizes);
+        let num_outputs = sum(&output_sizes);
+        if num_inputs > num_wires {
+            return Err(CircuitError::WireOutOfRange(num_inputs));
+        }
+        if num_outputs > num_wires {
+            return Err(CircuitError::WireOutOfRange(num_outputs));
+        }
+
+        // Every wire is an input or the output of a gate, which also bounds the allocations sized
+        // by the number of wires
+        let max_wires = num_inputs.saturating_add(gates.len());
+        if num_wires > max_wires {
+            return Err(CircuitError::TooManyWires {
+                declared: num_wires,
+                max: max_wires,
+            });
+        }
+
+        let levels = schedule(num_wires, num_inputs, num_outputs, &gates)?;
+
+        Ok(Self {
+            num_wires,
+            input_sizes,
+            output_sizes,
+            gates,
+            levels,
+        })
+    }
+
+    /// Parses a circuit in the Bristol Fashion format.
+    ///
+    /// On top of the `AND`, `XOR`, `INV`, `EQ` and `EQW` gates of the format, the `NAND`, `NOR`,
+    /// `OR`, `XNOR` two-input gates and the `MUX` three-input gate (condition, then, else) are
+    /// accepted.
+    pub fn from_bristol(netlist: &str) -> Result<Self, CircuitError> {
+        bristol::parse(netlist)
+    }
+
+    /// Returns the total number of wires.
+    pub fn num_wires(&self) -> usize {
+        self.num_wires
+    }
+
+    /// Returns the number of bits of each input value.
+    pub fn input_sizes(&self) -> &[usize] {
+        &self.input_sizes
+    }
+
+    /// Returns the number of bits of each output value.
+    pub fn output_sizes(&self) -> &[usize] {
+        &self.output_sizes
+    }
+
+    /// Returns the total number of input bits.
+    pub fn num_inputs(&self) -> usize {
+        self.input_sizes.iter().sum()
+    }
+
+    /// Returns the total number of output bits.
+    pub fn num_outputs(&self) -> usize {
+        self.output_sizes.iter().sum()
+    }
+
+    /// Returns the gates, in the order they were given.
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// Returns the number of levels of the schedule, i.e. the depth of the circuit.
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Evaluates the circuit in the clear.
+    ///
+    /// The input bits are given in wire order and the output bits are returned in wire order.
+    pub fn evaluate_clear(&self, inputs: &[bool]) -> Result<Vec<bool>, CircuitError> {
+        self.check_input_count(inputs.len())?;
+
+        let mut wires: Vec<Option<bool>> = vec![None; self.num_wires];
+        for (wire, input) in wires.iter_mut().zip(inputs.iter()) {
+            *wire = Some(*input);
+        }
+
+        for level in self.levels.iter() {
+            for gate_index in level.iter() {
+                let gate = &self.gates[*gate_index];
+                wires[gate.output()] = Some(gate.evaluate_clear(&wires));
+            }
+        }
+
+        Ok(wires[self.num_wires - self.num_outputs()..]
+            .iter()
+            .map(|wire| wire.unwrap())
+            .collect())
+    }
+
+    fn check_input_count(&self, got: usize) -> Result<(), CircuitError> {
+        let expected = self.num_inputs();
+        if got != expected {
+            return Err(CircuitError::InputCountMismatch { expected, got });
+        }
+        Ok(())
+    }
+}
+
+impl ServerKey {
+    /// Evaluates a circuit homomorphically.
+    ///
+    /// The input ciphertexts are given in wire order and the output ciphertexts are returned in
+    /// wire order. The gates of a same level are evaluated in parallel.
+    pub fn evaluate_circuit(
+        &self,
+        circuit: &Circuit,
+        inputs: &[Ciphertext],
+    ) -> Result<Vec<Ciphertext>, CircuitError> {
+        circuit.check_input_count(inputs.len())?;
+
+        let mut wires: Vec<Option<Ciphertext>> = vec![None; circuit.num_wires];
+        for (wire, input) in wires.iter_mut().zip(inputs.iter()) {
+            *wire = Some(input.clone());
+        }
+
+        for level in circuit.levels.iter() {
+            let results = map_batch(level, |gate_index| {
+                circuit.gates[*gate_index].evaluate(self, &wires)
+            });
+            for (gate_index, result) in level.iter().zip(results) {
+                wires[circuit.gates[*gate_index].output()] = Some(result);
+            }
+        }
+
+        Ok(wires
+            .drain(circuit.num_wires - circuit.num_outputs()..)
+            .map(|wire| wire.unwrap())
+            .collect())
+    }
+}
+
+/// Groups the gates by levels, so that a gate only reads the circuit inputs or the outputs of
+/// gates from previous levels.
+fn schedule(
+    num_wires: usize,
+    num_inputs: usize,
+    num_outputs: usize,
+    gates: &[Gate],
+) -> Result<Vec<Vec<usize>>, CircuitError> {
+    // Index of the gate writing each wire
+    let mut producers: Vec<Option<usize>> = vec![None; num_wires];
+    for (gate_index, gate) in gates.iter().enumerate() {
+        let output = gate.output();
+        if output >= num_wires {
+            return Err(CircuitError::WireOutOfRange(output));
+        }
+        if output < num_inputs || producers[output].is_some() {
+            return Err(CircuitError::WireAssignedTwice(output));
+        }
+        producers[output] = Some(gate_index);
+    }
+
+    // Number of gates each gate waits for, and the gates waiting for it
+    let mut pending: Vec<usize> = vec![0; gates.len()];
+    let mut dependents: Vec<Vec<usize>> = vec![vec![]; gates.len()];
+    for (gate_index, gate) in gates.iter().enumerate() {
+        for input in gate.inputs() {
+            if input >= num_wires {
+                return Err(CircuitError::WireOutOfRange(input));
+            }
+            if input < num_inputs {
+                continue;
+            }
+            match producers[input] {
+                Some(producer) => {
+                    pending[gate_index] += 1;
+                    dependents[producer].push(gate_index);
+                }
+                None => return Err(CircuitError::UndefinedWire(input)),
+            }
+        }
+    }
+
+    for output in num_wires - num_outputs..num_wires {
+        if output >= num_inputs && producers[output].is_none() {
+            return Err(CircuitError::UndefinedWire(output));
+        }
+    }
+
+    let mut levels = vec![];
+    let mut current: Vec<usize> = (0..gates.len())
+        .filter(|gate_index| pending[*gate_index] == 0)
+        .collect();
+    let mut num_scheduled = 0;
+    while !current.is_empty() {
+        let mut next = vec![];
+        for gate_index in current.iter() {
+            for dependent in dependents[*gate_index].iter() {
+                pending[*dependent] -= 1;
+                if pending[*dependent] == 0 {
+                    next.push(*dependent);
+                }
+            }
+        }
+        num_scheduled += current.len();
+        levels.push(current);
+        current = next;
+    }
+
+    if num_scheduled != gates.len() {
+        return Err(CircuitError::Cycle);
+    }
+
+    Ok(levels)
+}
@@ -0,0 +1,259 @@
+use crate::circuit::{Circuit, CircuitError, Gate};
+use crate::client_key::ClientKey;
+use crate::parameters::BooleanParameters;
+use crate::random_integer;
+use crate::server_key::ServerKey;
+
+/// Number of assert in randomized tests
+const NB_TEST: usize = 4;
+
+/// Number of bits of the adder
+const NB_BITS: usize = 32;
+
+/// Selects one of two 2-bit values, then negates its second bit, using the extended gates
+const MUX_NETLIST: &str = "
+4 9
+3 1 2 2
+1 2
+
+3 1 0 1 3 5 MUX
+3 1 0 2 4 6 MUX
+1 1 5 7 EQW
+1 1 6 8 INV
+";
+
+#[cfg(not(feature = "cuda"))]
+mod default_parameters_tests {
+    use super::*;
+    use crate::parameters::DEFAULT_PARAMETERS;
+
+    #[test]
+    fn test_adder_circuit_default_parameters() {
+        test_adder_circuit(DEFAULT_PARAMETERS);
+    }
+}
+
+mod tfhe_lib_parameters_tests {
+    use super::*;
+    use crate::parameters::TFHE_LIB_PARAMETERS;
+
+    #[test]
+    fn test_adder_circuit_tfhe_lib_parameters() {
+        test_adder_circuit(TFHE_LIB_PARAMETERS);
+    }
+}
+
+/// Writes a `NB_BITS` ripple carry adder in the Bristol Fashion format, only using standard gates
+fn adder_netlist() -> String {
+    // wires: the two inputs, 1 + 4 * NB_BITS intermediate wires then the outputs
+    let num_wires = 2 * NB_BITS + 1 + 4 * NB_BITS + NB_BITS;
+    let output_start = num_wires - NB_BITS;
+
+    let mut gates = vec![];
+    let mut next_wire = 2 * NB_BITS;
+    let mut fresh = || {
+        next_wire += 1;
+        next_wire - 1
+    };
+
+    let mut carry = fresh();
+    gates.push(format!("1 1 0 {} EQ", carry));
+    for i in 0..NB_BITS {
+        let (a, b) = (i, NB_BITS + i);
+        let (t, u, v, next_carry) = (fresh(), fresh(), fresh(), fresh());
+        gates.push(format!("2 1 {} {} {} XOR", a, b, t));
+        gates.push(format!("2 1 {} {} {} XOR", t, carry, output_start + i));
+        gates.push(format!("2 1 {} {} {} AND", a, b, u));
+        gates.push(format!("2 1 {} {} {} AND", t, carry, v));
+        gates.push(format!("2 1 {} {} {} XOR", u, v, next_carry));
+        carry = next_carry;
+    }
+
+    format!(
+        "{} {}\n2 {} {}\n1 {}\n\n{}\n",
+        gates.len(),
+        num_wires,
+        NB_BITS,
+        NB_BITS,
+        NB_BITS,
+        gates.join("\n")
+    )
+}
+
+fn to_bits(value: u32) -> Vec<bool> {
+    (0..NB_BITS).map(|i| (value >> i) & 1 == 1).collect()
+}
+
+fn from_bits(bits: &[bool]) -> u32 {
+    bits.iter()
+        .enumerate()
+        .fold(0, |acc, (i, bit)| acc | ((*bit as u32) << i))
+}
+
+#[test]
+fn test_adder_circuit_clear() {
+    let circuit = Circuit::from_bristol(&adder_netlist()).unwrap();
+    assert_eq!(circuit.input_sizes(), &[NB_BITS, NB_BITS]);
+    assert_eq!(circuit.output_sizes(), &[NB_BITS]);
+
+    for _ in 0..NB_TEST * 32 {
+        let a = random_integer();
+        let b = random_integer();
+
+        let inputs: Vec<bool> = to_bits(a).into_iter().chain(to_bits(b)).collect();
+        let outputs = circuit.evaluate_clear(&inputs).unwrap();
+
+        assert_eq!(a.wrapping_add(b), from_bits(&outputs));
+    }
+}
+
+#[test]
+fn test_mux_circuit_clear() {
+    let circuit = Circuit::from_bristol(MUX_NETLIST).unwrap();
+    assert_eq!(circuit.depth(), 2);
+
+    // select the first value
+    let outputs = circuit
+        .evaluate_clear(&[true, true, false, false, true])
+        .unwrap();
+    assert_eq!(outputs, vec![true, true]);
+
+    // select the second value
+    let outputs = circuit
+        .evaluate_clear(&[false, true, false, false, true])
+        .unwrap();
+    assert_eq!(outputs, vec![false, false]);
+}
+
+#[test]
+fn test_schedule_out_of_order_gates() {
+    // the gates are listed in reverse order of evaluation
+    let gates = vec![
+        Gate::Not {
+            input: 3,
+            output: 4,
+        },
+        Gate::And {
+            left: 2,
+            right: 1,
+            output: 3,
+        },
+        Gate::Xor {
+            left: 0,
+            right: 1,
+            output: 2,
+        },
+    ];
+    let circuit = Circuit::new(5, vec![2], vec![1], gates).unwrap();
+    assert_eq!(circuit.depth(), 3);
+    assert_eq!(circuit.evaluate_clear(&[true, true]).unwrap(), vec![true]);
+    assert_eq!(circuit.evaluate_clear(&[false, true]).unwrap(), vec![false]);
+}
+
+#[test]
+fn test_invalid_circuits() {
+    // reading a wire nobody writes
+    let gates = vec![Gate::Not {
+        input: 2,
+        output: 3,
+    }];
+    assert_eq!(
+        Circuit::new(4, vec![2], vec![1], gates),
+        Err(CircuitError::UndefinedWire(2))
+    );
+
+    // writing an input wire
+    let gates = vec![Gate::Not {
+        input: 0,
+        output: 1,
+    }];
+    assert_eq!(
+        Circuit::new(2, vec![2], vec![1], gates),
+        Err(CircuitError::WireAssignedTwice(1))
+    );
+
+    // two gates depending on each other
+    let gates = vec![
+        Gate::And {
+            left: 0,
+            right: 2,
+            output: 1,
+        },
+        Gate::Not {
+            input: 1,
+            output: 2,
+        },
+    ];
+    assert_eq!(
+        Circuit::new(3, vec![1], vec![1], gates),
+        Err(CircuitError::Cycle)
+    );
+
+    // wrong gate count
+    assert!(matches!(
+        Circuit::from_bristol("2 3\n1 2\n1 1\n2 1 0 1 2 AND\n"),
+        Err(CircuitError::Parse { line: 1, .. })
+    ));
+
+    // truncated header, the error points after the last line
+    assert!(matches!(
+        Circuit::from_bristol("1 3\n1 2\n"),
+        Err(CircuitError::Parse { line: 3, .. })
+    ));
+
+    // wire count larger than the inputs and gates can assign, before allocating the wires
+    assert_eq!(
+        Circuit::from_bristol(&format!("1 {}\n1 2\n1 1\n2 1 0 1 2 AND\n", usize::MAX)),
+        Err(CircuitError::TooManyWires {
+            declared: usize::MAX,
+            max: 3
+        })
+    );
+
+    // input sizes overflowing
+    assert_eq!(
+        Circuit::new(3, vec![usize::MAX, 1], vec![1], vec![]),
+        Err(CircuitError::WireOutOfRange(usize::MAX))
+    );
+
+    // unknown gate
+    assert!(matches!(
+        Circuit::from_bristol("1 3\n1 2\n1 1\n2 1 0 1 2 MAND\n"),
+        Err(CircuitError::Parse { line: 4, .. })
+    ));
+
+    // wrong number of inputs at evaluation
+    let circuit = Circuit::from_bristol(MUX_NETLIST).unwrap();
+    assert_eq!(
+        circuit.evaluate_clear(&[true]),
+        Err(CircuitError::InputCountMismatch {
+            expected: 5,
+            got: 1
+        })
+    );
+}
+
+/// test the homomorphic evaluation of the adder against the clear one
+fn test_adder_circuit(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    let circuit = Circuit::from_bristol(&adder_netlist()).unwrap();
+
+    for _ in 0..NB_TEST {
+        let a = random_integer();
+        let b = random_integer();
+
+        let inputs: Vec<bool> = to_bits(a).into_iter().chain(to_bits(b)).collect();
+        let ct_inputs: Vec<_> = inputs.iter().map(|bit| cks.encrypt(*bit)).collect();
+
+        let ct_outputs = sks.evaluate_circuit(&circuit, &ct_inputs).unwrap();
+        let outputs: Vec<bool> = ct_outputs.iter().map(|ct| cks.decrypt(ct)).collect();
+
+        assert_eq!(circuit.evaluate_clear(&inputs).unwrap(), outputs);
+        assert_eq!(a.wrapping_add(b), from_bits(&outputs));
+    }
+}
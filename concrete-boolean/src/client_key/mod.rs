@@ -3,9 +3,10 @@
 //! This module implements the generation of the client' secret keys, together with the
 //! encryption and decryption methods.
 
-use crate::ciphertext::Ciphertext;
+use crate::ciphertext::{Ciphertext, CompressedCiphertext};
 use crate::engine::{CpuBooleanEngine, WithThreadLocalEngine};
 use crate::parameters::BooleanParameters;
+use crate::serialization::Versioned;
 use concrete_core::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::{Debug, Formatter};
@@ -72,6 +73,32 @@ impl ClientKey {
         CpuBooleanEngine::with_thread_local_mut(|engine| engine.encrypt(message, self))
     }
 
+    /// Encrypts a Boolean message using the client key, returning a compressed ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "cuda"))]
+    /// # fn main() {
+    /// use concrete_boolean::prelude::*;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, mut sks) = gen_keys();
+    ///
+    /// // Encryption of one message:
+    /// let ct = cks.encrypt_compressed(true);
+    ///
+    /// // Decryption:
+    /// let dec = cks.decrypt(&ct.decompress());
+    /// assert_eq!(true, dec);
+    /// # }
+    /// # #[cfg(feature = "cuda")]
+    /// # fn main() {}
+    /// ```
+    pub fn encrypt_compressed(&self, message: bool) -> CompressedCiphertext {
+        CpuBooleanEngine::with_thread_local_mut(|engine| engine.encrypt_compressed(message, self))
+    }
+
     /// Decrypts a ciphertext encrypting a Boolean message using the client key.
     ///
     /// # Example
@@ -124,6 +151,19 @@ impl ClientKey {
         }
         CpuBooleanEngine::with_thread_local_mut(|engine| engine.create_client_key(*parameter_set))
     }
+
+    /// Returns the parameters of the client key.
+    pub fn parameters(&self) -> &BooleanParameters {
+        &self.parameters
+    }
+
+    /// Returns the LWE secret key, under which the ciphertexts are encrypted.
+    ///
+    /// It is meant to generate key switching keys towards other schemes, and must be kept as
+    /// secret as the client key itself.
+    pub fn lwe_secret_key(&self) -> &LweSecretKey32 {
+        &self.lwe_secret_key
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -133,8 +173,14 @@ struct SerializableClientKey {
     parameters: BooleanParameters,
 }
 
-impl Serialize for ClientKey {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+impl Versioned for ClientKey {
+    const MAGIC: [u8; 4] = *b"CBCK";
+
+    fn envelope_parameters(&self) -> Option<BooleanParameters> {
+        Some(self.parameters)
+    }
+
+    fn serialize_payload<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
@@ -154,10 +200,8 @@ impl Serialize for ClientKey {
         }
         .serialize(serializer)
     }
-}
 
-impl<'de> Deserialize<'de> for ClientKey {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    fn deserialize_payload<'de, D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
@@ -176,3 +220,5 @@ impl<'de> Deserialize<'de> for ClientKey {
         })
     }
 }
+
+impl_versioned_serde!(ClientKey);
@@ -1,4 +1,6 @@
 use crate::ciphertext::Ciphertext;
+use crate::engine::{CpuBooleanEngine, WithThreadLocalEngine};
+use crate::parameters::BooleanParameters;
 use crate::{ClientKey, PLAINTEXT_TRUE};
 use concrete_core::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -81,7 +83,15 @@ pub struct CpuBootstrapKey {
     pub(super) key_switching_key: LweKeyswitchKey32,
 }
 
-impl CpuBootstrapKey {}
+impl CpuBootstrapKey {
+    /// Generates the bootstrapping and key switching keys of a client key.
+    ///
+    /// This is the key of the [`CpuBootstrapper`], also usable by other bootstrappers running
+    /// on the CPU.
+    pub fn new(cks: &ClientKey) -> Self {
+        CpuBooleanEngine::with_thread_local_mut(|engine| engine.create_server_key(cks))
+    }
+}
 
 impl BooleanServerKey for CpuBootstrapKey {
     fn lwe_size(&self) -> LweSize {
@@ -90,7 +100,7 @@ impl BooleanServerKey for CpuBootstrapKey {
 }
 
 /// Performs ciphertext bootstraps on the CPU
-pub(crate) struct CpuBootstrapper {
+pub struct CpuBootstrapper {
     memory: Memory,
     engine: DefaultEngine,
     fourier_engine: FftEngine,
@@ -142,6 +152,99 @@ impl CpuBootstrapper {
     }
 }
 
+/// A structure containing the server public key in a compressed form.
+///
+/// The random masks of the bootstrapping and key switching keys are replaced by the seed used to
+/// generate them.
+#[derive(Clone)]
+pub struct CpuCompressedBootstrapKey {
+    pub(super) seeded_bootstrapping_key: LweSeededBootstrapKey32,
+    pub(super) seeded_key_switching_key: LweSeededKeyswitchKey32,
+}
+
+impl CpuCompressedBootstrapKey {
+    /// Returns whether the dimensions and decompositions of the keys are the ones of `parameters`.
+    pub(crate) fn matches_parameters(&self, parameters: &BooleanParameters) -> bool {
+        let bsk = &self.seeded_bootstrapping_key;
+        let ksk = &self.seeded_key_switching_key;
+        bsk.input_lwe_dimension() == parameters.lwe_dimension
+            && bsk.glwe_dimension() == parameters.glwe_dimension
+            && bsk.polynomial_size() == parameters.polynomial_size
+            && bsk.decomposition_base_log() == parameters.pbs_base_log
+            && bsk.decomposition_level_count() == parameters.pbs_level
+            && ksk.output_lwe_dimension() == parameters.lwe_dimension
+            && ksk.decomposition_base_log() == parameters.ks_base_log
+            && ksk.decomposition_level_count() == parameters.ks_level
+    }
+}
+
+impl CpuBootstrapper {
+    pub(crate) fn new_compressed_server_key(
+        &mut self,
+        cks: &ClientKey,
+    ) -> Result<CpuCompressedBootstrapKey, Box<dyn std::error::Error>> {
+        // convert into a variance for rlwe context
+        let var_rlwe = Variance(cks.parameters.glwe_modular_std_dev.get_variance());
+        // creation of the seeded bootstrapping key
+        let seeded_bootstrapping_key: LweSeededBootstrapKey32 =
+            self.engine.generate_new_lwe_seeded_bootstrap_key(
+                &cks.lwe_secret_key,
+                &cks.glwe_secret_key,
+                cks.parameters.pbs_base_log,
+                cks.parameters.pbs_level,
+                var_rlwe,
+            )?;
+
+        // Convert the GLWE secret key into an LWE secret key:
+        let big_lwe_secret_key = self
+            .engine
+            .transform_glwe_secret_key_to_lwe_secret_key(cks.glwe_secret_key.clone())?;
+
+        // convert into a variance for lwe context
+        let var_lwe = Variance(cks.parameters.lwe_modular_std_dev.get_variance());
+        // creation of the seeded key switching key
+        let seeded_key_switching_key = self.engine.generate_new_lwe_seeded_keyswitch_key(
+            &big_lwe_secret_key,
+            &cks.lwe_secret_key,
+            cks.parameters.ks_level,
+            cks.parameters.ks_base_log,
+            var_lwe,
+        )?;
+
+        Ok(CpuCompressedBootstrapKey {
+            seeded_bootstrapping_key,
+            seeded_key_switching_key,
+        })
+    }
+
+    pub(crate) fn decompress_server_key(
+        &mut self,
+        compressed_key: &CpuCompressedBootstrapKey,
+    ) -> Result<CpuBootstrapKey, Box<dyn std::error::Error>> {
+        // regenerate the masks from the seeds
+        let standard_bootstraping_key = self
+            .engine
+            .transform_lwe_seeded_bootstrap_key_to_lwe_bootstrap_key(
+                compressed_key.seeded_bootstrapping_key.clone(),
+            )?;
+        let key_switching_key = self
+            .engine
+            .transform_lwe_seeded_keyswitch_key_to_lwe_keyswitch_key(
+                compressed_key.seeded_key_switching_key.clone(),
+            )?;
+
+        let bootstrapping_key = self
+            .fourier_engine
+            .convert_lwe_bootstrap_key(&standard_bootstraping_key)?;
+
+        Ok(CpuBootstrapKey {
+            standard_bootstraping_key,
+            bootstrapping_key,
+            key_switching_key,
+        })
+    }
+}
+
 impl Default for CpuBootstrapper {
     fn default() -> Self {
         let engine = DefaultEngine::new(Box::new(UnixSeeder::new(0)))
@@ -159,6 +262,8 @@ impl Default for CpuBootstrapper {
 impl Bootstrapper for CpuBootstrapper {
     type ServerKey = CpuBootstrapKey;
 
+    crate::engine_slot!(CpuBootstrapper);
+
     fn bootstrap(
         &mut self,
         input: &LweCiphertext32,
@@ -314,3 +419,51 @@ impl<'de> Deserialize<'de> for CpuBootstrapKey {
         })
     }
 }
+
+#[derive(Serialize, Deserialize)]
+struct SerializableCpuCompressedServerKey {
+    pub seeded_bootstrapping_key: Vec<u8>,
+    pub seeded_key_switching_key: Vec<u8>,
+}
+
+impl Serialize for CpuCompressedBootstrapKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_eng = DefaultSerializationEngine::new(()).map_err(serde::ser::Error::custom)?;
+
+        let seeded_bootstrapping_key = ser_eng
+            .serialize(&self.seeded_bootstrapping_key)
+            .map_err(serde::ser::Error::custom)?;
+        let seeded_key_switching_key = ser_eng
+            .serialize(&self.seeded_key_switching_key)
+            .map_err(serde::ser::Error::custom)?;
+
+        SerializableCpuCompressedServerKey {
+            seeded_bootstrapping_key,
+            seeded_key_switching_key,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CpuCompressedBootstrapKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let thing = SerializableCpuCompressedServerKey::deserialize(deserializer)
+            .map_err(serde::de::Error::custom)?;
+        let mut ser_eng = DefaultSerializationEngine::new(()).map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            seeded_bootstrapping_key: ser_eng
+                .deserialize(thing.seeded_bootstrapping_key.as_slice())
+                .map_err(serde::de::Error::custom)?,
+            seeded_key_switching_key: ser_eng
+                .deserialize(thing.seeded_key_switching_key.as_slice())
+                .map_err(serde::de::Error::custom)?,
+        })
+    }
+}
@@ -1,8 +1,11 @@
 use super::{BooleanServerKey, Bootstrapper, CpuBootstrapKey};
+use crate::engine::{CudaBooleanEngine, WithThreadLocalEngine};
 use crate::PLAINTEXT_TRUE;
 use concrete_core::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
 use crate::ciphertext::Ciphertext;
 
@@ -17,6 +20,54 @@ impl BooleanServerKey for CudaBootstrapKey {
     }
 }
 
+/// The server key of the [`CudaBootstrapper`].
+///
+/// The keys living on the GPU are shared between the clones, the CPU key they were converted from
+/// is kept for serialization.
+#[derive(Clone)]
+pub struct CudaServerKey {
+    pub(crate) cpu_key: CpuBootstrapKey,
+    pub(crate) cuda_key: Arc<CudaBootstrapKey>,
+}
+
+impl From<CpuBootstrapKey> for CudaServerKey {
+    fn from(cpu_key: CpuBootstrapKey) -> Self {
+        let cuda_key =
+            CudaBooleanEngine::with_thread_local_mut(|engine| engine.create_server_key(&cpu_key));
+
+        Self {
+            cpu_key,
+            cuda_key: Arc::new(cuda_key),
+        }
+    }
+}
+
+impl BooleanServerKey for CudaServerKey {
+    fn lwe_size(&self) -> LweSize {
+        self.cuda_key.lwe_size()
+    }
+}
+
+impl Serialize for CudaServerKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.cpu_key.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CudaServerKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let cpu_key = CpuBootstrapKey::deserialize(deserializer)?;
+
+        Ok(Self::from(cpu_key))
+    }
+}
+
 #[derive(PartialOrd, PartialEq, Ord, Eq)]
 struct KeyId {
     // Both of these are for the accumulator
@@ -100,7 +151,8 @@ impl CudaMemory {
     }
 }
 
-pub(crate) struct CudaBootstrapper {
+/// Performs ciphertext bootstraps on the GPU
+pub struct CudaBootstrapper {
     cuda_engine: CudaEngine,
     cpu_engine: DefaultEngine,
     memory: CudaMemory,
@@ -138,13 +190,16 @@ impl CudaBootstrapper {
 }
 
 impl Bootstrapper for CudaBootstrapper {
-    type ServerKey = CudaBootstrapKey;
+    type ServerKey = CudaServerKey;
+
+    crate::engine_slot!(CudaBootstrapper);
 
     fn bootstrap(
         &mut self,
         input: &LweCiphertext32,
         server_key: &Self::ServerKey,
     ) -> Result<LweCiphertext32, Box<dyn std::error::Error>> {
+        let server_key = &*server_key.cuda_key;
         let cuda_buffers =
             self.memory
                 .as_buffers_for_key(&mut self.cpu_engine, &mut self.cuda_engine, server_key);
@@ -172,6 +227,7 @@ impl Bootstrapper for CudaBootstrapper {
         input: &LweCiphertext32,
         server_key: &Self::ServerKey,
     ) -> Result<LweCiphertext32, Box<dyn std::error::Error>> {
+        let server_key = &*server_key.cuda_key;
         let cuda_buffers =
             self.memory
                 .as_buffers_for_key(&mut self.cpu_engine, &mut self.cuda_engine, server_key);
@@ -200,6 +256,7 @@ impl Bootstrapper for CudaBootstrapper {
     ) -> Result<Ciphertext, Box<dyn std::error::Error>> {
         // We re-implement instead of calling our bootstrap and then keyswitch fn
         // to avoid one extra conversion / copy  cpu <-> gpu
+        let server_key = &*server_key.cuda_key;
 
         let cuda_buffers =
             self.memory
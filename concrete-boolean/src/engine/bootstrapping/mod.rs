@@ -1,13 +1,36 @@
+//! The bootstrapping backends.
+//!
+//! A [`ServerKey`](crate::server_key::ServerKey) evaluates the gates with the
+//! [`DefaultBootstrapper`], which is the CPU implementation or, with the `cuda` feature, the GPU
+//! one. Another implementation of the [`Bootstrapper`] trait can be plugged in by building a
+//! `ServerKey<B>` from its key with
+//! [`ServerKey::from_bootstrap_key`](crate::server_key::ServerKey::from_bootstrap_key).
+
 use crate::ciphertext::Ciphertext;
+use crate::engine::BooleanEngine;
 use concrete_core::prelude::{LweCiphertext32, LweSize};
+use std::cell::RefCell;
+use std::thread::LocalKey;
 mod cpu;
 #[cfg(feature = "cuda")]
 mod cuda;
 
 #[cfg(feature = "cuda")]
-pub(crate) use cuda::{CudaBootstrapKey, CudaBootstrapper};
+pub(crate) use cuda::CudaBootstrapKey;
+#[cfg(feature = "cuda")]
+pub use cuda::{CudaBootstrapper, CudaServerKey};
 
-pub(crate) use cpu::{CpuBootstrapKey, CpuBootstrapper};
+pub(crate) use cpu::CpuCompressedBootstrapKey;
+pub use cpu::{CpuBootstrapKey, CpuBootstrapper};
+
+/// The bootstrapper used by the gates of a [`ServerKey`](crate::server_key::ServerKey) when no
+/// other is given.
+#[cfg(not(feature = "cuda"))]
+pub type DefaultBootstrapper = CpuBootstrapper;
+/// The bootstrapper used by the gates of a [`ServerKey`](crate::server_key::ServerKey) when no
+/// other is given.
+#[cfg(feature = "cuda")]
+pub type DefaultBootstrapper = CudaBootstrapper;
 
 pub trait BooleanServerKey {
     /// The LweSize of the Ciphertexts that this key can bootstrap
@@ -19,9 +42,77 @@ pub trait BooleanServerKey {
 ///
 /// Meant to be implemented for different hardware (CPU, GPU) or for other bootstrapping
 /// technics.
-pub(crate) trait Bootstrapper: Default {
+///
+/// Each thread evaluating gates creates its own bootstrapper with [`Default::default`], so an
+/// implementation can keep buffers without any synchronization. It is kept in the thread local
+/// [`EngineSlot`] declared by the [`engine_slot!`](crate::engine_slot) macro.
+///
+/// # Example
+///
+/// A bootstrapper counting the bootstraps, delegating the computation to the CPU one:
+///
+/// ```rust
+/// use concrete_boolean::ciphertext::Ciphertext;
+/// use concrete_boolean::client_key::ClientKey;
+/// use concrete_boolean::engine::bootstrapping::{Bootstrapper, CpuBootstrapKey, CpuBootstrapper};
+/// use concrete_boolean::parameters::DEFAULT_PARAMETERS;
+/// use concrete_boolean::prelude::*;
+/// use concrete_core::prelude::LweCiphertext32;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static BOOTSTRAP_COUNT: AtomicUsize = AtomicUsize::new(0);
+///
+/// #[derive(Default)]
+/// struct CountingBootstrapper(CpuBootstrapper);
+///
+/// impl Bootstrapper for CountingBootstrapper {
+///     type ServerKey = CpuBootstrapKey;
+///
+///     concrete_boolean::engine_slot!(CountingBootstrapper);
+///
+///     fn bootstrap(
+///         &mut self,
+///         input: &LweCiphertext32,
+///         server_key: &CpuBootstrapKey,
+///     ) -> Result<LweCiphertext32, Box<dyn std::error::Error>> {
+///         BOOTSTRAP_COUNT.fetch_add(1, Ordering::Relaxed);
+///         self.0.bootstrap(input, server_key)
+///     }
+///
+///     fn keyswitch(
+///         &mut self,
+///         input: &LweCiphertext32,
+///         server_key: &CpuBootstrapKey,
+///     ) -> Result<LweCiphertext32, Box<dyn std::error::Error>> {
+///         self.0.keyswitch(input, server_key)
+///     }
+///
+///     fn bootstrap_keyswitch(
+///         &mut self,
+///         ciphertext: LweCiphertext32,
+///         server_key: &CpuBootstrapKey,
+///     ) -> Result<Ciphertext, Box<dyn std::error::Error>> {
+///         BOOTSTRAP_COUNT.fetch_add(1, Ordering::Relaxed);
+///         self.0.bootstrap_keyswitch(ciphertext, server_key)
+///     }
+/// }
+///
+/// let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+/// let sks = ServerKey::<CountingBootstrapper>::from_bootstrap_key(CpuBootstrapKey::new(&cks));
+///
+/// let ct_res = sks.and(&cks.encrypt(true), &cks.encrypt(true));
+/// assert_eq!(cks.decrypt(&ct_res), true);
+/// assert_eq!(BOOTSTRAP_COUNT.load(Ordering::Relaxed), 1);
+/// ```
+pub trait Bootstrapper: Default + 'static {
     type ServerKey: BooleanServerKey;
 
+    /// Returns the thread local engine evaluating the gates with this bootstrapper.
+    ///
+    /// Each bootstrapper type has its own one, declared with the
+    /// [`engine_slot!`](crate::engine_slot) macro.
+    fn engine_slot() -> &'static LocalKey<EngineSlot<Self>>;
+
     /// Shall return the result of the bootstrapping of the
     /// input ciphertext or an error if any
     fn bootstrap(
@@ -46,3 +137,36 @@ pub(crate) trait Bootstrapper: Default {
         server_key: &Self::ServerKey,
     ) -> Result<Ciphertext, Box<dyn std::error::Error>>;
 }
+
+/// The thread local engine evaluating the gates with the bootstrapper `B`.
+///
+/// A gate only borrows the engine of its bootstrapper, so a bootstrapper may itself evaluate
+/// gates with another bootstrapper, but not with its own type.
+pub struct EngineSlot<B>(pub(super) RefCell<BooleanEngine<B>>);
+
+impl<B> Default for EngineSlot<B>
+where
+    B: Bootstrapper,
+{
+    fn default() -> Self {
+        Self(RefCell::new(BooleanEngine::new()))
+    }
+}
+
+/// Implements [`Bootstrapper::engine_slot`] for the given bootstrapper type.
+///
+/// It has to be invoked inside the implementation of the [`Bootstrapper`] trait, see its example.
+#[macro_export]
+macro_rules! engine_slot {
+    ($bootstrapper:ty) => {
+        fn engine_slot() -> &'static ::std::thread::LocalKey<
+            $crate::engine::bootstrapping::EngineSlot<$bootstrapper>,
+        > {
+            ::std::thread_local! {
+                static ENGINE: $crate::engine::bootstrapping::EngineSlot<$bootstrapper> =
+                    ::std::default::Default::default();
+            }
+            &ENGINE
+        }
+    };
+}
@@ -1,15 +1,21 @@
-use crate::ciphertext::Ciphertext;
-use crate::parameters::BooleanParameters;
+use crate::ciphertext::{Ciphertext, CompressedCiphertext};
+use crate::parameters::{BooleanParameters, NoiseEstimate};
+use crate::public_key::PublicKey;
+use crate::threshold::{PartialDecryption, SecretKeyShare};
 use crate::{ClientKey, PLAINTEXT_FALSE, PLAINTEXT_TRUE};
 use bootstrapping::{BooleanServerKey, Bootstrapper, CpuBootstrapper};
 use concrete_core::prelude::*;
-use std::cell::RefCell;
 pub mod bootstrapping;
-use crate::engine::bootstrapping::CpuBootstrapKey;
+use crate::engine::bootstrapping::{CpuBootstrapKey, CpuCompressedBootstrapKey};
+mod random;
+use random::Sampler;
 
 #[cfg(feature = "cuda")]
 use bootstrapping::{CudaBootstrapKey, CudaBootstrapper};
 
+/// The base 2 logarithm of the ciphertext modulus
+const LOG2_Q_32: usize = 32;
+
 pub(crate) trait BinaryGatesEngine<L, R, K> {
     fn and(&mut self, ct_left: L, ct_right: R, server_key: &K) -> Ciphertext;
     fn nand(&mut self, ct_left: L, ct_right: R, server_key: &K) -> Ciphertext;
@@ -31,35 +37,23 @@ pub(crate) type CpuBooleanEngine = BooleanEngine<CpuBootstrapper>;
 #[cfg(feature = "cuda")]
 pub(crate) type CudaBooleanEngine = BooleanEngine<CudaBootstrapper>;
 
-// All our thread local engines
-// that our exposed types will use internally to implement their methods
-thread_local! {
-    static CPU_ENGINE: RefCell<BooleanEngine<CpuBootstrapper>> = RefCell::new(BooleanEngine::<_>::new());
-    #[cfg(feature = "cuda")]
-    static CUDA_ENGINE: RefCell<BooleanEngine<CudaBootstrapper>> = RefCell::new(BooleanEngine::<_>::new());
-}
-
-impl WithThreadLocalEngine for CpuBooleanEngine {
-    fn with_thread_local_mut<R, F>(func: F) -> R
-    where
-        F: FnOnce(&mut Self) -> R,
-    {
-        CPU_ENGINE.with(|engine_cell| func(&mut engine_cell.borrow_mut()))
-    }
-}
-
-#[cfg(feature = "cuda")]
-impl WithThreadLocalEngine for CudaBooleanEngine {
+// Each bootstrapper type has its own thread local engine, that our exposed types will use
+// internally to implement their methods
+impl<B> WithThreadLocalEngine for BooleanEngine<B>
+where
+    B: Bootstrapper,
+{
     fn with_thread_local_mut<R, F>(func: F) -> R
     where
         F: FnOnce(&mut Self) -> R,
     {
-        CUDA_ENGINE.with(|engine_cell| func(&mut engine_cell.borrow_mut()))
+        B::engine_slot().with(|slot| func(&mut slot.0.borrow_mut()))
     }
 }
 
 pub(crate) struct BooleanEngine<B> {
     pub(crate) engine: DefaultEngine,
+    sampler: Sampler,
     bootstrapper: B,
 }
 
@@ -69,6 +63,19 @@ impl BooleanEngine<CpuBootstrapper> {
 
         server_key
     }
+
+    pub fn create_compressed_server_key(&mut self, cks: &ClientKey) -> CpuCompressedBootstrapKey {
+        self.bootstrapper.new_compressed_server_key(cks).unwrap()
+    }
+
+    pub fn decompress_server_key(
+        &mut self,
+        compressed_key: &CpuCompressedBootstrapKey,
+    ) -> CpuBootstrapKey {
+        self.bootstrapper
+            .decompress_server_key(compressed_key)
+            .unwrap()
+    }
 }
 
 #[cfg(feature = "cuda")]
@@ -100,6 +107,27 @@ impl<B> BooleanEngine<B> {
             parameters,
         }
     }
+    pub fn create_public_key(&mut self, cks: &ClientKey) -> PublicKey {
+        // Formula is (n + 1) * log2(q) + 128
+        let zero_encryption_count = LwePublicKeyZeroEncryptionCount(
+            cks.parameters.lwe_dimension.to_lwe_size().0 * LOG2_Q_32 + 128,
+        );
+
+        // convert into a variance
+        let var = Variance(cks.parameters.lwe_modular_std_dev.get_variance());
+
+        // generate the zero encryptions
+        let lwe_public_key: LwePublicKey32 = self
+            .engine
+            .generate_new_lwe_public_key(&cks.lwe_secret_key, var, zero_encryption_count)
+            .unwrap();
+
+        PublicKey {
+            lwe_public_key,
+            parameters: cks.parameters,
+        }
+    }
+
     pub fn trivial_encrypt(&mut self, message: bool) -> Ciphertext {
         Ciphertext::Trivial(message)
     }
@@ -124,6 +152,61 @@ impl<B> BooleanEngine<B> {
         Ciphertext::Encrypted(ct)
     }
 
+    pub fn encrypt_compressed(&mut self, message: bool, cks: &ClientKey) -> CompressedCiphertext {
+        // encode the boolean message
+        let plain: Plaintext32 = if message {
+            self.engine.create_plaintext_from(&PLAINTEXT_TRUE).unwrap()
+        } else {
+            self.engine.create_plaintext_from(&PLAINTEXT_FALSE).unwrap()
+        };
+
+        // convert into a variance
+        let var = Variance(cks.parameters.lwe_modular_std_dev.get_variance());
+
+        // encryption, only the seed of the mask is kept
+        let ct = self
+            .engine
+            .encrypt_lwe_seeded_ciphertext(&cks.lwe_secret_key, &plain, var)
+            .unwrap();
+
+        CompressedCiphertext {
+            ciphertext: ct,
+            parameters: Some(cks.parameters),
+        }
+    }
+
+    pub fn decompress(&mut self, ct: &CompressedCiphertext) -> Ciphertext {
+        // regenerate the mask from the seed
+        let ct = self
+            .engine
+            .transform_lwe_seeded_ciphertext_to_lwe_ciphertext(ct.ciphertext.clone())
+            .unwrap();
+
+        Ciphertext::Encrypted(ct)
+    }
+
+    pub fn encrypt_with_public_key(&mut self, message: bool, pks: &PublicKey) -> Ciphertext {
+        // encode the boolean message
+        let plain: Plaintext32 = if message {
+            self.engine.create_plaintext_from(&PLAINTEXT_TRUE).unwrap()
+        } else {
+            self.engine.create_plaintext_from(&PLAINTEXT_FALSE).unwrap()
+        };
+
+        // allocate the output ciphertext
+        let mut ct = self
+            .engine
+            .create_lwe_ciphertext_from(vec![0u32; pks.parameters.lwe_dimension.to_lwe_size().0])
+            .unwrap();
+
+        // encryption: random subset sum of the zero encryptions, plus the plaintext
+        self.engine
+            .discard_encrypt_lwe_ciphertext_with_public_key(&pks.lwe_public_key, &mut ct, &plain)
+            .unwrap();
+
+        Ciphertext::Encrypted(ct)
+    }
+
     pub fn decrypt(&mut self, ct: &Ciphertext, cks: &ClientKey) -> bool {
         match ct {
             Ciphertext::Trivial(b) => *b,
@@ -146,11 +229,106 @@ impl<B> BooleanEngine<B> {
         }
     }
 
+    pub fn split_secret_key(&mut self, cks: &ClientKey, num_shares: usize) -> Vec<SecretKeyShare> {
+        assert!(num_shares > 0, "At least one share is needed");
+        let lwe_dimension = cks.parameters.lwe_dimension;
+
+        let secret_key = self
+            .engine
+            .consume_retrieve_lwe_secret_key(cks.lwe_secret_key.clone())
+            .unwrap();
+
+        // all the shares but the last one are uniformly random
+        let mut shares: Vec<Vec<u32>> = (1..num_shares)
+            .map(|_| {
+                (0..lwe_dimension.0)
+                    .map(|_| self.sampler.uniform_u32())
+                    .collect()
+            })
+            .collect();
+
+        // the last one completes the sum into the secret key
+        let last_share = shares.iter().fold(secret_key, |mut acc, share| {
+            for (acc_coefficient, share_coefficient) in acc.iter_mut().zip(share.iter()) {
+                *acc_coefficient = acc_coefficient.wrapping_sub(*share_coefficient);
+            }
+            acc
+        });
+        shares.push(last_share);
+
+        shares
+            .into_iter()
+            .map(|share| SecretKeyShare {
+                share,
+                parameters: cks.parameters,
+            })
+            .collect()
+    }
+
+    pub fn partial_decrypt(
+        &mut self,
+        ct: &Ciphertext,
+        share: &SecretKeyShare,
+        smudging_std_dev: StandardDev,
+    ) -> PartialDecryption {
+        match ct {
+            // anyone can read a trivial ciphertext
+            Ciphertext::Trivial(_) => PartialDecryption(0),
+            Ciphertext::Encrypted(ciphertext) => {
+                let data = self
+                    .engine
+                    .consume_retrieve_lwe_ciphertext(ciphertext.clone())
+                    .unwrap();
+                let (mask, _body) = data.split_at(data.len() - 1);
+                assert_eq!(
+                    mask.len(),
+                    share.share.len(),
+                    "The share does not match the ciphertext dimension"
+                );
+
+                // the share of <mask, secret key>
+                let partial_mask_product = mask
+                    .iter()
+                    .zip(share.share.iter())
+                    .fold(0u32, |acc, (a, s)| acc.wrapping_add(a.wrapping_mul(*s)));
+
+                let smudging_noise = self.sampler.gaussian_u32(smudging_std_dev.0);
+
+                PartialDecryption(partial_mask_product.wrapping_add(smudging_noise))
+            }
+        }
+    }
+
+    pub fn combine_partial_decryptions(
+        &mut self,
+        ct: &Ciphertext,
+        partial_decryptions: &[PartialDecryption],
+    ) -> bool {
+        match ct {
+            Ciphertext::Trivial(b) => *b,
+            Ciphertext::Encrypted(ciphertext) => {
+                let data = self
+                    .engine
+                    .consume_retrieve_lwe_ciphertext(ciphertext.clone())
+                    .unwrap();
+                let body = *data.last().unwrap();
+
+                // body - <mask, secret key> is the plaintext plus the noise
+                let decrypted_u32 = partial_decryptions
+                    .iter()
+                    .fold(body, |acc, partial| acc.wrapping_sub(partial.0));
+
+                decrypted_u32 < (1 << 31)
+            }
+        }
+    }
+
     pub fn not(&mut self, ct: &Ciphertext) -> Ciphertext {
         match ct {
             Ciphertext::Trivial(message) => Ciphertext::Trivial(!*message),
             Ciphertext::Encrypted(ct_ct) => {
                 // Compute the linear combination for NOT: -ct
+                record_stats!(linear_ops += 1);
                 let mut ct_res = ct_ct.clone();
                 self.engine.fuse_opp_lwe_ciphertext(&mut ct_res).unwrap(); // compute the negation
 
@@ -161,6 +339,110 @@ impl<B> BooleanEngine<B> {
     }
 }
 
+/// Largest squared norm of the weights of a gate, the one of `xor3`.
+///
+/// The variance of the noise before the bootstrap grows with the squared norm of the weights.
+const MAX_GATE_WEIGHTS_SQUARED_NORM: i32 = 12;
+
+/// The linear combination of the inputs of a gate computed before its bootstrap.
+struct GateWeights {
+    weights: Vec<i32>,
+    /// The constant added to the combination, in multiples of 1/8.
+    constant: u32,
+    /// The distance between the phases and the decision thresholds, in multiples of 1/8.
+    margin: i32,
+}
+
+impl GateWeights {
+    fn squared_norm(&self) -> i32 {
+        self.weights.iter().map(|weight| weight * weight).sum()
+    }
+
+    /// Returns the estimated failure probability of the gate under the `noise_estimate`.
+    fn failure_probability(&self, noise_estimate: &NoiseEstimate) -> f64 {
+        noise_estimate.gate_failure_probability(self.squared_norm() as f64, self.margin as f64 / 8.)
+    }
+}
+
+/// Finds the weights `w` and the constant `c` such that the phase
+/// `(sum(w_i * m_i) + c) / 8`, with `m_i` in `{-1, 1}` the encoding of the i-th input, lies in
+/// `]0, 1/2[` for the true rows of the truth table and in `]1/2, 1[` for the false ones.
+///
+/// The failure probability of a gate decreases with the ratio between its squared margin to the
+/// decision thresholds and the squared norm of its weights, the weights with the highest ratio are
+/// returned. Even weights can reach a margin of 1/4, as the XOR gate does. On equal ratios, the
+/// larger margin is preferred since it is less affected by the modulus switching noise.
+fn find_gate_weights(truth_table: &[bool], nb_inputs: usize) -> Option<GateWeights> {
+    let mut candidates: Vec<Vec<i32>> = vec![vec![]];
+    for _ in 0..nb_inputs {
+        candidates = candidates
+            .into_iter()
+            .flat_map(|weights| {
+                [0, 1, -1, 2, -2].into_iter().map(move |weight| {
+                    let mut weights = weights.clone();
+                    weights.push(weight);
+                    weights
+                })
+            })
+            .collect();
+    }
+
+    candidates
+        .into_iter()
+        .map(|weights| GateWeights {
+            weights,
+            constant: 0,
+            margin: 0,
+        })
+        .filter(|candidate| candidate.squared_norm() <= MAX_GATE_WEIGHTS_SQUARED_NORM)
+        .filter_map(|candidate| {
+            // Keep the constant giving the largest margin to the decision thresholds
+            (0..8)
+                .map(|constant| {
+                    let margin = truth_table
+                        .iter()
+                        .enumerate()
+                        .map(|(row, value)| {
+                            let phase = candidate
+                                .weights
+                                .iter()
+                                .enumerate()
+                                .map(|(i, weight)| {
+                                    let bit = (row >> (nb_inputs - 1 - i)) & 1;
+                                    if bit == 1 {
+                                        *weight
+                                    } else {
+                                        -*weight
+                                    }
+                                })
+                                .sum::<i32>()
+                                + constant;
+                            match phase.rem_euclid(8) {
+                                phase @ 1..=3 if *value => phase.min(4 - phase),
+                                phase @ 5..=7 if !*value => (phase - 4).min(8 - phase),
+                                _ => 0,
+                            }
+                        })
+                        .min()
+                        .unwrap_or(0);
+                    (constant as u32, margin)
+                })
+                .filter(|(_, margin)| *margin > 0)
+                .max_by_key(|(constant, margin)| (*margin, std::cmp::Reverse(*constant)))
+                .map(|(constant, margin)| GateWeights {
+                    constant,
+                    margin,
+                    ..candidate
+                })
+        })
+        .max_by(|left, right| {
+            // margin_l^2 / norm_l compared with margin_r^2 / norm_r, without divisions
+            (left.margin * left.margin * right.squared_norm())
+                .cmp(&(right.margin * right.margin * left.squared_norm()))
+                .then(left.margin.cmp(&right.margin))
+        })
+}
+
 fn new_seeder() -> Box<dyn Seeder> {
     let seeder: Box<dyn Seeder>;
     #[cfg(target_arch = "x86_64")]
@@ -189,6 +471,7 @@ where
 
         Self {
             engine,
+            sampler: Sampler::new(new_seeder().as_mut()),
             bootstrapper: Default::default(),
         }
     }
@@ -226,6 +509,7 @@ where
         match ct_condition {
             // in the case of the condition is trivially encrypted
             Ciphertext::Trivial(message_condition) => {
+                record_stats!(trivial_shortcuts += 1);
                 if *message_condition {
                     ct_then.clone()
                 } else {
@@ -237,6 +521,7 @@ where
 
                 // take a shortcut if ct_then is trivially encrypted
                 if let Ciphertext::Trivial(message_then) = ct_then {
+                    record_stats!(trivial_shortcuts += 1);
                     return if *message_then {
                         self.or(ct_condition, ct_else, server_key)
                     } else {
@@ -247,6 +532,7 @@ where
 
                 // take a shortcut if ct_else is trivially encrypted
                 if let Ciphertext::Trivial(message_else) = ct_else {
+                    record_stats!(trivial_shortcuts += 1);
                     return if *message_else {
                         let ct_not_condition = self.not(ct_condition);
                         self.or(ct_then, &ct_not_condition, server_key)
@@ -290,6 +576,8 @@ where
                     .unwrap(); //
                                // - 1/8
 
+                record_stats!(linear_ops += 7, bootstraps += 2, keyswitches += 1);
+
                 // Compute the first programmable bootstrapping with fixed test polynomial:
                 let mut ct_pbs_1 = bootstrapper
                     .bootstrap(buffer_lwe_before_pbs, server_key)
@@ -314,6 +602,278 @@ where
             }
         }
     }
+
+    pub fn maj3(
+        &mut self,
+        ct_a: &Ciphertext,
+        ct_b: &Ciphertext,
+        ct_c: &Ciphertext,
+        server_key: &B::ServerKey,
+    ) -> Ciphertext {
+        // MAJ(a, b, true) = a OR b and MAJ(a, b, false) = a AND b
+        match (ct_a, ct_b, ct_c) {
+            (Ciphertext::Trivial(message), _, _) => {
+                record_stats!(trivial_shortcuts += 1);
+                if *message {
+                    self.or(ct_b, ct_c, server_key)
+                } else {
+                    self.and(ct_b, ct_c, server_key)
+                }
+            }
+            (_, Ciphertext::Trivial(message), _) => {
+                record_stats!(trivial_shortcuts += 1);
+                if *message {
+                    self.or(ct_a, ct_c, server_key)
+                } else {
+                    self.and(ct_a, ct_c, server_key)
+                }
+            }
+            (_, _, Ciphertext::Trivial(message)) => {
+                record_stats!(trivial_shortcuts += 1);
+                if *message {
+                    self.or(ct_a, ct_b, server_key)
+                } else {
+                    self.and(ct_a, ct_b, server_key)
+                }
+            }
+            (
+                Ciphertext::Encrypted(ct_a_ct),
+                Ciphertext::Encrypted(ct_b_ct),
+                Ciphertext::Encrypted(ct_c_ct),
+            ) => {
+                let mut buffer_lwe_before_pbs = self
+                    .engine
+                    .create_lwe_ciphertext_from(vec![0u32; server_key.lwe_size().0])
+                    .unwrap();
+                let bootstrapper = &mut self.bootstrapper;
+
+                // Compute the linear combination for MAJ: ct_a + ct_b + ct_c
+                // the sum lies in {-3/8, -1/8, 1/8, 3/8} so its sign is the majority
+                self.engine
+                    .discard_add_lwe_ciphertext(&mut buffer_lwe_before_pbs, ct_a_ct, ct_b_ct)
+                    .unwrap(); // ct_a + ct_b
+                self.engine
+                    .fuse_add_lwe_ciphertext(&mut buffer_lwe_before_pbs, ct_c_ct)
+                    .unwrap(); // + ct_c
+
+                record_stats!(linear_ops += 2, bootstraps += 1, keyswitches += 1);
+
+                // compute the bootstrap and the key switch
+                bootstrapper
+                    .bootstrap_keyswitch(buffer_lwe_before_pbs, server_key)
+                    .unwrap()
+            }
+        }
+    }
+
+    pub fn xor3(
+        &mut self,
+        ct_a: &Ciphertext,
+        ct_b: &Ciphertext,
+        ct_c: &Ciphertext,
+        server_key: &B::ServerKey,
+    ) -> Ciphertext {
+        // a XOR b XOR true = a XNOR b and a XOR b XOR false = a XOR b
+        match (ct_a, ct_b, ct_c) {
+            (Ciphertext::Trivial(message), _, _) => {
+                record_stats!(trivial_shortcuts += 1);
+                if *message {
+                    self.xnor(ct_b, ct_c, server_key)
+                } else {
+                    self.xor(ct_b, ct_c, server_key)
+                }
+            }
+            (_, Ciphertext::Trivial(message), _) => {
+                record_stats!(trivial_shortcuts += 1);
+                if *message {
+                    self.xnor(ct_a, ct_c, server_key)
+                } else {
+                    self.xor(ct_a, ct_c, server_key)
+                }
+            }
+            (_, _, Ciphertext::Trivial(message)) => {
+                record_stats!(trivial_shortcuts += 1);
+                if *message {
+                    self.xnor(ct_a, ct_b, server_key)
+                } else {
+                    self.xor(ct_a, ct_b, server_key)
+                }
+            }
+            (
+                Ciphertext::Encrypted(ct_a_ct),
+                Ciphertext::Encrypted(ct_b_ct),
+                Ciphertext::Encrypted(ct_c_ct),
+            ) => {
+                let mut buffer_lwe_before_pbs = self
+                    .engine
+                    .create_lwe_ciphertext_from(vec![0u32; server_key.lwe_size().0])
+                    .unwrap();
+                let bootstrapper = &mut self.bootstrapper;
+
+                // Compute the linear combination for XOR3: -2*(ct_a + ct_b + ct_c)
+                // the sum lies in {-3/8, -1/8, 1/8, 3/8} and once multiplied by -2
+                // an odd number of true inputs maps to 1/4 and an even one to -1/4
+                self.engine
+                    .discard_add_lwe_ciphertext(&mut buffer_lwe_before_pbs, ct_a_ct, ct_b_ct)
+                    .unwrap(); // ct_a + ct_b
+                self.engine
+                    .fuse_add_lwe_ciphertext(&mut buffer_lwe_before_pbs, ct_c_ct)
+                    .unwrap(); // + ct_c
+                self.engine
+                    .fuse_opp_lwe_ciphertext(&mut buffer_lwe_before_pbs)
+                    .unwrap(); // compute the negation
+                let cst_mul = self.engine.create_cleartext_from(&2u32).unwrap();
+                self.engine
+                    .fuse_mul_lwe_ciphertext_cleartext(&mut buffer_lwe_before_pbs, &cst_mul)
+                    .unwrap(); //* 2
+
+                record_stats!(linear_ops += 4, bootstraps += 1, keyswitches += 1);
+
+                // compute the bootstrap and the key switch
+                bootstrapper
+                    .bootstrap_keyswitch(buffer_lwe_before_pbs, server_key)
+                    .unwrap()
+            }
+        }
+    }
+
+    pub fn and3(
+        &mut self,
+        ct_a: &Ciphertext,
+        ct_b: &Ciphertext,
+        ct_c: &Ciphertext,
+        server_key: &B::ServerKey,
+    ) -> Ciphertext {
+        // With the +/-1/8 encoding, the sums for one and three true inputs are exactly 1/2
+        // apart, the negacyclic bootstrap cannot separate them so two gates are needed.
+        let ct_ab = self.and(ct_a, ct_b, server_key);
+        self.and(&ct_ab, ct_c, server_key)
+    }
+
+    pub fn or3(
+        &mut self,
+        ct_a: &Ciphertext,
+        ct_b: &Ciphertext,
+        ct_c: &Ciphertext,
+        server_key: &B::ServerKey,
+    ) -> Ciphertext {
+        // Same limitation as for and3
+        let ct_ab = self.or(ct_a, ct_b, server_key);
+        self.or(&ct_ab, ct_c, server_key)
+    }
+
+    /// Evaluates the function given by `truth_table` on `cts` with a single bootstrap.
+    ///
+    /// The truth table is indexed by the inputs read as a binary number, the first input being
+    /// the most significant bit. Returns `None` when no linear combination of the encrypted
+    /// inputs within the noise budget has the expected sign for every row of the table, or when
+    /// the estimated failure probability of the best one under the `noise_estimate` exceeds
+    /// `max_failure_probability`.
+    pub fn gate_from_truth_table(
+        &mut self,
+        truth_table: &[bool],
+        cts: &[&Ciphertext],
+        server_key: &B::ServerKey,
+        noise_estimate: Option<&NoiseEstimate>,
+        max_failure_probability: f64,
+    ) -> Option<Ciphertext> {
+        assert_eq!(
+            truth_table.len(),
+            1 << cts.len(),
+            "the truth table of a {}-input gate must have {} rows",
+            cts.len(),
+            1 << cts.len()
+        );
+
+        // Restrict the truth table to the encrypted inputs, the trivial ones are fixed
+        let mut encrypted_cts = Vec::with_capacity(cts.len());
+        let mut trivial_index = 0;
+        let mut encrypted_shifts = Vec::with_capacity(cts.len());
+        for (i, ct) in cts.iter().enumerate() {
+            let shift = cts.len() - 1 - i;
+            match ct {
+                Ciphertext::Encrypted(ct_ct) => {
+                    encrypted_cts.push(ct_ct);
+                    encrypted_shifts.push(shift);
+                }
+                Ciphertext::Trivial(message) => trivial_index |= (*message as usize) << shift,
+            }
+        }
+        let restricted_truth_table: Vec<bool> = (0..1 << encrypted_cts.len())
+            .map(|row| {
+                let index =
+                    encrypted_shifts
+                        .iter()
+                        .enumerate()
+                        .fold(trivial_index, |index, (j, shift)| {
+                            let bit = (row >> (encrypted_shifts.len() - 1 - j)) & 1;
+                            index | bit << shift
+                        });
+                truth_table[index]
+            })
+            .collect();
+
+        // The result does not depend on the encrypted inputs
+        if restricted_truth_table
+            .iter()
+            .all(|value| *value == restricted_truth_table[0])
+        {
+            record_stats!(trivial_shortcuts += 1);
+            return Some(Ciphertext::Trivial(restricted_truth_table[0]));
+        }
+
+        let gate_weights = find_gate_weights(&restricted_truth_table, encrypted_cts.len())?;
+        if let Some(noise_estimate) = noise_estimate {
+            if gate_weights.failure_probability(noise_estimate) > max_failure_probability {
+                return None;
+            }
+        }
+        let GateWeights {
+            weights, constant, ..
+        } = gate_weights;
+
+        let mut buffer_lwe_before_pbs = self
+            .engine
+            .create_lwe_ciphertext_from(vec![0u32; server_key.lwe_size().0])
+            .unwrap();
+        let bootstrapper = &mut self.bootstrapper;
+
+        // Compute the linear combination: sum(weight * ct) + (0,...,0,constant/8)
+        for (ct_ct, weight) in encrypted_cts.into_iter().zip(weights) {
+            if weight == 0 {
+                continue;
+            }
+            let mut ct_temp = ct_ct.clone();
+            if weight != 1 {
+                // the weights are negative modulo 2^32
+                let cst_mul = self.engine.create_cleartext_from(&(weight as u32)).unwrap();
+                self.engine
+                    .fuse_mul_lwe_ciphertext_cleartext(&mut ct_temp, &cst_mul)
+                    .unwrap(); // * weight
+                record_stats!(linear_ops += 1);
+            }
+            self.engine
+                .fuse_add_lwe_ciphertext(&mut buffer_lwe_before_pbs, &ct_temp)
+                .unwrap(); // + weight * ct
+            record_stats!(linear_ops += 1);
+        }
+        let cst_add = self
+            .engine
+            .create_plaintext_from(&PLAINTEXT_TRUE.wrapping_mul(constant))
+            .unwrap();
+        self.engine
+            .fuse_add_lwe_ciphertext_plaintext(&mut buffer_lwe_before_pbs, &cst_add)
+            .unwrap(); // + constant/8
+
+        record_stats!(linear_ops += 1, bootstraps += 1, keyswitches += 1);
+
+        // compute the bootstrap and the key switch
+        Some(
+            bootstrapper
+                .bootstrap_keyswitch(buffer_lwe_before_pbs, server_key)
+                .unwrap(),
+        )
+    }
 }
 
 impl<B> BinaryGatesEngine<&Ciphertext, &Ciphertext, B::ServerKey> for BooleanEngine<B>
@@ -328,6 +888,7 @@ where
     ) -> Ciphertext {
         match (ct_left, ct_right) {
             (Ciphertext::Trivial(message_left), Ciphertext::Trivial(message_right)) => {
+                record_stats!(trivial_shortcuts += 1);
                 Ciphertext::Trivial(*message_left && *message_right)
             }
             (Ciphertext::Encrypted(_), Ciphertext::Trivial(message_right)) => {
@@ -353,6 +914,8 @@ where
                     .unwrap(); //
                                // - 1/8
 
+                record_stats!(linear_ops += 2, bootstraps += 1, keyswitches += 1);
+
                 // compute the bootstrap and the key switch
                 bootstrapper
                     .bootstrap_keyswitch(buffer_lwe_before_pbs, server_key)
@@ -369,6 +932,7 @@ where
     ) -> Ciphertext {
         match (ct_left, ct_right) {
             (Ciphertext::Trivial(message_left), Ciphertext::Trivial(message_right)) => {
+                record_stats!(trivial_shortcuts += 1);
                 Ciphertext::Trivial(!(*message_left && *message_right))
             }
             (Ciphertext::Encrypted(_), Ciphertext::Trivial(message_right)) => {
@@ -396,6 +960,8 @@ where
                     .fuse_add_lwe_ciphertext_plaintext(&mut buffer_lwe_before_pbs, &cst)
                     .unwrap(); // + 1/8
 
+                record_stats!(linear_ops += 3, bootstraps += 1, keyswitches += 1);
+
                 // compute the bootstrap and the key switch
                 bootstrapper
                     .bootstrap_keyswitch(buffer_lwe_before_pbs, server_key)
@@ -412,6 +978,7 @@ where
     ) -> Ciphertext {
         match (ct_left, ct_right) {
             (Ciphertext::Trivial(message_left), Ciphertext::Trivial(message_right)) => {
+                record_stats!(trivial_shortcuts += 1);
                 Ciphertext::Trivial(!(*message_left || *message_right))
             }
             (Ciphertext::Encrypted(_), Ciphertext::Trivial(message_right)) => {
@@ -440,6 +1007,8 @@ where
                     .unwrap(); //
                                // - 1/8
 
+                record_stats!(linear_ops += 3, bootstraps += 1, keyswitches += 1);
+
                 // compute the bootstrap and the key switch
                 bootstrapper
                     .bootstrap_keyswitch(buffer_lwe_before_pbs, server_key)
@@ -456,6 +1025,7 @@ where
     ) -> Ciphertext {
         match (ct_left, ct_right) {
             (Ciphertext::Trivial(message_left), Ciphertext::Trivial(message_right)) => {
+                record_stats!(trivial_shortcuts += 1);
                 Ciphertext::Trivial(*message_left || *message_right)
             }
             (Ciphertext::Encrypted(_), Ciphertext::Trivial(message_right)) => {
@@ -480,6 +1050,8 @@ where
                     .fuse_add_lwe_ciphertext_plaintext(&mut buffer_lwe_before_pbs, &cst)
                     .unwrap(); // + 1/8
 
+                record_stats!(linear_ops += 2, bootstraps += 1, keyswitches += 1);
+
                 // compute the bootstrap and the key switch
                 bootstrapper
                     .bootstrap_keyswitch(buffer_lwe_before_pbs, server_key)
@@ -496,6 +1068,7 @@ where
     ) -> Ciphertext {
         match (ct_left, ct_right) {
             (Ciphertext::Trivial(message_left), Ciphertext::Trivial(message_right)) => {
+                record_stats!(trivial_shortcuts += 1);
                 Ciphertext::Trivial(*message_left ^ *message_right)
             }
             (Ciphertext::Encrypted(_), Ciphertext::Trivial(message_right)) => {
@@ -524,6 +1097,8 @@ where
                     .fuse_mul_lwe_ciphertext_cleartext(&mut buffer_lwe_before_pbs, &cst_mul)
                     .unwrap(); //* 2
 
+                record_stats!(linear_ops += 3, bootstraps += 1, keyswitches += 1);
+
                 // compute the bootstrap and the key switch
                 bootstrapper
                     .bootstrap_keyswitch(buffer_lwe_before_pbs, server_key)
@@ -540,6 +1115,7 @@ where
     ) -> Ciphertext {
         match (ct_left, ct_right) {
             (Ciphertext::Trivial(message_left), Ciphertext::Trivial(message_right)) => {
+                record_stats!(trivial_shortcuts += 1);
                 Ciphertext::Trivial(!(*message_left ^ *message_right))
             }
             (Ciphertext::Encrypted(_), Ciphertext::Trivial(message_right)) => {
@@ -571,6 +1147,8 @@ where
                     .fuse_mul_lwe_ciphertext_cleartext(&mut buffer_lwe_before_pbs, &cst_mul)
                     .unwrap(); //* 2
 
+                record_stats!(linear_ops += 4, bootstraps += 1, keyswitches += 1);
+
                 // compute the bootstrap and the key switch
                 bootstrapper
                     .bootstrap_keyswitch(buffer_lwe_before_pbs, server_key)
@@ -590,6 +1168,7 @@ where
         ct_right: bool,
         _server_key: &B::ServerKey,
     ) -> Ciphertext {
+        record_stats!(trivial_shortcuts += 1);
         if ct_right {
             // ct AND true = ct
             ct_left.clone()
@@ -605,6 +1184,7 @@ where
         ct_right: bool,
         _server_key: &B::ServerKey,
     ) -> Ciphertext {
+        record_stats!(trivial_shortcuts += 1);
         if ct_right {
             // NOT (ct AND true) = NOT(ct)
             self.not(ct_left)
@@ -620,6 +1200,7 @@ where
         ct_right: bool,
         _server_key: &B::ServerKey,
     ) -> Ciphertext {
+        record_stats!(trivial_shortcuts += 1);
         if ct_right {
             // NOT (ct OR true) = NOT(true) = false
             self.trivial_encrypt(false)
@@ -635,6 +1216,7 @@ where
         ct_right: bool,
         _server_key: &B::ServerKey,
     ) -> Ciphertext {
+        record_stats!(trivial_shortcuts += 1);
         if ct_right {
             // ct OR true = true
             self.trivial_encrypt(true)
@@ -650,6 +1232,7 @@ where
         ct_right: bool,
         _server_key: &B::ServerKey,
     ) -> Ciphertext {
+        record_stats!(trivial_shortcuts += 1);
         if ct_right {
             // ct XOR true = NOT(ct)
             self.not(ct_left)
@@ -665,6 +1248,7 @@ where
         ct_right: bool,
         _server_key: &B::ServerKey,
     ) -> Ciphertext {
+        record_stats!(trivial_shortcuts += 1);
         if ct_right {
             // NOT(ct XOR true) = NOT(NOT(ct)) = ct
             ct_left.clone()
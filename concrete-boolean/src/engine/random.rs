@@ -0,0 +1,53 @@
+//! Samplers for the random values drawn outside of the encryptions of `concrete-core`: the shares
+//! of a secret key and the smudging noises of the partial decryptions.
+
+use concrete_core::prelude::Seeder;
+#[cfg(target_arch = "x86_64")]
+use concrete_csprng::generators::AesniRandomGenerator as Generator;
+#[cfg(target_arch = "aarch64")]
+use concrete_csprng::generators::NeonAesRandomGenerator as Generator;
+use concrete_csprng::generators::RandomGenerator;
+use std::f64::consts::PI;
+
+/// Draws uniform and Gaussian elements of the torus from a cryptographically secure generator.
+pub(crate) struct Sampler {
+    generator: Generator,
+}
+
+impl Sampler {
+    pub(crate) fn new(seeder: &mut dyn Seeder) -> Self {
+        Sampler {
+            generator: Generator::new(seeder.seed()),
+        }
+    }
+
+    /// Returns a uniformly random element of the torus.
+    pub(crate) fn uniform_u32(&mut self) -> u32 {
+        u32::from_le_bytes([0; 4].map(|_| self.next_byte()))
+    }
+
+    /// Returns an element of the torus drawn from a centered Gaussian of standard deviation
+    /// `std_dev`, for a torus normalized to 1, rounded to the nearest multiple of $2^{-32}$.
+    pub(crate) fn gaussian_u32(&mut self, std_dev: f64) -> u32 {
+        // Box-Muller transform of two uniform samples, the first one in ]0, 1] for the logarithm
+        let u1 = ((self.uniform_u64() >> 11) + 1) as f64 * 2f64.powi(-53);
+        let u2 = (self.uniform_u64() >> 11) as f64 * 2f64.powi(-53);
+        let normal = (-2. * u1.ln()).sqrt() * (2. * PI * u2).cos();
+
+        // Negative values wrap around the torus
+        let torus_modulus = 2f64.powi(32);
+        (normal * std_dev * torus_modulus)
+            .round()
+            .rem_euclid(torus_modulus) as u32
+    }
+
+    fn uniform_u64(&mut self) -> u64 {
+        u64::from_le_bytes([0; 8].map(|_| self.next_byte()))
+    }
+
+    fn next_byte(&mut self) -> u8 {
+        self.generator
+            .next_byte()
+            .expect("The random generator is exhausted")
+    }
+}
@@ -65,12 +65,83 @@ use crate::server_key::ServerKey;
 #[cfg(test)]
 use rand::Rng;
 
+/// Records operations in the counters of the `stats` module, e.g.
+/// `record_stats!(linear_ops += 2, bootstraps += 1)`.
+#[cfg(feature = "stats")]
+macro_rules! record_stats {
+    ($($field:ident += $value:expr),+ $(,)?) => {
+        $crate::stats::record(|stats| {
+            $(stats.$field += $value;)+
+        })
+    };
+}
+
+#[cfg(not(feature = "stats"))]
+macro_rules! record_stats {
+    ($($field:ident += $value:expr),+ $(,)?) => {};
+}
+
+/// Evaluates a gate, recording its duration in the `stats` module.
+#[cfg(feature = "stats")]
+macro_rules! record_gate {
+    ($gate:ident, $body:expr) => {
+        $crate::stats::record_gate($crate::stats::GateKind::$gate, || $body)
+    };
+}
+
+#[cfg(not(feature = "stats"))]
+macro_rules! record_gate {
+    ($gate:ident, $body:expr) => {
+        $body
+    };
+}
+
+/// Implements `Serialize` and `Deserialize` for a type through the versioned envelope of the
+/// `serialization` module, as well as `Deserialize` for its `Legacy` wrapper.
+macro_rules! impl_versioned_serde {
+    ($type:ty) => {
+        impl serde::Serialize for $type {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                $crate::serialization::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                $crate::serialization::deserialize(deserializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $crate::serialization::Legacy<$type> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                $crate::serialization::deserialize_legacy(deserializer)
+            }
+        }
+    };
+}
+
+pub mod bitvec;
 pub mod ciphertext;
+pub mod circuit;
 pub mod client_key;
 pub mod engine;
 pub mod parameters;
 pub mod prelude;
+pub mod public_key;
+pub mod serialization;
 pub mod server_key;
+#[cfg(feature = "stats")]
+pub mod stats;
+pub mod threshold;
 
 /// The scaling factor used for the plaintext
 pub(crate) const PLAINTEXT_LOG_SCALING_FACTOR: usize = 3;
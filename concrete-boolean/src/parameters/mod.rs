@@ -17,13 +17,50 @@
 //! Note that if you desire, you can also create your own set of parameters.
 //! This is an unsafe operation as failing to properly fix the parameters will potentially result
 //! with an incorrect and/or insecure computation.
+//!
+//! The [`NoiseEstimate`] of a parameter set gives its expected gate failure probability, and
+//! [`BooleanParameters::new_checked`] rejects the sets failing too often.
 // TODO: speak about the lattice estimator and give the explicit used commit for the parameters
 
+mod noise;
+#[cfg(test)]
+mod tests;
+
+pub use noise::NoiseEstimate;
+
 pub use concrete_core::prelude::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
     StandardDev,
 };
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Error returned when a parameter set is rejected.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ParametersError {
+    /// A parameter is out of its valid range, the field is its name.
+    InvalidParameter(&'static str),
+    /// The estimated gate failure probability exceeds the requested maximum.
+    FailureProbabilityTooHigh { estimated: f64, max: f64 },
+}
+
+impl Display for ParametersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParametersError::InvalidParameter(name) => {
+                write!(f, "The parameter {} is out of its valid range", name)
+            }
+            ParametersError::FailureProbabilityTooHigh { estimated, max } => write!(
+                f,
+                "The estimated failure probability 2^{:.1} exceeds the maximum 2^{:.1}",
+                estimated.log2(),
+                max.log2()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParametersError {}
 
 /// A set of cryptographic parameters for homomorphic Boolean circuit evaluation.
 #[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -72,6 +109,104 @@ impl BooleanParameters {
             ks_base_log,
         }
     }
+
+    /// Constructs a new set of parameters for boolean circuit evaluation, checking that every
+    /// parameter is in its valid range, and that the estimated failure probability of the gates
+    /// does not exceed `max_failure_probability`.
+    ///
+    /// The failure probability is the one of the worst two-input gate, see
+    /// [`NoiseEstimate::failure_probability`].
+    ///
+    /// # Security
+    ///
+    /// Only the correctness of the computation is checked, the security of the parameters is
+    /// __not__: the dimensions and noise standard deviations still have to be chosen with a
+    /// lattice estimator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::parameters::*;
+    ///
+    /// let parameters = BooleanParameters::new_checked(
+    ///     LweDimension(630),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    ///     StandardDev(0.000_043_158_372_875_155_5),
+    ///     StandardDev(0.000_000_034_233_878_701_836_9),
+    ///     DecompositionBaseLog(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(2),
+    ///     DecompositionLevelCount(8),
+    ///     2f64.powi(-165),
+    /// );
+    /// assert_eq!(parameters, Ok(TFHE_LIB_PARAMETERS));
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        lwe_dimension: LweDimension,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        lwe_modular_std_dev: StandardDev,
+        glwe_modular_std_dev: StandardDev,
+        pbs_base_log: DecompositionBaseLog,
+        pbs_level: DecompositionLevelCount,
+        ks_base_log: DecompositionBaseLog,
+        ks_level: DecompositionLevelCount,
+        max_failure_probability: f64,
+    ) -> Result<BooleanParameters, ParametersError> {
+        if lwe_dimension.0 == 0 {
+            return Err(ParametersError::InvalidParameter("lwe_dimension"));
+        }
+        if glwe_dimension.0 == 0 {
+            return Err(ParametersError::InvalidParameter("glwe_dimension"));
+        }
+        // the bootstrap works in the negacyclic ring of a power of two degree
+        if polynomial_size.0 < 2 || !polynomial_size.0.is_power_of_two() {
+            return Err(ParametersError::InvalidParameter("polynomial_size"));
+        }
+        // written so that a NaN standard deviation is rejected
+        if !(lwe_modular_std_dev.0 > 0. && lwe_modular_std_dev.0 < 1.) {
+            return Err(ParametersError::InvalidParameter("lwe_modular_std_dev"));
+        }
+        if !(glwe_modular_std_dev.0 > 0. && glwe_modular_std_dev.0 < 1.) {
+            return Err(ParametersError::InvalidParameter("glwe_modular_std_dev"));
+        }
+        // the decompositions can not be more precise than the 32 bits of the ciphertexts
+        if pbs_base_log.0 == 0 || pbs_level.0 == 0 || pbs_base_log.0 * pbs_level.0 > 32 {
+            return Err(ParametersError::InvalidParameter("pbs_base_log"));
+        }
+        if ks_base_log.0 == 0 || ks_level.0 == 0 || ks_base_log.0 * ks_level.0 > 32 {
+            return Err(ParametersError::InvalidParameter("ks_base_log"));
+        }
+
+        let parameters = BooleanParameters {
+            lwe_dimension,
+            glwe_dimension,
+            polynomial_size,
+            lwe_modular_std_dev,
+            glwe_modular_std_dev,
+            pbs_base_log,
+            pbs_level,
+            ks_base_log,
+            ks_level,
+        };
+        let estimated = parameters.noise_estimate().failure_probability();
+        // written so that a NaN estimate is rejected
+        if estimated <= max_failure_probability {
+            Ok(parameters)
+        } else {
+            Err(ParametersError::FailureProbabilityTooHigh {
+                estimated,
+                max: max_failure_probability,
+            })
+        }
+    }
+
+    /// Estimates the noise of the gates evaluated with this parameter set.
+    pub fn noise_estimate(&self) -> NoiseEstimate {
+        NoiseEstimate::new(self)
+    }
 }
 
 /// Default parameter set.
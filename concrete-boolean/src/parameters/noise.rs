@@ -0,0 +1,145 @@
+//! Noise and failure probability estimation for a parameter set.
+//!
+//! The estimation follows the usual TFHE noise analysis, with uniform binary secret keys and
+//! independent errors. The FFT error of the bootstrap is neglected. All the variances are given on
+//! the torus, i.e. for a modulus normalized to 1.
+
+use super::BooleanParameters;
+
+/// Squared 2-norm and decision margin of the AND, NAND, OR and NOR gates, which sum their inputs
+/// encoded as $\pm 1/8$: the phases lie $1/8$ away from the decision thresholds.
+const SUM_GATE: (f64, f64) = (2., 1. / 8.);
+
+/// Squared 2-norm and decision margin of the XOR and XNOR gates, which double the sum of their
+/// inputs: the phases lie $1/4$ away from the decision thresholds.
+const XOR_GATE: (f64, f64) = (8., 1. / 4.);
+
+/// Squared 2-norm and decision margin of the MAJ3 gate, which sums its three inputs: the phases
+/// lie $1/8$ away from the decision threshold.
+const MAJ3_GATE: (f64, f64) = (3., 1. / 8.);
+
+/// Squared 2-norm and decision margin of the XOR3 gate, which doubles the sum of its three inputs:
+/// the phases lie $1/4$ away from the decision thresholds.
+const XOR3_GATE: (f64, f64) = (12., 1. / 4.);
+
+/// The noise estimates of a parameter set.
+///
+/// The output of a gate goes through a bootstrap then a keyswitch, so every ciphertext output by
+/// the [`ServerKey`](crate::server_key::ServerKey) carries the
+/// [`output_variance`](Self::output_variance). A gate fails when the noise of the linear
+/// combination of its inputs, plus the modulus switching noise, moves the phase past a decision
+/// threshold.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NoiseEstimate {
+    /// Variance of the output of the bootstrap.
+    pub bootstrap_variance: f64,
+    /// Variance added by the keyswitch.
+    pub keyswitch_variance: f64,
+    /// Variance added by the modulus switching at the start of the bootstrap.
+    pub modulus_switching_variance: f64,
+}
+
+impl NoiseEstimate {
+    /// Estimates the noise of a parameter set.
+    pub fn new(parameters: &BooleanParameters) -> NoiseEstimate {
+        let lwe_dimension = parameters.lwe_dimension.0 as f64;
+        let glwe_dimension = parameters.glwe_dimension.0 as f64;
+        let polynomial_size = parameters.polynomial_size.0 as f64;
+        // dimension of the LWE ciphertext extracted from the bootstrap
+        let big_lwe_dimension = glwe_dimension * polynomial_size;
+
+        // Bootstrap: one external product per coefficient of the LWE mask. The bootstrap key is
+        // encrypted under the GLWE noise, and the decomposition of the accumulator is rounded.
+        let pbs_base = 2f64.powi(parameters.pbs_base_log.0 as i32);
+        let pbs_level = parameters.pbs_level.0 as f64;
+        let glwe_variance = parameters.glwe_modular_std_dev.0.powi(2);
+        let external_product_variance =
+            (glwe_dimension + 1.) * pbs_level * polynomial_size * (pbs_base * pbs_base + 2.) / 12.
+                * glwe_variance
+                + (1. + big_lwe_dimension / 2.) * pbs_base.powf(-2. * pbs_level) / 12.;
+        let bootstrap_variance = lwe_dimension * external_product_variance;
+
+        // Keyswitch: each digit of the decomposed input mask multiplies a keyswitching key
+        // ciphertext encrypted under the LWE noise, and the mask is rounded to the precision of the
+        // decomposition.
+        let ks_base = 2f64.powi(parameters.ks_base_log.0 as i32);
+        let ks_level = parameters.ks_level.0 as f64;
+        let ks_precision = 2f64.powf(-((parameters.ks_base_log.0 * parameters.ks_level.0) as f64));
+        let lwe_variance = parameters.lwe_modular_std_dev.0.powi(2);
+        let keyswitch_variance = big_lwe_dimension * ks_level * (ks_base * ks_base + 2.) / 12.
+            * lwe_variance
+            + big_lwe_dimension / 2. * ks_precision * ks_precision / 12.;
+
+        // Modulus switching: the mask and body are rounded to a multiple of 1 / 2N.
+        let modulus_switching_variance =
+            (1. + lwe_dimension / 2.) / (48. * polynomial_size * polynomial_size);
+
+        NoiseEstimate {
+            bootstrap_variance,
+            keyswitch_variance,
+            modulus_switching_variance,
+        }
+    }
+
+    /// Returns the variance of a ciphertext output by a gate.
+    pub fn output_variance(&self) -> f64 {
+        self.bootstrap_variance + self.keyswitch_variance
+    }
+
+    /// Returns the failure probability of the worst two-input gate, when its inputs are outputs
+    /// of other gates.
+    pub fn failure_probability(&self) -> f64 {
+        let (sum_norm, sum_margin) = SUM_GATE;
+        let (xor_norm, xor_margin) = XOR_GATE;
+        self.gate_failure_probability(sum_norm, sum_margin)
+            .max(self.gate_failure_probability(xor_norm, xor_margin))
+    }
+
+    /// Returns the failure probability of the worst single bootstrap three-input gate,
+    /// [`maj3`](crate::server_key::ServerKey::maj3) or
+    /// [`xor3`](crate::server_key::ServerKey::xor3), when its inputs are outputs of other gates.
+    ///
+    /// It is higher than the [`failure_probability`](Self::failure_probability) of the two-input
+    /// gates, as the noise of three inputs is summed.
+    pub fn three_input_failure_probability(&self) -> f64 {
+        let (maj3_norm, maj3_margin) = MAJ3_GATE;
+        let (xor3_norm, xor3_margin) = XOR3_GATE;
+        self.gate_failure_probability(maj3_norm, maj3_margin)
+            .max(self.gate_failure_probability(xor3_norm, xor3_margin))
+    }
+
+    /// Returns the failure probability of a gate whose bootstrap input is a linear combination of
+    /// gate outputs, given the squared 2-norm of its coefficients and the distance between the
+    /// phases it can take and the decision thresholds of the bootstrap.
+    ///
+    /// For instance, the AND gate sums its two inputs (2, with a margin of 1/8), the XOR gate
+    /// doubles their sum (8, with a margin of 1/4) and the XOR3 gate doubles the sum of its three
+    /// inputs (12, with a margin of 1/4).
+    pub fn gate_failure_probability(&self, squared_norm: f64, decision_margin: f64) -> f64 {
+        let variance = squared_norm * self.output_variance() + self.modulus_switching_variance;
+        erfc(decision_margin / (2. * variance).sqrt())
+    }
+}
+
+/// Complementary error function, with a relative error below $1.2 \cdot 10^{-7}$.
+///
+/// Uses the Chebyshev fitting from Numerical Recipes, which stays accurate for the tiny values
+/// taken by the failure probabilities.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1. / (1. + 0.5 * z);
+    let polynomial = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let result = t * polynomial.exp();
+    if x >= 0. {
+        result
+    } else {
+        2. - result
+    }
+}
@@ -0,0 +1,94 @@
+use crate::parameters::{
+    BooleanParameters, ParametersError, PolynomialSize, StandardDev, DEFAULT_PARAMETERS,
+    TFHE_LIB_PARAMETERS,
+};
+
+#[test]
+fn test_noise_estimate_default_parameters() {
+    let estimate = DEFAULT_PARAMETERS.noise_estimate();
+
+    // the keyswitch dominates the output noise
+    assert!(estimate.keyswitch_variance > estimate.bootstrap_variance);
+
+    // the documented error probability
+    let failure = estimate.failure_probability();
+    assert!(failure <= 2f64.powi(-25), "2^{}", failure.log2());
+
+    // the three-input gates sum more noise, but still fit the documented error probability
+    let three_input_failure = estimate.three_input_failure_probability();
+    assert!(three_input_failure > failure);
+    assert!(
+        three_input_failure <= 2f64.powi(-25),
+        "2^{}",
+        three_input_failure.log2()
+    );
+}
+
+#[test]
+fn test_noise_estimate_tfhe_lib_parameters() {
+    let estimate = TFHE_LIB_PARAMETERS.noise_estimate();
+
+    // the documented error probability
+    let failure = estimate.failure_probability();
+    assert!(failure <= 2f64.powi(-165), "2^{}", failure.log2());
+    assert!(failure < DEFAULT_PARAMETERS.noise_estimate().failure_probability());
+
+    // doubling the sum also doubles the margin, the XOR gate fails less often than the AND gate
+    assert!(estimate.gate_failure_probability(8., 1. / 4.) < failure);
+    assert_eq!(estimate.gate_failure_probability(2., 1. / 8.), failure);
+
+    // the MAJ3 gate sums three inputs with the margin of the AND gate
+    assert_eq!(
+        estimate.three_input_failure_probability(),
+        estimate.gate_failure_probability(3., 1. / 8.)
+    );
+}
+
+#[test]
+fn test_new_checked() {
+    let new_checked = |polynomial_size, glwe_modular_std_dev, max_failure_probability| {
+        BooleanParameters::new_checked(
+            TFHE_LIB_PARAMETERS.lwe_dimension,
+            TFHE_LIB_PARAMETERS.glwe_dimension,
+            polynomial_size,
+            TFHE_LIB_PARAMETERS.lwe_modular_std_dev,
+            glwe_modular_std_dev,
+            TFHE_LIB_PARAMETERS.pbs_base_log,
+            TFHE_LIB_PARAMETERS.pbs_level,
+            TFHE_LIB_PARAMETERS.ks_base_log,
+            TFHE_LIB_PARAMETERS.ks_level,
+            max_failure_probability,
+        )
+    };
+    let polynomial_size = TFHE_LIB_PARAMETERS.polynomial_size;
+    let glwe_modular_std_dev = TFHE_LIB_PARAMETERS.glwe_modular_std_dev;
+
+    assert_eq!(
+        new_checked(polynomial_size, glwe_modular_std_dev, 2f64.powi(-165)),
+        Ok(TFHE_LIB_PARAMETERS)
+    );
+    assert!(matches!(
+        new_checked(polynomial_size, glwe_modular_std_dev, 2f64.powi(-200)),
+        Err(ParametersError::FailureProbabilityTooHigh { .. })
+    ));
+
+    // a noisy bootstrapping key makes almost every gate fail
+    let result = new_checked(polynomial_size, StandardDev(2f64.powi(-10)), 0.01);
+    match result {
+        Err(ParametersError::FailureProbabilityTooHigh { estimated, max }) => {
+            assert!(estimated > 0.1);
+            assert_eq!(max, 0.01);
+        }
+        _ => panic!("expected the parameters to be rejected, got {:?}", result),
+    }
+
+    // parameters out of their range
+    assert_eq!(
+        new_checked(PolynomialSize(1000), glwe_modular_std_dev, 1.),
+        Err(ParametersError::InvalidParameter("polynomial_size"))
+    );
+    assert_eq!(
+        new_checked(polynomial_size, StandardDev(f64::NAN), 1.),
+        Err(ParametersError::InvalidParameter("glwe_modular_std_dev"))
+    );
+}
@@ -1,5 +1,7 @@
 #![doc(hidden)]
+pub use super::bitvec::FheBits;
 pub use super::client_key::ClientKey;
 pub use super::gen_keys;
+pub use super::public_key::PublicKey;
 pub use super::server_key::{BinaryBooleanGates, ServerKey};
 // pub use super::engine::BinaryGatesEngine;
@@ -0,0 +1,134 @@
+//! The public key of the client.
+//!
+//! This module implements the generation of the client's public key, together with the
+//! encryption method. Anyone holding the public key can encrypt Boolean messages that the
+//! corresponding [`ClientKey`] decrypts, without having access to the secret key.
+
+#[cfg(test)]
+mod tests;
+
+use crate::ciphertext::Ciphertext;
+use crate::client_key::ClientKey;
+use crate::engine::{CpuBooleanEngine, WithThreadLocalEngine};
+use crate::parameters::BooleanParameters;
+use crate::serialization::Versioned;
+use concrete_core::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A structure containing a public key.
+///
+/// In more details, it contains:
+/// * `lwe_public_key` - a set of LWE encryptions of zero under the client's LWE secret key.
+/// * `parameters` - the cryptographic parameter set.
+#[derive(Clone, Debug)]
+pub struct PublicKey {
+    pub(crate) lwe_public_key: LwePublicKey32,
+    pub(crate) parameters: BooleanParameters,
+}
+
+impl PublicKey {
+    /// Encrypts a Boolean message using the public key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "cuda"))]
+    /// # fn main() {
+    /// use concrete_boolean::prelude::*;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, _) = gen_keys();
+    ///
+    /// let pks = PublicKey::new(&cks);
+    ///
+    /// // Encryption of one message:
+    /// let ct_res = pks.encrypt(true);
+    ///
+    /// // Decryption:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(true, dec);
+    /// # }
+    /// # #[cfg(feature = "cuda")]
+    /// # fn main() {}
+    /// ```
+    pub fn encrypt(&self, message: bool) -> Ciphertext {
+        CpuBooleanEngine::with_thread_local_mut(|engine| {
+            engine.encrypt_with_public_key(message, self)
+        })
+    }
+
+    /// Allocates and generates a public key from a client key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "cuda"))]
+    /// # fn main() {
+    /// use concrete_boolean::prelude::*;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, _) = gen_keys();
+    ///
+    /// let pks = PublicKey::new(&cks);
+    /// # }
+    /// # #[cfg(feature = "cuda")]
+    /// # fn main() {}
+    /// ```
+    pub fn new(client_key: &ClientKey) -> PublicKey {
+        CpuBooleanEngine::with_thread_local_mut(|engine| engine.create_public_key(client_key))
+    }
+
+    /// Returns the parameters the public key was generated with.
+    pub fn parameters(&self) -> BooleanParameters {
+        self.parameters
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializablePublicKey {
+    lwe_public_key: Vec<u8>,
+    parameters: BooleanParameters,
+}
+
+impl Versioned for PublicKey {
+    const MAGIC: [u8; 4] = *b"CBPK";
+
+    fn envelope_parameters(&self) -> Option<BooleanParameters> {
+        Some(self.parameters)
+    }
+
+    fn serialize_payload<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_eng = DefaultSerializationEngine::new(()).map_err(serde::ser::Error::custom)?;
+
+        let lwe_public_key = ser_eng
+            .serialize(&self.lwe_public_key)
+            .map_err(serde::ser::Error::custom)?;
+
+        SerializablePublicKey {
+            lwe_public_key,
+            parameters: self.parameters,
+        }
+        .serialize(serializer)
+    }
+
+    fn deserialize_payload<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let thing =
+            SerializablePublicKey::deserialize(deserializer).map_err(serde::de::Error::custom)?;
+        let mut de_eng = DefaultSerializationEngine::new(()).map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            lwe_public_key: de_eng
+                .deserialize(thing.lwe_public_key.as_slice())
+                .map_err(serde::de::Error::custom)?,
+            parameters: thing.parameters,
+        })
+    }
+}
+
+impl_versioned_serde!(PublicKey);
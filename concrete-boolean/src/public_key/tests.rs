@@ -0,0 +1,137 @@
+use crate::client_key::ClientKey;
+use crate::parameters::BooleanParameters;
+use crate::public_key::PublicKey;
+use crate::random_boolean;
+use crate::server_key::{BinaryBooleanGates, ServerKey};
+
+/// Number of assert in randomized tests
+const NB_TEST: usize = 32;
+
+#[cfg(not(feature = "cuda"))]
+mod default_parameters_tests {
+    use super::*;
+    use crate::parameters::DEFAULT_PARAMETERS;
+
+    #[test]
+    fn test_public_key_encrypt_decrypt_default_parameters() {
+        test_public_key_encrypt_decrypt(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_public_key_gates_default_parameters() {
+        test_public_key_gates(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_public_key_serialization_default_parameters() {
+        test_public_key_serialization(DEFAULT_PARAMETERS);
+    }
+}
+
+mod tfhe_lib_parameters_tests {
+    use super::*;
+    use crate::parameters::TFHE_LIB_PARAMETERS;
+
+    #[test]
+    fn test_public_key_encrypt_decrypt_tfhe_lib_parameters() {
+        test_public_key_encrypt_decrypt(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_public_key_gates_tfhe_lib_parameters() {
+        test_public_key_gates(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_public_key_serialization_tfhe_lib_parameters() {
+        test_public_key_serialization(TFHE_LIB_PARAMETERS);
+    }
+}
+
+/// test encryption with the public key and decryption with the LWE secret key
+fn test_public_key_encrypt_decrypt(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the public key
+    let pks = PublicKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // encryption of false
+        let ct_false = pks.encrypt(false);
+
+        // encryption of true
+        let ct_true = pks.encrypt(true);
+
+        // decryption of false
+        let dec_false = cks.decrypt(&ct_false);
+
+        // decryption of true
+        let dec_true = cks.decrypt(&ct_true);
+
+        // assert
+        assert!(!dec_false);
+        assert!(dec_true);
+    }
+}
+
+/// test that the gates of the server key accept public key encryptions
+fn test_public_key_gates(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // generate the public key
+    let pks = PublicKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+
+        // encryption with the public key
+        let ct1 = pks.encrypt(b1);
+        let ct2 = pks.encrypt(b2);
+        let ct3 = pks.encrypt(b3);
+
+        // AND gate
+        let ct_res = sks.and(&ct1, &ct2);
+        assert_eq!(b1 && b2, cks.decrypt(&ct_res));
+
+        // XOR gate
+        let ct_res = sks.xor(&ct1, &ct2);
+        assert_eq!(b1 ^ b2, cks.decrypt(&ct_res));
+
+        // NOT gate
+        let ct_res = sks.not(&ct1);
+        assert_eq!(!b1, cks.decrypt(&ct_res));
+
+        // MUX gate
+        let ct_res = sks.mux(&ct1, &ct2, &ct3);
+        assert_eq!(if b1 { b2 } else { b3 }, cks.decrypt(&ct_res));
+
+        // mixing public key and secret key encryptions
+        let ct4 = cks.encrypt(b3);
+        let ct_res = sks.or(&ct1, &ct4);
+        assert_eq!(b1 || b3, cks.decrypt(&ct_res));
+    }
+}
+
+/// test that a serialized public key still produces valid encryptions
+fn test_public_key_serialization(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the public key
+    let pks = PublicKey::new(&cks);
+
+    // round-trip through bincode
+    let serialized = bincode::serialize(&pks).unwrap();
+    let pks: PublicKey = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(pks.parameters(), parameters);
+
+    for _ in 0..NB_TEST {
+        let b = random_boolean();
+        let ct = pks.encrypt(b);
+        assert_eq!(b, cks.decrypt(&ct));
+    }
+}
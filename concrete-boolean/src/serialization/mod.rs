@@ -0,0 +1,332 @@
+//! Versioned serialization format.
+//!
+//! The keys and ciphertexts of this crate are serialized inside a self-describing envelope,
+//! made of:
+//! * a 4-byte magic identifying the type of the object,
+//! * the [`FORMAT_VERSION`] the object was written with,
+//! * the [`BooleanParameters`] of the object, when it knows them,
+//! * the payload.
+//!
+//! The magic of each type, and whether its envelope holds parameters:
+//! * [`ClientKey`](crate::client_key::ClientKey): `CBCK`, with parameters;
+//! * [`PublicKey`](crate::public_key::PublicKey): `CBPK`, with parameters;
+//! * [`ServerKey`](crate::server_key::ServerKey): `CBSK`, with parameters unless it was built
+//!   from a bootstrap key alone;
+//! * [`CompressedServerKey`](crate::server_key::CompressedServerKey): `CBCS`, with parameters;
+//! * [`Ciphertext`](crate::ciphertext::Ciphertext): `CBCT`, without parameters, as a ciphertext
+//!   does not keep the ones of the key it was encrypted with;
+//! * [`CompressedCiphertext`](crate::ciphertext::CompressedCiphertext): `CBCC`, with parameters;
+//! * [`SecretKeyShare`](crate::threshold::SecretKeyShare): `CBKS`, with parameters.
+//!
+//! Loading an object checks the envelope before reading the payload, and fails with one of the
+//! [`SerializationError`] messages when the data holds another type of object, was written by a
+//! newer version of the crate, or in a format too old to be migrated. The payloads written by
+//! older supported versions are migrated to the current format when loaded.
+//!
+//! The parameters of the envelope are then checked against the payload: they have to be the ones
+//! the payload holds, or, for the objects not storing them in their payload, to match the
+//! dimensions of their keys or ciphertexts.
+//!
+//! The data written before the introduction of the envelope has no magic, it can still be loaded
+//! through the [`Legacy`] wrapper.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(not(feature = "cuda"))]
+//! # fn main() {
+//! use concrete_boolean::ciphertext::Ciphertext;
+//! use concrete_boolean::prelude::*;
+//!
+//! let (cks, _) = gen_keys();
+//!
+//! let serialized = bincode::serialize(&cks).unwrap();
+//! assert_eq!(&serialized[..4], b"CBCK");
+//!
+//! // A client key can't be loaded as a ciphertext
+//! let error = bincode::deserialize::<Ciphertext>(&serialized).unwrap_err();
+//! assert!(error.to_string().contains("magic"));
+//!
+//! let deserialized: ClientKey = bincode::deserialize(&serialized).unwrap();
+//! assert_eq!(cks, deserialized);
+//! # }
+//! # #[cfg(feature = "cuda")]
+//! # fn main() {}
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use crate::parameters::BooleanParameters;
+use serde::de::{DeserializeSeed, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+
+/// The version of the format written by this version of the crate.
+pub const FORMAT_VERSION: u16 = 1;
+
+/// The oldest version of the format which can still be loaded.
+pub const OLDEST_SUPPORTED_FORMAT_VERSION: u16 = 1;
+
+/// Error returned when the envelope of a serialized object is rejected.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SerializationError {
+    /// The data does not start with the magic of the expected type: it holds another type of
+    /// object, or was written before the format was versioned.
+    InvalidMagic { expected: [u8; 4], found: [u8; 4] },
+    /// The data was written by a newer version of the crate.
+    NewerVersion { found: u16, current: u16 },
+    /// The data was written in a format too old to be migrated.
+    ObsoleteVersion { found: u16, oldest: u16 },
+    /// The parameters of the envelope do not match the ones of the payload.
+    ParametersMismatch {
+        envelope: Option<BooleanParameters>,
+        payload: Option<BooleanParameters>,
+    },
+    /// The dimensions of the payload do not match the parameters of the envelope.
+    IncompatibleParameters { envelope: BooleanParameters },
+}
+
+impl Display for SerializationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SerializationError::InvalidMagic { expected, found } => write!(
+                f,
+                "Invalid magic {:?}, expected {:?}: the data holds another type of object, or was \
+                 written before the format was versioned",
+                String::from_utf8_lossy(found),
+                String::from_utf8_lossy(expected)
+            ),
+            SerializationError::NewerVersion { found, current } => write!(
+                f,
+                "The data was written with format version {}, newer than the version {} \
+                 supported by this version of concrete-boolean",
+                found, current
+            ),
+            SerializationError::ObsoleteVersion { found, oldest } => write!(
+                f,
+                "The data was written with format version {}, older than the oldest version {} \
+                 which can be migrated",
+                found, oldest
+            ),
+            SerializationError::ParametersMismatch { envelope, payload } => write!(
+                f,
+                "The parameters of the envelope {:?} do not match the ones of the payload {:?}",
+                envelope, payload
+            ),
+            SerializationError::IncompatibleParameters { envelope } => write!(
+                f,
+                "The dimensions of the payload do not match the parameters of the envelope {:?}",
+                envelope
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SerializationError {}
+
+/// An object written before the format was versioned.
+///
+/// Deserializing a `Legacy<T>` reads a `T` without envelope, as written by the versions of the
+/// crate prior to [`FORMAT_VERSION`] 1. Serializing the inner object again writes it in the
+/// current format.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_boolean::ciphertext::Ciphertext;
+/// use concrete_boolean::serialization::Legacy;
+///
+/// // A trivial encryption of true, written before the envelope was introduced
+/// let legacy_data = [1, 0, 0, 0, 1];
+///
+/// let Legacy(ct): Legacy<Ciphertext> = bincode::deserialize(&legacy_data).unwrap();
+/// assert!(matches!(ct, Ciphertext::Trivial(true)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Legacy<T>(pub T);
+
+/// An object serialized inside the versioned envelope.
+pub(crate) trait Versioned: Sized {
+    /// The magic identifying the type of the object.
+    const MAGIC: [u8; 4];
+
+    /// Returns the parameters written in the envelope.
+    fn envelope_parameters(&self) -> Option<BooleanParameters>;
+
+    /// Checks the parameters read from the envelope against the deserialized payload.
+    ///
+    /// By default they have to be the parameters the payload holds. The objects which do not
+    /// store them in their payload override this to check them against their dimensions, and to
+    /// take them from the envelope.
+    fn with_envelope_parameters(
+        self,
+        parameters: Option<BooleanParameters>,
+    ) -> Result<Self, SerializationError> {
+        if parameters != self.envelope_parameters() {
+            return Err(SerializationError::ParametersMismatch {
+                envelope: parameters,
+                payload: self.envelope_parameters(),
+            });
+        }
+        Ok(self)
+    }
+
+    /// Serializes the payload in the current format.
+    fn serialize_payload<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+
+    /// Deserializes a payload written in the current format.
+    fn deserialize_payload<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// Serializes an object inside the envelope.
+pub(crate) fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Versioned,
+    S: Serializer,
+{
+    let mut envelope = serializer.serialize_tuple(4)?;
+    envelope.serialize_element(&T::MAGIC)?;
+    envelope.serialize_element(&FORMAT_VERSION)?;
+    envelope.serialize_element(&value.envelope_parameters())?;
+    envelope.serialize_element(&Payload(value))?;
+    envelope.end()
+}
+
+/// Deserializes an object from its envelope, migrating its payload if needed.
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Versioned,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_tuple(
+        4,
+        EnvelopeVisitor {
+            marker: PhantomData,
+        },
+    )
+}
+
+/// Deserializes an object written without envelope.
+pub(crate) fn deserialize_legacy<'de, T, D>(deserializer: D) -> Result<Legacy<T>, D::Error>
+where
+    T: Versioned,
+    D: Deserializer<'de>,
+{
+    T::deserialize_payload(deserializer).map(Legacy)
+}
+
+struct Payload<'a, T>(&'a T);
+
+impl<T> Serialize for Payload<'_, T>
+where
+    T: Versioned,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_payload(serializer)
+    }
+}
+
+struct PayloadSeed<T> {
+    version: u16,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T> DeserializeSeed<'de> for PayloadSeed<T>
+where
+    T: Versioned,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // The payloads written by older versions are converted here when the format changes
+        match self.version {
+            FORMAT_VERSION => T::deserialize_payload(deserializer),
+            version => Err(serde::de::Error::custom(
+                SerializationError::ObsoleteVersion {
+                    found: version,
+                    oldest: OLDEST_SUPPORTED_FORMAT_VERSION,
+                },
+            )),
+        }
+    }
+}
+
+struct EnvelopeVisitor<T> {
+    marker: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for EnvelopeVisitor<T>
+where
+    T: Versioned,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a concrete-boolean object with magic {:?}",
+            String::from_utf8_lossy(&T::MAGIC)
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let magic: [u8; 4] = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+        if magic != T::MAGIC {
+            return Err(serde::de::Error::custom(SerializationError::InvalidMagic {
+                expected: T::MAGIC,
+                found: magic,
+            }));
+        }
+
+        let version: u16 = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+        if version > FORMAT_VERSION {
+            return Err(serde::de::Error::custom(SerializationError::NewerVersion {
+                found: version,
+                current: FORMAT_VERSION,
+            }));
+        }
+        if version < OLDEST_SUPPORTED_FORMAT_VERSION {
+            return Err(serde::de::Error::custom(
+                SerializationError::ObsoleteVersion {
+                    found: version,
+                    oldest: OLDEST_SUPPORTED_FORMAT_VERSION,
+                },
+            ));
+        }
+
+        let parameters: Option<BooleanParameters> = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(2, &self))?;
+
+        let value = seq
+            .next_element_seed(PayloadSeed {
+                version,
+                marker: PhantomData,
+            })?
+            .ok_or_else(|| serde::de::Error::invalid_length(3, &self))?;
+
+        value
+            .with_envelope_parameters(parameters)
+            .map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,346 @@
+use crate::ciphertext::{Ciphertext, CompressedCiphertext};
+use crate::client_key::ClientKey;
+use crate::parameters::{
+    BooleanParameters, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    PolynomialSize, StandardDev, DEFAULT_PARAMETERS, TFHE_LIB_PARAMETERS,
+};
+use crate::public_key::PublicKey;
+use crate::serialization::{Legacy, SerializationError, Versioned, FORMAT_VERSION};
+use crate::server_key::{BinaryBooleanGates, CompressedServerKey, ServerKey};
+use crate::threshold::SecretKeyShare;
+use serde::{Serialize, Serializer};
+use std::path::{Path, PathBuf};
+
+/// Trivial encryption of true in the format version 1
+const CIPHERTEXT_TRIVIAL_TRUE_V1: &[u8] = include_bytes!("fixtures/ciphertext_trivial_true_v1.bin");
+
+/// Trivial encryption of true, claiming a format version not released yet
+const CIPHERTEXT_TRIVIAL_TRUE_V2: &[u8] = include_bytes!("fixtures/ciphertext_trivial_true_v2.bin");
+
+/// Trivial encryption of true, claiming a format version older than the oldest supported one
+const CIPHERTEXT_TRIVIAL_TRUE_V0: &[u8] = include_bytes!("fixtures/ciphertext_trivial_true_v0.bin");
+
+/// Trivial encryption of false, written before the format was versioned
+const CIPHERTEXT_TRIVIAL_FALSE_LEGACY: &[u8] =
+    include_bytes!("fixtures/ciphertext_trivial_false_legacy.bin");
+
+/// Keys and encryptions of true with [`TFHE_LIB_PARAMETERS`], in the format version 1
+const CLIENT_KEY_TFHE_LIB_V1: &str = "client_key_tfhe_lib_v1.bin";
+const CIPHERTEXT_TFHE_LIB_TRUE_V1: &str = "ciphertext_tfhe_lib_true_v1.bin";
+const COMPRESSED_CIPHERTEXT_TFHE_LIB_TRUE_V1: &str = "compressed_ciphertext_tfhe_lib_true_v1.bin";
+
+/// Keys with [`fixture_parameters`], in the format version 1
+const CLIENT_KEY_SMALL_V1: &str = "client_key_small_v1.bin";
+const SERVER_KEY_SMALL_V1: &str = "server_key_small_v1.bin";
+const COMPRESSED_SERVER_KEY_SMALL_V1: &str = "compressed_server_key_small_v1.bin";
+
+/// Parameters of the server key fixtures.
+///
+/// A server key with [`TFHE_LIB_PARAMETERS`] weighs tens of megabytes, those parameters keep it
+/// around a hundred kilobytes while the gates stay correct. They are __not__ secure.
+fn fixture_parameters() -> BooleanParameters {
+    BooleanParameters::new_checked(
+        LweDimension(16),
+        GlweDimension(1),
+        PolynomialSize(128),
+        StandardDev(2f64.powi(-25)),
+        StandardDev(2f64.powi(-25)),
+        DecompositionBaseLog(10),
+        DecompositionLevelCount(2),
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(3),
+        2f64.powi(-64),
+    )
+    .unwrap()
+}
+
+fn fixture_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/serialization/fixtures")
+        .join(name)
+}
+
+/// Reads a fixture written by [`generate_fixtures`]
+fn read_fixture(name: &str) -> Vec<u8> {
+    let path = fixture_path(name);
+    std::fs::read(&path).unwrap_or_else(|err| {
+        panic!(
+            "cannot read the fixture {}: {}, run `cargo test -p concrete-boolean -- --ignored \
+             generate_fixtures` and commit the generated files",
+            path.display(),
+            err
+        )
+    })
+}
+
+fn write_fixture<T: Serialize>(name: &str, value: &T) {
+    std::fs::write(fixture_path(name), bincode::serialize(value).unwrap()).unwrap();
+}
+
+/// Writes the key and encrypted ciphertext fixtures.
+///
+/// Those fixtures must be generated once, when a format version is released, and never again:
+/// the tests check that the current version still reads them.
+#[test]
+#[ignore]
+fn generate_fixtures() {
+    let cks = ClientKey::new(&TFHE_LIB_PARAMETERS);
+    write_fixture(CLIENT_KEY_TFHE_LIB_V1, &cks);
+    write_fixture(CIPHERTEXT_TFHE_LIB_TRUE_V1, &cks.encrypt(true));
+    write_fixture(
+        COMPRESSED_CIPHERTEXT_TFHE_LIB_TRUE_V1,
+        &cks.encrypt_compressed(true),
+    );
+
+    let cks = ClientKey::new(&fixture_parameters());
+    write_fixture(CLIENT_KEY_SMALL_V1, &cks);
+    write_fixture(SERVER_KEY_SMALL_V1, &ServerKey::new(&cks));
+    write_fixture(
+        COMPRESSED_SERVER_KEY_SMALL_V1,
+        &CompressedServerKey::new(&cks),
+    );
+}
+
+/// Writes an object without envelope, as the versions prior to the format version 1 did
+struct LegacyWriter<'a, T>(&'a T);
+
+impl<T> Serialize for LegacyWriter<'_, T>
+where
+    T: Versioned,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_payload(serializer)
+    }
+}
+
+/// Serializes an object with bincode, checks its envelope and deserializes it back
+fn round_trip<T>(value: &T) -> T
+where
+    T: Versioned + Serialize + serde::de::DeserializeOwned,
+{
+    let serialized = bincode::serialize(value).unwrap();
+    assert_eq!(serialized[..4], T::MAGIC);
+    assert_eq!(serialized[4..6], FORMAT_VERSION.to_le_bytes());
+
+    bincode::deserialize(&serialized).unwrap()
+}
+
+/// Serializes an object without envelope and deserializes it through the `Legacy` wrapper
+fn legacy_round_trip<T>(value: &T) -> T
+where
+    T: Versioned,
+    Legacy<T>: serde::de::DeserializeOwned,
+{
+    let serialized = bincode::serialize(&LegacyWriter(value)).unwrap();
+    let Legacy(deserialized) = bincode::deserialize(&serialized).unwrap();
+    deserialized
+}
+
+fn assert_rejected<T>(data: &[u8], error: SerializationError)
+where
+    T: serde::de::DeserializeOwned,
+{
+    let result = bincode::deserialize::<T>(data);
+    let message = result
+        .err()
+        .expect("the data should be rejected")
+        .to_string();
+    assert!(
+        message.contains(&error.to_string()),
+        "unexpected error: {}",
+        message
+    );
+}
+
+#[test]
+fn test_fixture_v1() {
+    let ct: Ciphertext = bincode::deserialize(CIPHERTEXT_TRIVIAL_TRUE_V1).unwrap();
+    assert!(matches!(ct, Ciphertext::Trivial(true)));
+
+    // the current version still writes the same bytes
+    let serialized = bincode::serialize(&Ciphertext::Trivial(true)).unwrap();
+    assert_eq!(serialized, CIPHERTEXT_TRIVIAL_TRUE_V1);
+}
+
+#[test]
+fn test_fixture_encrypted_v1() {
+    let cks: ClientKey = bincode::deserialize(&read_fixture(CLIENT_KEY_TFHE_LIB_V1)).unwrap();
+    assert_eq!(*cks.parameters(), TFHE_LIB_PARAMETERS);
+
+    let ct: Ciphertext = bincode::deserialize(&read_fixture(CIPHERTEXT_TFHE_LIB_TRUE_V1)).unwrap();
+    assert!(matches!(ct, Ciphertext::Encrypted(_)));
+    assert!(cks.decrypt(&ct));
+
+    let compressed: CompressedCiphertext =
+        bincode::deserialize(&read_fixture(COMPRESSED_CIPHERTEXT_TFHE_LIB_TRUE_V1)).unwrap();
+    assert!(cks.decrypt(&compressed.decompress()));
+
+    // the key still encrypts messages the current version decrypts
+    assert!(!cks.decrypt(&round_trip(&cks.encrypt(false))));
+}
+
+#[test]
+fn test_fixture_server_key_v1() {
+    let cks: ClientKey = bincode::deserialize(&read_fixture(CLIENT_KEY_SMALL_V1)).unwrap();
+    assert_eq!(*cks.parameters(), fixture_parameters());
+
+    let sks: ServerKey = bincode::deserialize(&read_fixture(SERVER_KEY_SMALL_V1)).unwrap();
+    let compressed_sks: CompressedServerKey =
+        bincode::deserialize(&read_fixture(COMPRESSED_SERVER_KEY_SMALL_V1)).unwrap();
+
+    for sks in [sks, compressed_sks.decompress()] {
+        assert_eq!(sks.parameters(), Some(fixture_parameters()));
+        for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+            let ct = sks.xor(&cks.encrypt(a), &cks.encrypt(b));
+            assert_eq!(cks.decrypt(&ct), a ^ b);
+        }
+    }
+}
+
+#[test]
+fn test_fixture_legacy() {
+    let Legacy(ct): Legacy<Ciphertext> =
+        bincode::deserialize(CIPHERTEXT_TRIVIAL_FALSE_LEGACY).unwrap();
+    assert!(matches!(ct, Ciphertext::Trivial(false)));
+
+    // the data without envelope is not mistaken for the current format
+    let result = bincode::deserialize::<Ciphertext>(CIPHERTEXT_TRIVIAL_FALSE_LEGACY);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_fixture_rejected_versions() {
+    assert_rejected::<Ciphertext>(
+        CIPHERTEXT_TRIVIAL_TRUE_V2,
+        SerializationError::NewerVersion {
+            found: 2,
+            current: FORMAT_VERSION,
+        },
+    );
+    assert_rejected::<Ciphertext>(
+        CIPHERTEXT_TRIVIAL_TRUE_V0,
+        SerializationError::ObsoleteVersion {
+            found: 0,
+            oldest: 1,
+        },
+    );
+}
+
+#[test]
+fn test_fixture_rejected_type() {
+    assert_rejected::<ClientKey>(
+        CIPHERTEXT_TRIVIAL_TRUE_V1,
+        SerializationError::InvalidMagic {
+            expected: *b"CBCK",
+            found: *b"CBCT",
+        },
+    );
+    assert_rejected::<CompressedCiphertext>(
+        CIPHERTEXT_TRIVIAL_TRUE_V1,
+        SerializationError::InvalidMagic {
+            expected: *b"CBCC",
+            found: *b"CBCT",
+        },
+    );
+}
+
+#[test]
+fn test_parameters_mismatch() {
+    let cks = ClientKey::new(&TFHE_LIB_PARAMETERS);
+    let mut serialized = bincode::serialize(&cks).unwrap();
+
+    // replace the parameters of the envelope, which follow the magic and the version
+    let tampered_parameters = bincode::serialize(&Some(DEFAULT_PARAMETERS)).unwrap();
+    serialized[6..6 + tampered_parameters.len()].copy_from_slice(&tampered_parameters);
+
+    assert_rejected::<ClientKey>(
+        &serialized,
+        SerializationError::ParametersMismatch {
+            envelope: Some(DEFAULT_PARAMETERS),
+            payload: Some(TFHE_LIB_PARAMETERS),
+        },
+    );
+}
+
+#[test]
+fn test_incompatible_parameters() {
+    let cks = ClientKey::new(&TFHE_LIB_PARAMETERS);
+    let tampered_parameters = bincode::serialize(&Some(DEFAULT_PARAMETERS)).unwrap();
+    let error = SerializationError::IncompatibleParameters {
+        envelope: DEFAULT_PARAMETERS,
+    };
+
+    // replace the parameters of the envelope, which follow the magic and the version
+    let tamper = |mut serialized: Vec<u8>| {
+        serialized[6..6 + tampered_parameters.len()].copy_from_slice(&tampered_parameters);
+        serialized
+    };
+
+    let serialized = tamper(bincode::serialize(&ServerKey::new(&cks)).unwrap());
+    assert_rejected::<ServerKey>(&serialized, error);
+
+    let serialized = tamper(bincode::serialize(&CompressedServerKey::new(&cks)).unwrap());
+    assert_rejected::<CompressedServerKey>(&serialized, error);
+
+    let serialized = tamper(bincode::serialize(&cks.encrypt_compressed(true)).unwrap());
+    assert_rejected::<CompressedCiphertext>(&serialized, error);
+}
+
+#[test]
+fn test_round_trip() {
+    let cks = ClientKey::new(&TFHE_LIB_PARAMETERS);
+    let pks = PublicKey::new(&cks);
+    let sks = ServerKey::new(&cks);
+    let compressed_sks = CompressedServerKey::new(&cks);
+    let shares = cks.split_into_shares(2);
+
+    for deserialized_cks in [round_trip(&cks), legacy_round_trip(&cks)] {
+        assert_eq!(deserialized_cks, cks);
+    }
+
+    for deserialized_shares in [
+        shares
+            .iter()
+            .map(round_trip)
+            .collect::<Vec<SecretKeyShare>>(),
+        shares.iter().map(legacy_round_trip).collect(),
+    ] {
+        assert_eq!(deserialized_shares, shares);
+    }
+
+    for deserialized_pks in [round_trip(&pks), legacy_round_trip(&pks)] {
+        assert_eq!(deserialized_pks.parameters(), TFHE_LIB_PARAMETERS);
+        assert!(cks.decrypt(&deserialized_pks.encrypt(true)));
+    }
+
+    assert_eq!(round_trip(&sks).parameters(), Some(TFHE_LIB_PARAMETERS));
+    assert_eq!(
+        round_trip(&compressed_sks).decompress().parameters(),
+        Some(TFHE_LIB_PARAMETERS)
+    );
+
+    for deserialized_sks in [
+        round_trip(&sks),
+        legacy_round_trip(&sks),
+        round_trip(&compressed_sks).decompress(),
+        legacy_round_trip(&compressed_sks).decompress(),
+    ] {
+        let ct = deserialized_sks.and(&cks.encrypt(true), &cks.encrypt(true));
+        assert!(cks.decrypt(&ct));
+    }
+
+    for b in [false, true] {
+        for ct in [
+            round_trip(&cks.encrypt(b)),
+            legacy_round_trip(&cks.encrypt(b)),
+            round_trip(&cks.encrypt_compressed(b)).decompress(),
+            legacy_round_trip(&cks.encrypt_compressed(b)).decompress(),
+            round_trip(&Ciphertext::Trivial(b)),
+        ] {
+            assert_eq!(cks.decrypt(&ct), b);
+        }
+    }
+}
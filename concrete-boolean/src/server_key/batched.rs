@@ -0,0 +1,164 @@
+//! Batched evaluation of the Boolean gates.
+//!
+//! Each function of this module evaluates the same gate on many independent inputs. On the CPU,
+//! the inputs are dispatched over the rayon thread pool: every worker thread uses its own
+//! thread-local engine, and therefore its own bootstrapping buffers.
+
+use super::{BinaryBooleanGates, ServerKey};
+use crate::ciphertext::Ciphertext;
+#[cfg(not(feature = "cuda"))]
+use rayon::prelude::*;
+
+/// Applies `func` to every input, in parallel over the rayon thread pool.
+#[cfg(not(feature = "cuda"))]
+pub(crate) fn map_batch<T, F>(inputs: &[T], func: F) -> Vec<Ciphertext>
+where
+    T: Sync,
+    F: Fn(&T) -> Ciphertext + Sync + Send,
+{
+    #[cfg(feature = "stats")]
+    {
+        // Each worker thread records in its own counters, they are gathered back here
+        let results: Vec<_> = inputs
+            .par_iter()
+            .map(|input| crate::stats::measure(|| func(input)))
+            .collect();
+        results
+            .into_iter()
+            .map(|(ct, stats)| {
+                crate::stats::record(|caller_stats| caller_stats.merge(&stats));
+                ct
+            })
+            .collect()
+    }
+    #[cfg(not(feature = "stats"))]
+    {
+        inputs.par_iter().map(func).collect()
+    }
+}
+
+/// Applies `func` to every input, sequentially as the cuda engine drives a single device.
+#[cfg(feature = "cuda")]
+pub(crate) fn map_batch<T, F>(inputs: &[T], func: F) -> Vec<Ciphertext>
+where
+    F: Fn(&T) -> Ciphertext,
+{
+    inputs.iter().map(func).collect()
+}
+
+impl ServerKey {
+    /// Computes the AND gate on every pair of ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "cuda"))]
+    /// # fn main() {
+    /// use concrete_boolean::prelude::*;
+    ///
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// let ct_1 = cks.encrypt(true);
+    /// let ct_2 = cks.encrypt(false);
+    /// let ct_3 = cks.encrypt(true);
+    ///
+    /// let ct_res = sks.and_many(&[(&ct_1, &ct_2), (&ct_1, &ct_3)]);
+    ///
+    /// assert_eq!(cks.decrypt(&ct_res[0]), false);
+    /// assert_eq!(cks.decrypt(&ct_res[1]), true);
+    /// # }
+    /// # #[cfg(feature = "cuda")]
+    /// # fn main() {}
+    /// ```
+    pub fn and_many(&self, ct_pairs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        map_batch(ct_pairs, |(ct_left, ct_right)| {
+            self.and(*ct_left, *ct_right)
+        })
+    }
+
+    /// Computes the NAND gate on every pair of ciphertexts.
+    pub fn nand_many(&self, ct_pairs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        map_batch(ct_pairs, |(ct_left, ct_right)| {
+            self.nand(*ct_left, *ct_right)
+        })
+    }
+
+    /// Computes the NOR gate on every pair of ciphertexts.
+    pub fn nor_many(&self, ct_pairs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        map_batch(ct_pairs, |(ct_left, ct_right)| {
+            self.nor(*ct_left, *ct_right)
+        })
+    }
+
+    /// Computes the OR gate on every pair of ciphertexts.
+    pub fn or_many(&self, ct_pairs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        map_batch(ct_pairs, |(ct_left, ct_right)| self.or(*ct_left, *ct_right))
+    }
+
+    /// Computes the XOR gate on every pair of ciphertexts.
+    pub fn xor_many(&self, ct_pairs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        map_batch(ct_pairs, |(ct_left, ct_right)| {
+            self.xor(*ct_left, *ct_right)
+        })
+    }
+
+    /// Computes the XNOR gate on every pair of ciphertexts.
+    pub fn xnor_many(&self, ct_pairs: &[(&Ciphertext, &Ciphertext)]) -> Vec<Ciphertext> {
+        map_batch(ct_pairs, |(ct_left, ct_right)| {
+            self.xnor(*ct_left, *ct_right)
+        })
+    }
+
+    /// Computes the NOT gate on every ciphertext.
+    pub fn not_many(&self, cts: &[&Ciphertext]) -> Vec<Ciphertext> {
+        map_batch(cts, |ct| self.not(ct))
+    }
+
+    /// Computes the MUX gate on every (condition, then, else) triplet of ciphertexts.
+    pub fn mux_many(
+        &self,
+        ct_triplets: &[(&Ciphertext, &Ciphertext, &Ciphertext)],
+    ) -> Vec<Ciphertext> {
+        map_batch(ct_triplets, |(ct_condition, ct_then, ct_else)| {
+            self.mux(ct_condition, ct_then, ct_else)
+        })
+    }
+
+    /// Computes the MAJ3 gate on every triplet of ciphertexts.
+    pub fn maj3_many(
+        &self,
+        ct_triplets: &[(&Ciphertext, &Ciphertext, &Ciphertext)],
+    ) -> Vec<Ciphertext> {
+        map_batch(ct_triplets, |(ct_a, ct_b, ct_c)| {
+            self.maj3(ct_a, ct_b, ct_c)
+        })
+    }
+
+    /// Computes the XOR3 gate on every triplet of ciphertexts.
+    pub fn xor3_many(
+        &self,
+        ct_triplets: &[(&Ciphertext, &Ciphertext, &Ciphertext)],
+    ) -> Vec<Ciphertext> {
+        map_batch(ct_triplets, |(ct_a, ct_b, ct_c)| {
+            self.xor3(ct_a, ct_b, ct_c)
+        })
+    }
+
+    /// Computes the AND3 gate on every triplet of ciphertexts.
+    pub fn and3_many(
+        &self,
+        ct_triplets: &[(&Ciphertext, &Ciphertext, &Ciphertext)],
+    ) -> Vec<Ciphertext> {
+        map_batch(ct_triplets, |(ct_a, ct_b, ct_c)| {
+            self.and3(ct_a, ct_b, ct_c)
+        })
+    }
+
+    /// Computes the OR3 gate on every triplet of ciphertexts.
+    pub fn or3_many(
+        &self,
+        ct_triplets: &[(&Ciphertext, &Ciphertext, &Ciphertext)],
+    ) -> Vec<Ciphertext> {
+        map_batch(ct_triplets, |(ct_a, ct_b, ct_c)| self.or3(ct_a, ct_b, ct_c))
+    }
+}
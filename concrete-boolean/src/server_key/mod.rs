@@ -3,21 +3,31 @@
 //! This module implements the generation of the server's public key, together with all the
 //! available homomorphic Boolean gates ($\mathrm{AND}$, $\mathrm{MUX}$, $\mathrm{NAND}$,
 //! $\mathrm{NOR}$,
-//! $\mathrm{NOT}$, $\mathrm{OR}$, $\mathrm{XNOR}$, $\mathrm{XOR}$).
+//! $\mathrm{NOT}$, $\mathrm{OR}$, $\mathrm{XNOR}$, $\mathrm{XOR}$) and the three-input gates
+//! ($\mathrm{MAJ3}$, $\mathrm{XOR3}$, $\mathrm{AND3}$, $\mathrm{OR3}$).
 
+mod batched;
 #[cfg(test)]
 mod tests;
 
-use serde::{Deserialize, Serialize};
+pub(crate) use batched::map_batch;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::ciphertext::Ciphertext;
 use crate::client_key::ClientKey;
-use crate::engine::bootstrapping::CpuBootstrapKey;
-#[cfg(feature = "cuda")]
-use crate::engine::{bootstrapping::CudaBootstrapKey, CudaBooleanEngine};
-use crate::engine::{BinaryGatesEngine, CpuBooleanEngine, WithThreadLocalEngine};
-#[cfg(feature = "cuda")]
-use std::sync::Arc;
+use crate::engine::bootstrapping::{
+    BooleanServerKey, Bootstrapper, CpuBootstrapKey, CpuCompressedBootstrapKey, DefaultBootstrapper,
+};
+use crate::engine::{BinaryGatesEngine, BooleanEngine, CpuBooleanEngine, WithThreadLocalEngine};
+use crate::parameters::{BooleanParameters, NoiseEstimate};
+use crate::serialization::{self, Legacy, SerializationError, Versioned};
+
+/// The highest estimated failure probability allowed for the gates whose weights depend on the
+/// gate, the single bootstrap three-input gates and the gates built from a truth table. It is the
+/// error probability of the [`DEFAULT_PARAMETERS`](crate::parameters::DEFAULT_PARAMETERS).
+const MAX_FAILURE_PROBABILITY: f64 = 1. / (1 << 25) as f64;
 
 pub trait BinaryBooleanGates<L, R> {
     fn and(&self, ct_left: L, ct_right: R) -> Ciphertext;
@@ -28,98 +38,82 @@ pub trait BinaryBooleanGates<L, R> {
     fn xnor(&self, ct_left: L, ct_right: R) -> Ciphertext;
 }
 
-trait RefFromServerKey {
-    fn get_ref(server_key: &ServerKey) -> &Self;
+/// A structure containing the server public key.
+///
+/// The gates are evaluated by the bootstrapper `B`, the [`DefaultBootstrapper`] unless another
+/// implementation of the [`Bootstrapper`] trait is plugged in with
+/// [`ServerKey::from_bootstrap_key`].
+///
+/// The batched gates, the bit vector operations and the circuit evaluation are only available
+/// with the default bootstrapper.
+pub struct ServerKey<B: Bootstrapper = DefaultBootstrapper> {
+    bootstrap_key: B::ServerKey,
+    parameters: Option<BooleanParameters>,
 }
 
-trait DefaultImplementation {
-    type Engine: WithThreadLocalEngine;
-    type BootsrapKey: RefFromServerKey;
-}
-
-#[derive(Clone)]
-pub struct ServerKey {
-    cpu_key: CpuBootstrapKey,
-    #[cfg(feature = "cuda")]
-    cuda_key: Arc<CudaBootstrapKey>,
-}
-
-#[cfg(not(feature = "cuda"))]
-mod implementation {
-    use super::*;
-
-    impl RefFromServerKey for CpuBootstrapKey {
-        fn get_ref(server_key: &ServerKey) -> &Self {
-            &server_key.cpu_key
-        }
-    }
-
-    impl DefaultImplementation for ServerKey {
-        type Engine = CpuBooleanEngine;
-        type BootsrapKey = CpuBootstrapKey;
-    }
-}
-
-#[cfg(feature = "cuda")]
-mod implementation {
-    use super::*;
-
-    impl RefFromServerKey for CudaBootstrapKey {
-        fn get_ref(server_key: &ServerKey) -> &Self {
-            &server_key.cuda_key
+impl<B> Clone for ServerKey<B>
+where
+    B: Bootstrapper,
+    B::ServerKey: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            bootstrap_key: self.bootstrap_key.clone(),
+            parameters: self.parameters,
         }
     }
-
-    impl DefaultImplementation for ServerKey {
-        type Engine = CudaBooleanEngine;
-        type BootsrapKey = CudaBootstrapKey;
-    }
 }
 
-impl<Lhs, Rhs> BinaryBooleanGates<Lhs, Rhs> for ServerKey
+impl<Lhs, Rhs, B> BinaryBooleanGates<Lhs, Rhs> for ServerKey<B>
 where
-    <ServerKey as DefaultImplementation>::Engine:
-        BinaryGatesEngine<Lhs, Rhs, <ServerKey as DefaultImplementation>::BootsrapKey>,
+    B: Bootstrapper,
+    BooleanEngine<B>: BinaryGatesEngine<Lhs, Rhs, B::ServerKey>,
 {
     fn and(&self, ct_left: Lhs, ct_right: Rhs) -> Ciphertext {
-        let bootstrap_key = <ServerKey as DefaultImplementation>::BootsrapKey::get_ref(self);
-        <ServerKey as DefaultImplementation>::Engine::with_thread_local_mut(|engine| {
-            engine.and(ct_left, ct_right, bootstrap_key)
+        record_gate!(And, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.and(ct_left, ct_right, &self.bootstrap_key)
+            })
         })
     }
 
     fn nand(&self, ct_left: Lhs, ct_right: Rhs) -> Ciphertext {
-        let bootstrap_key = <ServerKey as DefaultImplementation>::BootsrapKey::get_ref(self);
-        <ServerKey as DefaultImplementation>::Engine::with_thread_local_mut(|engine| {
-            engine.nand(ct_left, ct_right, bootstrap_key)
+        record_gate!(Nand, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.nand(ct_left, ct_right, &self.bootstrap_key)
+            })
         })
     }
 
     fn nor(&self, ct_left: Lhs, ct_right: Rhs) -> Ciphertext {
-        let bootstrap_key = <ServerKey as DefaultImplementation>::BootsrapKey::get_ref(self);
-        <ServerKey as DefaultImplementation>::Engine::with_thread_local_mut(|engine| {
-            engine.nor(ct_left, ct_right, bootstrap_key)
+        record_gate!(Nor, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.nor(ct_left, ct_right, &self.bootstrap_key)
+            })
         })
     }
 
     fn or(&self, ct_left: Lhs, ct_right: Rhs) -> Ciphertext {
-        let bootstrap_key = <ServerKey as DefaultImplementation>::BootsrapKey::get_ref(self);
-        <ServerKey as DefaultImplementation>::Engine::with_thread_local_mut(|engine| {
-            engine.or(ct_left, ct_right, bootstrap_key)
+        record_gate!(Or, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.or(ct_left, ct_right, &self.bootstrap_key)
+            })
         })
     }
 
     fn xor(&self, ct_left: Lhs, ct_right: Rhs) -> Ciphertext {
-        let bootstrap_key = <ServerKey as DefaultImplementation>::BootsrapKey::get_ref(self);
-        <ServerKey as DefaultImplementation>::Engine::with_thread_local_mut(|engine| {
-            engine.xor(ct_left, ct_right, bootstrap_key)
+        record_gate!(Xor, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.xor(ct_left, ct_right, &self.bootstrap_key)
+            })
         })
     }
 
     fn xnor(&self, ct_left: Lhs, ct_right: Rhs) -> Ciphertext {
-        let bootstrap_key = <ServerKey as DefaultImplementation>::BootsrapKey::get_ref(self);
-        <ServerKey as DefaultImplementation>::Engine::with_thread_local_mut(|engine| {
-            engine.xnor(ct_left, ct_right, bootstrap_key)
+        record_gate!(Xnor, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.xnor(ct_left, ct_right, &self.bootstrap_key)
+            })
         })
     }
 }
@@ -129,7 +123,46 @@ impl ServerKey {
         let cpu_key =
             CpuBooleanEngine::with_thread_local_mut(|engine| engine.create_server_key(cks));
 
-        Self::from(cpu_key)
+        Self::from(cpu_key).with_parameters(cks.parameters)
+    }
+}
+
+impl<B> ServerKey<B>
+where
+    B: Bootstrapper,
+{
+    /// Creates a server key evaluating the gates with the bootstrapper `B`.
+    ///
+    /// See the [`Bootstrapper`] trait for an example.
+    ///
+    /// The bootstrap key does not hold the parameters it was generated with, so the server key
+    /// does not know them: see [`ServerKey::parameters`].
+    pub fn from_bootstrap_key(bootstrap_key: B::ServerKey) -> Self {
+        Self {
+            bootstrap_key,
+            parameters: None,
+        }
+    }
+
+    fn with_parameters(self, parameters: BooleanParameters) -> Self {
+        Self {
+            parameters: Some(parameters),
+            ..self
+        }
+    }
+
+    /// Returns the parameters of the client key this server key was generated from.
+    ///
+    /// They are unknown, and `None` is returned, when the key was built with
+    /// [`ServerKey::from_bootstrap_key`] or loaded from data written before the format was
+    /// versioned.
+    pub fn parameters(&self) -> Option<BooleanParameters> {
+        self.parameters
+    }
+
+    /// Returns the key used by the bootstrapper.
+    pub fn bootstrap_key(&self) -> &B::ServerKey {
+        &self.bootstrap_key
     }
 
     pub fn trivial_encrypt(&self, message: bool) -> Ciphertext {
@@ -137,7 +170,9 @@ impl ServerKey {
     }
 
     pub fn not(&self, ct: &Ciphertext) -> Ciphertext {
-        CpuBooleanEngine::with_thread_local_mut(|engine| engine.not(ct))
+        record_gate!(Not, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| engine.not(ct))
+        })
     }
 
     pub fn mux(
@@ -146,56 +181,433 @@ impl ServerKey {
         ct_then: &Ciphertext,
         ct_else: &Ciphertext,
     ) -> Ciphertext {
-        #[cfg(feature = "cuda")]
-        {
-            CudaBooleanEngine::with_thread_local_mut(|engine| {
-                engine.mux(ct_condition, ct_then, ct_else, &self.cuda_key)
+        record_gate!(Mux, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.mux(ct_condition, ct_then, ct_else, &self.bootstrap_key)
             })
-        }
-        #[cfg(not(feature = "cuda"))]
-        {
-            CpuBooleanEngine::with_thread_local_mut(|engine| {
-                engine.mux(ct_condition, ct_then, ct_else, &self.cpu_key)
+        })
+    }
+
+    /// Computes the majority of three ciphertexts with a single bootstrap.
+    ///
+    /// The three inputs are summed before the bootstrap, so the error probability is higher than
+    /// for a two-input gate, see [`NoiseEstimate::three_input_failure_probability`].
+    ///
+    /// [`NoiseEstimate::three_input_failure_probability`]:
+    /// crate::parameters::NoiseEstimate::three_input_failure_probability
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the parameters of the key are known and their estimated
+    /// three-input failure probability exceeds $2^{-25}$.
+    pub fn maj3(&self, ct_a: &Ciphertext, ct_b: &Ciphertext, ct_c: &Ciphertext) -> Ciphertext {
+        self.check_three_input_gates();
+        record_gate!(Maj3, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.maj3(ct_a, ct_b, ct_c, &self.bootstrap_key)
             })
+        })
+    }
+
+    /// Computes the XOR of three ciphertexts with a single bootstrap.
+    ///
+    /// The three inputs are summed and doubled before the bootstrap, so the error probability is
+    /// higher than for a two-input gate, see [`NoiseEstimate::three_input_failure_probability`].
+    ///
+    /// [`NoiseEstimate::three_input_failure_probability`]:
+    /// crate::parameters::NoiseEstimate::three_input_failure_probability
+    ///
+    /// # Panics
+    ///
+    /// This function will panic under the same condition as [`ServerKey::maj3`].
+    pub fn xor3(&self, ct_a: &Ciphertext, ct_b: &Ciphertext, ct_c: &Ciphertext) -> Ciphertext {
+        self.check_three_input_gates();
+        record_gate!(Xor3, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.xor3(ct_a, ct_b, ct_c, &self.bootstrap_key)
+            })
+        })
+    }
+
+    /// Returns the noise estimate of the parameters of the key, when they are known.
+    fn noise_estimate(&self) -> Option<NoiseEstimate> {
+        self.parameters
+            .map(|parameters| parameters.noise_estimate())
+    }
+
+    /// Checks that the noise of the parameters allows the single bootstrap three-input gates.
+    ///
+    /// A key built from a bootstrap key alone does not know its parameters, and is not checked.
+    fn check_three_input_gates(&self) {
+        if let Some(noise_estimate) = self.noise_estimate() {
+            let failure = noise_estimate.three_input_failure_probability();
+            assert!(
+                failure <= MAX_FAILURE_PROBABILITY,
+                "The parameters of the server key do not allow three-input gates: their estimated \
+                 failure probability 2^{:.1} exceeds 2^-25",
+                failure.log2()
+            );
         }
     }
+
+    /// Computes the AND of three ciphertexts.
+    ///
+    /// This gate costs two bootstraps: with the $\pm 1/8$ encoding, a three-input AND cannot be
+    /// evaluated by a single negacyclic bootstrap.
+    pub fn and3(&self, ct_a: &Ciphertext, ct_b: &Ciphertext, ct_c: &Ciphertext) -> Ciphertext {
+        record_gate!(And3, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.and3(ct_a, ct_b, ct_c, &self.bootstrap_key)
+            })
+        })
+    }
+
+    /// Computes the OR of three ciphertexts.
+    ///
+    /// This gate costs two bootstraps, for the same reason as [`ServerKey::and3`].
+    pub fn or3(&self, ct_a: &Ciphertext, ct_b: &Ciphertext, ct_c: &Ciphertext) -> Ciphertext {
+        record_gate!(Or3, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.or3(ct_a, ct_b, ct_c, &self.bootstrap_key)
+            })
+        })
+    }
+
+    /// Computes an arbitrary two-input gate with a single bootstrap.
+    ///
+    /// The truth table lists the outputs for the inputs `(false, false)`, `(false, true)`,
+    /// `(true, false)` and `(true, true)`, i.e. `truth_table[2 * a + b]` is the value of the
+    /// gate for the inputs `a` and `b`.
+    ///
+    /// The inputs are weighted and summed with a constant before the bootstrap, with the weights
+    /// of the native gates: the XOR-like gates double their inputs as the
+    /// [`xor`](BinaryBooleanGates::xor) gate does.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the parameters of the key are known and the estimated failure
+    /// probability of the gate exceeds $2^{-25}$, which only happens for parameters that are too
+    /// noisy for the native gates as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "cuda"))]
+    /// # fn main() {
+    /// use concrete_boolean::prelude::*;
+    ///
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// // a AND NOT b
+    /// let truth_table = [false, false, true, false];
+    ///
+    /// for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+    ///     let ct = sks.gate_from_truth_table(truth_table, &cks.encrypt(a), &cks.encrypt(b));
+    ///     assert_eq!(cks.decrypt(&ct), a & !b);
+    /// }
+    /// # }
+    /// # #[cfg(feature = "cuda")]
+    /// # fn main() {}
+    /// ```
+    pub fn gate_from_truth_table(
+        &self,
+        truth_table: [bool; 4],
+        ct_a: &Ciphertext,
+        ct_b: &Ciphertext,
+    ) -> Ciphertext {
+        record_gate!(TruthTable, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine
+                    .gate_from_truth_table(
+                        &truth_table,
+                        &[ct_a, ct_b],
+                        &self.bootstrap_key,
+                        self.noise_estimate().as_ref(),
+                        MAX_FAILURE_PROBABILITY,
+                    )
+                    .expect(
+                        "The parameters of the server key are too noisy for this gate: its \
+                         estimated failure probability exceeds 2^-25",
+                    )
+            })
+        })
+    }
+
+    /// Computes an arbitrary three-input gate with a single bootstrap, when the noise permits.
+    ///
+    /// The truth table is indexed as in [`ServerKey::gate_from_truth_table`]:
+    /// `truth_table[4 * a + 2 * b + c]` is the value of the gate for the inputs `a`, `b` and `c`.
+    ///
+    /// Only the gates for which a weighted sum of the inputs, with weights no larger than the
+    /// ones of [`ServerKey::xor3`], separates the true rows from the false ones can be evaluated
+    /// with a single bootstrap; `None` is returned for the other ones, e.g. the three-input AND.
+    /// `None` is also returned when the parameters of the key are known and the estimated failure
+    /// probability of the gate exceeds $2^{-25}$, the bound of [`ServerKey::maj3`]. For instance
+    /// `a XOR (b AND c)` needs the weights `(2, 1, 1)` and is rejected with the
+    /// [`DEFAULT_PARAMETERS`](crate::parameters::DEFAULT_PARAMETERS). When one of the inputs is
+    /// trivially encrypted, the gate is reduced to a two-input gate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "cuda"))]
+    /// # fn main() {
+    /// use concrete_boolean::prelude::*;
+    ///
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// let (ct_a, ct_b, ct_c) = (cks.encrypt(true), cks.encrypt(false), cks.encrypt(true));
+    ///
+    /// // the majority of a, NOT b and c
+    /// let truth_table = [false, true, false, false, true, true, false, true];
+    /// let ct = sks.gate_from_truth_table3(truth_table, &ct_a, &ct_b, &ct_c).unwrap();
+    /// assert!(cks.decrypt(&ct));
+    ///
+    /// // a XOR (b AND c) is too noisy with the default parameters
+    /// let truth_table = [false, false, false, true, true, true, true, false];
+    /// assert!(sks
+    ///     .gate_from_truth_table3(truth_table, &ct_a, &ct_b, &ct_c)
+    ///     .is_none());
+    ///
+    /// // a AND b AND c needs two bootstraps
+    /// let truth_table = [false, false, false, false, false, false, false, true];
+    /// assert!(sks
+    ///     .gate_from_truth_table3(truth_table, &ct_a, &ct_b, &ct_c)
+    ///     .is_none());
+    /// # }
+    /// # #[cfg(feature = "cuda")]
+    /// # fn main() {}
+    /// ```
+    pub fn gate_from_truth_table3(
+        &self,
+        truth_table: [bool; 8],
+        ct_a: &Ciphertext,
+        ct_b: &Ciphertext,
+        ct_c: &Ciphertext,
+    ) -> Option<Ciphertext> {
+        record_gate!(TruthTable3, {
+            BooleanEngine::<B>::with_thread_local_mut(|engine| {
+                engine.gate_from_truth_table(
+                    &truth_table,
+                    &[ct_a, ct_b, ct_c],
+                    &self.bootstrap_key,
+                    self.noise_estimate().as_ref(),
+                    MAX_FAILURE_PROBABILITY,
+                )
+            })
+        })
+    }
 }
 
 impl From<CpuBootstrapKey> for ServerKey {
+    // With the cuda feature, the keys are converted for the GPU
+    #[allow(clippy::useless_conversion)]
     fn from(cpu_key: CpuBootstrapKey) -> Self {
-        #[cfg(feature = "cuda")]
-        {
-            let cuda_key = CudaBooleanEngine::with_thread_local_mut(|engine| {
-                engine.create_server_key(&cpu_key)
-            });
+        Self::from_bootstrap_key(cpu_key.into())
+    }
+}
 
-            let cuda_key = Arc::new(cuda_key);
+impl<B> Versioned for ServerKey<B>
+where
+    B: Bootstrapper,
+    B::ServerKey: Serialize + DeserializeOwned,
+{
+    const MAGIC: [u8; 4] = *b"CBSK";
 
-            Self { cpu_key, cuda_key }
-        }
-        #[cfg(not(feature = "cuda"))]
-        {
-            Self { cpu_key }
+    fn envelope_parameters(&self) -> Option<BooleanParameters> {
+        self.parameters
+    }
+
+    fn with_envelope_parameters(
+        self,
+        parameters: Option<BooleanParameters>,
+    ) -> Result<Self, SerializationError> {
+        match parameters {
+            Some(parameters)
+                if parameters.lwe_dimension.to_lwe_size() != self.bootstrap_key.lwe_size() =>
+            {
+                Err(SerializationError::IncompatibleParameters {
+                    envelope: parameters,
+                })
+            }
+            Some(parameters) => Ok(self.with_parameters(parameters)),
+            None => Ok(self),
         }
     }
+
+    fn serialize_payload<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.bootstrap_key.serialize(serializer)
+    }
+
+    fn deserialize_payload<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bootstrap_key = B::ServerKey::deserialize(deserializer)?;
+
+        Ok(Self::from_bootstrap_key(bootstrap_key))
+    }
 }
 
-impl Serialize for ServerKey {
+impl<B> Serialize for ServerKey<B>
+where
+    B: Bootstrapper,
+    B::ServerKey: Serialize + DeserializeOwned,
+{
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        S: serde::Serializer,
+        S: Serializer,
     {
-        self.cpu_key.serialize(serializer)
+        serialization::serialize(self, serializer)
     }
 }
 
-impl<'de> Deserialize<'de> for ServerKey {
+impl<'de, B> Deserialize<'de> for ServerKey<B>
+where
+    B: Bootstrapper,
+    B::ServerKey: Serialize + DeserializeOwned,
+{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
-        D: serde::Deserializer<'de>,
+        D: Deserializer<'de>,
     {
-        let cpu_key = CpuBootstrapKey::deserialize(deserializer)?;
+        serialization::deserialize(deserializer)
+    }
+}
 
-        Ok(Self::from(cpu_key))
+impl<'de, B> Deserialize<'de> for Legacy<ServerKey<B>>
+where
+    B: Bootstrapper,
+    B::ServerKey: Serialize + DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serialization::deserialize_legacy(deserializer)
     }
 }
+
+/// A structure containing the server public key in a compressed form.
+///
+/// The random masks of the bootstrapping and key switching keys are replaced by the seeds used
+/// to generate them, which makes this key much smaller to send to the server. The server then
+/// has to decompress it into a [`ServerKey`].
+#[derive(Clone)]
+pub struct CompressedServerKey {
+    cpu_key: CpuCompressedBootstrapKey,
+    parameters: Option<BooleanParameters>,
+}
+
+impl CompressedServerKey {
+    /// Generates a compressed server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(feature = "cuda"))]
+    /// # fn main() {
+    /// use concrete_boolean::client_key::ClientKey;
+    /// use concrete_boolean::parameters::DEFAULT_PARAMETERS;
+    /// use concrete_boolean::prelude::*;
+    /// use concrete_boolean::server_key::CompressedServerKey;
+    ///
+    /// let cks = ClientKey::new(&DEFAULT_PARAMETERS);
+    ///
+    /// // The client generates the compressed key and sends it to the server
+    /// let compressed_sks = CompressedServerKey::new(&cks);
+    ///
+    /// // The server decompresses it before computing
+    /// let sks = compressed_sks.decompress();
+    ///
+    /// let ct_res = sks.and(&cks.encrypt(true), &cks.encrypt(true));
+    /// assert_eq!(true, cks.decrypt(&ct_res));
+    /// # }
+    /// # #[cfg(feature = "cuda")]
+    /// # fn main() {}
+    /// ```
+    pub fn new(cks: &ClientKey) -> Self {
+        let cpu_key = CpuBooleanEngine::with_thread_local_mut(|engine| {
+            engine.create_compressed_server_key(cks)
+        });
+
+        Self {
+            cpu_key,
+            parameters: Some(cks.parameters),
+        }
+    }
+
+    /// Regenerates the masks of the keys from their seeds.
+    pub fn decompress(&self) -> ServerKey {
+        let cpu_key = CpuBooleanEngine::with_thread_local_mut(|engine| {
+            engine.decompress_server_key(&self.cpu_key)
+        });
+
+        ServerKey {
+            parameters: self.parameters,
+            ..ServerKey::from(cpu_key)
+        }
+    }
+}
+
+impl From<CompressedServerKey> for ServerKey {
+    fn from(compressed_key: CompressedServerKey) -> Self {
+        compressed_key.decompress()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializableCompressedServerKey<K> {
+    cpu_key: K,
+}
+
+impl Versioned for CompressedServerKey {
+    const MAGIC: [u8; 4] = *b"CBCS";
+
+    fn envelope_parameters(&self) -> Option<BooleanParameters> {
+        self.parameters
+    }
+
+    fn with_envelope_parameters(
+        self,
+        parameters: Option<BooleanParameters>,
+    ) -> Result<Self, SerializationError> {
+        match parameters {
+            Some(parameters) if !self.cpu_key.matches_parameters(&parameters) => {
+                Err(SerializationError::IncompatibleParameters {
+                    envelope: parameters,
+                })
+            }
+            parameters => Ok(Self { parameters, ..self }),
+        }
+    }
+
+    fn serialize_payload<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SerializableCompressedServerKey {
+            cpu_key: &self.cpu_key,
+        }
+        .serialize(serializer)
+    }
+
+    fn deserialize_payload<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let thing = SerializableCompressedServerKey::<CpuCompressedBootstrapKey>::deserialize(
+            deserializer,
+        )?;
+
+        Ok(Self {
+            cpu_key: thing.cpu_key,
+            parameters: None,
+        })
+    }
+}
+
+impl_versioned_serde!(CompressedServerKey);
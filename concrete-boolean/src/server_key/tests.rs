@@ -1,8 +1,11 @@
-use crate::ciphertext::Ciphertext;
+use crate::ciphertext::{Ciphertext, CompressedCiphertext};
 use crate::client_key::ClientKey;
+use crate::engine::bootstrapping::{Bootstrapper, CpuBootstrapKey, CpuBootstrapper};
 use crate::parameters::BooleanParameters;
-use crate::server_key::{BinaryBooleanGates, ServerKey};
+use crate::server_key::{BinaryBooleanGates, CompressedServerKey, ServerKey};
 use crate::{random_boolean, random_integer};
+use concrete_core::prelude::LweCiphertext32;
+use std::cell::Cell;
 
 /// Number of assert in randomized tests
 const NB_TEST: usize = 128;
@@ -58,6 +61,50 @@ mod default_parameters_tests {
     fn test_deep_circuit_default_parameters() {
         test_deep_circuit(DEFAULT_PARAMETERS);
     }
+    #[test]
+    fn test_maj3_gate_default_parameters() {
+        test_maj3_gate(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_xor3_gate_default_parameters() {
+        test_xor3_gate(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_and3_gate_default_parameters() {
+        test_and3_gate(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_or3_gate_default_parameters() {
+        test_or3_gate(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_gate_from_truth_table_default_parameters() {
+        test_gate_from_truth_table(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_gate_from_truth_table3_default_parameters() {
+        test_gate_from_truth_table3(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_full_adder_default_parameters() {
+        test_full_adder(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_batched_gates_default_parameters() {
+        test_batched_gates(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_compressed_ciphertext_default_parameters() {
+        test_compressed_ciphertext(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_compressed_server_key_default_parameters() {
+        test_compressed_server_key(DEFAULT_PARAMETERS);
+    }
+    #[test]
+    fn test_custom_bootstrapper_default_parameters() {
+        test_custom_bootstrapper(DEFAULT_PARAMETERS);
+    }
 }
 
 mod tfhe_lib_parameters_tests {
@@ -104,6 +151,50 @@ mod tfhe_lib_parameters_tests {
     fn test_deep_circuit_tfhe_lib_parameters() {
         test_deep_circuit(TFHE_LIB_PARAMETERS);
     }
+    #[test]
+    fn test_maj3_gate_tfhe_lib_parameters() {
+        test_maj3_gate(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_xor3_gate_tfhe_lib_parameters() {
+        test_xor3_gate(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_and3_gate_tfhe_lib_parameters() {
+        test_and3_gate(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_or3_gate_tfhe_lib_parameters() {
+        test_or3_gate(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_gate_from_truth_table_tfhe_lib_parameters() {
+        test_gate_from_truth_table(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_gate_from_truth_table3_tfhe_lib_parameters() {
+        test_gate_from_truth_table3(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_full_adder_tfhe_lib_parameters() {
+        test_full_adder(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_batched_gates_tfhe_lib_parameters() {
+        test_batched_gates(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_compressed_ciphertext_tfhe_lib_parameters() {
+        test_compressed_ciphertext(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_compressed_server_key_tfhe_lib_parameters() {
+        test_compressed_server_key(TFHE_LIB_PARAMETERS);
+    }
+    #[test]
+    fn test_custom_bootstrapper_tfhe_lib_parameters() {
+        test_custom_bootstrapper(TFHE_LIB_PARAMETERS);
+    }
 }
 
 /// test encryption and decryption with the LWE secret key
@@ -547,6 +638,435 @@ fn test_xor_gate(parameters: BooleanParameters) {
     }
 }
 
+fn test_maj3_gate(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+        let expected_result = (b1 && b2) || (b1 && b3) || (b2 && b3);
+
+        // encryption of b1
+        let ct1 = random_enum_encryption(&cks, &sks, b1);
+
+        // encryption of b2
+        let ct2 = random_enum_encryption(&cks, &sks, b2);
+
+        // encryption of b3
+        let ct3 = random_enum_encryption(&cks, &sks, b3);
+
+        // MAJ3 gate
+        let ct_res = sks.maj3(&ct1, &ct2, &ct3);
+
+        // decryption
+        let dec_maj3 = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            expected_result, dec_maj3,
+            "a: {:?}, b: {:?}, c: {:?}",
+            ct1, ct2, ct3
+        );
+    }
+}
+
+fn test_gate_from_truth_table(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // every two-input gate
+    for table in 0..16 {
+        let truth_table = [0, 1, 2, 3].map(|row| (table >> row) & 1 == 1);
+
+        for row in 0..4 {
+            let b1 = row & 2 != 0;
+            let b2 = row & 1 != 0;
+            let expected_result = truth_table[row];
+
+            // encryption of b1
+            let ct1 = random_enum_encryption(&cks, &sks, b1);
+
+            // encryption of b2
+            let ct2 = random_enum_encryption(&cks, &sks, b2);
+
+            // gate
+            let ct_res = sks.gate_from_truth_table(truth_table, &ct1, &ct2);
+
+            // decryption
+            let dec = cks.decrypt(&ct_res);
+
+            // assert
+            assert_eq!(
+                expected_result, dec,
+                "truth table: {:?}, a: {:?}, b: {:?}",
+                truth_table, ct1, ct2
+            );
+        }
+    }
+}
+
+fn test_gate_from_truth_table3(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    let gates: [fn(bool, bool, bool) -> bool; 4] = [
+        |a, b, c| (a && b) || (a && c) || (b && c),
+        |a, b, c| a ^ b ^ c,
+        |a, b, _| a ^ b,
+        |a, b, c| (a && !b) || (a && c) || (!b && c),
+    ];
+
+    for gate in gates {
+        let truth_table =
+            [0, 1, 2, 3, 4, 5, 6, 7].map(|row| gate(row & 4 != 0, row & 2 != 0, row & 1 != 0));
+
+        for _ in 0..NB_TEST / 4 {
+            // generation of three random booleans
+            let b1 = random_boolean();
+            let b2 = random_boolean();
+            let b3 = random_boolean();
+            let expected_result = gate(b1, b2, b3);
+
+            // encryption of b1
+            let ct1 = random_enum_encryption(&cks, &sks, b1);
+
+            // encryption of b2
+            let ct2 = random_enum_encryption(&cks, &sks, b2);
+
+            // encryption of b3
+            let ct3 = random_enum_encryption(&cks, &sks, b3);
+
+            // gate
+            let ct_res = sks
+                .gate_from_truth_table3(truth_table, &ct1, &ct2, &ct3)
+                .unwrap();
+
+            // decryption
+            let dec = cks.decrypt(&ct_res);
+
+            // assert
+            assert_eq!(
+                expected_result, dec,
+                "truth table: {:?}, a: {:?}, b: {:?}, c: {:?}",
+                truth_table, ct1, ct2, ct3
+            );
+        }
+    }
+
+    // the three-input AND needs two bootstraps, unless one of its inputs is trivial
+    let truth_table = [false, false, false, false, false, false, false, true];
+    let ct1 = cks.encrypt(true);
+    let ct2 = cks.encrypt(true);
+    assert!(sks
+        .gate_from_truth_table3(truth_table, &ct1, &ct2, &cks.encrypt(true))
+        .is_none());
+    let ct_res = sks
+        .gate_from_truth_table3(truth_table, &ct1, &ct2, &sks.trivial_encrypt(true))
+        .unwrap();
+    assert!(cks.decrypt(&ct_res));
+
+    // a XOR (b AND c) needs the weights (2, 1, 1) with a margin of 1/8, it is only evaluated when
+    // the noise of the parameters allows it
+    let truth_table = [false, false, false, true, true, true, true, false];
+    let failure = parameters
+        .noise_estimate()
+        .gate_failure_probability(6., 1. / 8.);
+    let ct_res = sks.gate_from_truth_table3(truth_table, &ct1, &ct2, &cks.encrypt(true));
+    assert_eq!(ct_res.is_some(), failure <= 1. / (1 << 25) as f64);
+    if let Some(ct_res) = ct_res {
+        assert!(!cks.decrypt(&ct_res));
+    }
+}
+
+fn test_xor3_gate(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+        let expected_result = b1 ^ b2 ^ b3;
+
+        // encryption of b1
+        let ct1 = random_enum_encryption(&cks, &sks, b1);
+
+        // encryption of b2
+        let ct2 = random_enum_encryption(&cks, &sks, b2);
+
+        // encryption of b3
+        let ct3 = random_enum_encryption(&cks, &sks, b3);
+
+        // XOR3 gate
+        let ct_res = sks.xor3(&ct1, &ct2, &ct3);
+
+        // decryption
+        let dec_xor3 = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            expected_result, dec_xor3,
+            "a: {:?}, b: {:?}, c: {:?}",
+            ct1, ct2, ct3
+        );
+    }
+}
+
+fn test_and3_gate(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+        let expected_result = b1 && b2 && b3;
+
+        // encryption of b1
+        let ct1 = random_enum_encryption(&cks, &sks, b1);
+
+        // encryption of b2
+        let ct2 = random_enum_encryption(&cks, &sks, b2);
+
+        // encryption of b3
+        let ct3 = random_enum_encryption(&cks, &sks, b3);
+
+        // AND3 gate
+        let ct_res = sks.and3(&ct1, &ct2, &ct3);
+
+        // decryption
+        let dec_and3 = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            expected_result, dec_and3,
+            "a: {:?}, b: {:?}, c: {:?}",
+            ct1, ct2, ct3
+        );
+    }
+}
+
+fn test_or3_gate(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+        let expected_result = b1 || b2 || b3;
+
+        // encryption of b1
+        let ct1 = random_enum_encryption(&cks, &sks, b1);
+
+        // encryption of b2
+        let ct2 = random_enum_encryption(&cks, &sks, b2);
+
+        // encryption of b3
+        let ct3 = random_enum_encryption(&cks, &sks, b3);
+
+        // OR3 gate
+        let ct_res = sks.or3(&ct1, &ct2, &ct3);
+
+        // decryption
+        let dec_or3 = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            expected_result, dec_or3,
+            "a: {:?}, b: {:?}, c: {:?}",
+            ct1, ct2, ct3
+        );
+    }
+}
+
+/// test an 8-bit ripple-carry adder, whose full adders are made of the single bootstrap three-input
+/// gates
+fn test_full_adder(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of two random 8 bits integers
+        let a = random_integer() & 0xff;
+        let b = random_integer() & 0xff;
+
+        // encryption of the bits, LSB first
+        let ct_a: Vec<Ciphertext> = (0..8).map(|i| cks.encrypt((a >> i) & 1 == 1)).collect();
+        let ct_b: Vec<Ciphertext> = (0..8).map(|i| cks.encrypt((b >> i) & 1 == 1)).collect();
+
+        // ripple carry addition
+        let mut carry = sks.trivial_encrypt(false);
+        let mut result = 0u32;
+        for (i, (bit_a, bit_b)) in ct_a.iter().zip(ct_b.iter()).enumerate() {
+            let sum = sks.xor3(bit_a, bit_b, &carry);
+            carry = sks.maj3(bit_a, bit_b, &carry);
+            result |= (cks.decrypt(&sum) as u32) << i;
+        }
+        result |= (cks.decrypt(&carry) as u32) << 8;
+
+        // assert
+        assert_eq!(a + b, result);
+    }
+}
+
+/// test the batched gates against the clear evaluation of every input
+fn test_batched_gates(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    // generation of three columns of random booleans and their encryptions
+    let bools: Vec<(bool, bool, bool)> = (0..NB_TEST)
+        .map(|_| (random_boolean(), random_boolean(), random_boolean()))
+        .collect();
+    let cts: Vec<(Ciphertext, Ciphertext, Ciphertext)> = bools
+        .iter()
+        .map(|(b1, b2, b3)| {
+            (
+                random_enum_encryption(&cks, &sks, *b1),
+                random_enum_encryption(&cks, &sks, *b2),
+                random_enum_encryption(&cks, &sks, *b3),
+            )
+        })
+        .collect();
+
+    let singles: Vec<&Ciphertext> = cts.iter().map(|(ct1, _, _)| ct1).collect();
+    let pairs: Vec<(&Ciphertext, &Ciphertext)> =
+        cts.iter().map(|(ct1, ct2, _)| (ct1, ct2)).collect();
+    let triplets: Vec<(&Ciphertext, &Ciphertext, &Ciphertext)> =
+        cts.iter().map(|(ct1, ct2, ct3)| (ct1, ct2, ct3)).collect();
+
+    let check = |results: Vec<Ciphertext>, expected: &dyn Fn(bool, bool, bool) -> bool| {
+        assert_eq!(results.len(), bools.len());
+        for (ct_res, (b1, b2, b3)) in results.iter().zip(bools.iter()) {
+            assert_eq!(expected(*b1, *b2, *b3), cks.decrypt(ct_res));
+        }
+    };
+
+    check(sks.and_many(&pairs), &|b1, b2, _| b1 && b2);
+    check(sks.nand_many(&pairs), &|b1, b2, _| !(b1 && b2));
+    check(sks.nor_many(&pairs), &|b1, b2, _| !(b1 || b2));
+    check(sks.or_many(&pairs), &|b1, b2, _| b1 || b2);
+    check(sks.xor_many(&pairs), &|b1, b2, _| b1 ^ b2);
+    check(sks.xnor_many(&pairs), &|b1, b2, _| !(b1 ^ b2));
+    check(sks.not_many(&singles), &|b1, _, _| !b1);
+    check(sks.mux_many(&triplets), &|b1, b2, b3| {
+        if b1 {
+            b2
+        } else {
+            b3
+        }
+    });
+    check(sks.maj3_many(&triplets), &|b1, b2, b3| {
+        (b1 && b2) || (b1 && b3) || (b2 && b3)
+    });
+    check(sks.xor3_many(&triplets), &|b1, b2, b3| b1 ^ b2 ^ b3);
+    check(sks.and3_many(&triplets), &|b1, b2, b3| b1 && b2 && b3);
+    check(sks.or3_many(&triplets), &|b1, b2, b3| b1 || b2 || b3);
+}
+
+/// test that compressed ciphertexts are smaller and decompress to valid ciphertexts
+fn test_compressed_ciphertext(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for _ in 0..NB_TEST {
+        // generation of two random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+
+        // compressed encryption of b1, serialized and sent to the server
+        let compressed_ct1 = cks.encrypt_compressed(b1);
+        let serialized = bincode::serialize(&compressed_ct1).unwrap();
+        let compressed_ct1: CompressedCiphertext = bincode::deserialize(&serialized).unwrap();
+
+        // the compressed ciphertext is smaller than a regular one
+        let ct2 = cks.encrypt(b2);
+        assert!(serialized.len() < bincode::serialize(&ct2).unwrap().len());
+
+        // decompression
+        let ct1 = compressed_ct1.decompress();
+        assert_eq!(b1, cks.decrypt(&ct1));
+
+        // AND gate
+        let ct_res = sks.and(&ct1, &ct2);
+
+        // assert
+        assert_eq!(b1 && b2, cks.decrypt(&ct_res));
+    }
+}
+
+/// test that a decompressed server key evaluates the gates
+fn test_compressed_server_key(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the compressed server key, serialized and sent to the server
+    let compressed_sks = CompressedServerKey::new(&cks);
+    let serialized = bincode::serialize(&compressed_sks).unwrap();
+    let compressed_sks: CompressedServerKey = bincode::deserialize(&serialized).unwrap();
+
+    // the compressed key is smaller than a regular one
+    let sks = ServerKey::new(&cks);
+    assert!(serialized.len() < bincode::serialize(&sks).unwrap().len());
+
+    // decompression
+    let sks = compressed_sks.decompress();
+
+    for _ in 0..NB_TEST {
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+
+        // encryption
+        let ct1 = cks.encrypt(b1);
+        let ct2 = cks.encrypt(b2);
+        let ct3 = cks.encrypt(b3);
+
+        // XOR gate
+        let ct_res = sks.xor(&ct1, &ct2);
+        assert_eq!(b1 ^ b2, cks.decrypt(&ct_res));
+
+        // MUX gate
+        let ct_res = sks.mux(&ct1, &ct2, &ct3);
+        assert_eq!(if b1 { b2 } else { b3 }, cks.decrypt(&ct_res));
+    }
+}
+
 /// generate a random index for the table in the long run tests
 fn random_index() -> usize {
     (random_integer() % (NB_CT as u32)) as usize
@@ -608,6 +1128,94 @@ fn random_gate_all(ct_tab: &mut [Ciphertext], bool_tab: &mut [bool], sks: &Serve
     }
 }
 
+thread_local! {
+    /// Number of bootstraps done by the `CountingBootstrapper` of the current thread
+    static BOOTSTRAP_COUNT: Cell<usize> = Cell::new(0);
+}
+
+/// A bootstrapper delegating to the CPU one and counting the bootstraps
+#[derive(Default)]
+struct CountingBootstrapper {
+    bootstrapper: CpuBootstrapper,
+}
+
+impl Bootstrapper for CountingBootstrapper {
+    type ServerKey = CpuBootstrapKey;
+
+    crate::engine_slot!(CountingBootstrapper);
+
+    fn bootstrap(
+        &mut self,
+        input: &LweCiphertext32,
+        server_key: &CpuBootstrapKey,
+    ) -> Result<LweCiphertext32, Box<dyn std::error::Error>> {
+        BOOTSTRAP_COUNT.with(|count| count.set(count.get() + 1));
+        self.bootstrapper.bootstrap(input, server_key)
+    }
+
+    fn keyswitch(
+        &mut self,
+        input: &LweCiphertext32,
+        server_key: &CpuBootstrapKey,
+    ) -> Result<LweCiphertext32, Box<dyn std::error::Error>> {
+        self.bootstrapper.keyswitch(input, server_key)
+    }
+
+    fn bootstrap_keyswitch(
+        &mut self,
+        ciphertext: LweCiphertext32,
+        server_key: &CpuBootstrapKey,
+    ) -> Result<Ciphertext, Box<dyn std::error::Error>> {
+        BOOTSTRAP_COUNT.with(|count| count.set(count.get() + 1));
+        self.bootstrapper
+            .bootstrap_keyswitch(ciphertext, server_key)
+    }
+}
+
+/// test the gates evaluated by a bootstrapper plugged in the server key
+fn test_custom_bootstrapper(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set, using the counting bootstrapper
+    let sks = ServerKey::<CountingBootstrapper>::from_bootstrap_key(CpuBootstrapKey::new(&cks));
+
+    let bootstrap_count = || BOOTSTRAP_COUNT.with(|count| count.get());
+
+    for _ in 0..NB_TEST {
+        // generation of three random booleans
+        let b1 = random_boolean();
+        let b2 = random_boolean();
+        let b3 = random_boolean();
+
+        // encryption
+        let ct1 = cks.encrypt(b1);
+        let ct2 = cks.encrypt(b2);
+        let ct3 = cks.encrypt(b3);
+
+        let count_before = bootstrap_count();
+
+        // AND gate
+        let ct_res = sks.and(&ct1, &ct2);
+        assert_eq!(b1 && b2, cks.decrypt(&ct_res));
+
+        // MUX gate
+        let ct_res = sks.mux(&ct1, &ct2, &ct3);
+        assert_eq!(if b1 { b2 } else { b3 }, cks.decrypt(&ct_res));
+
+        // MAJ3 gate
+        let ct_res = sks.maj3(&ct1, &ct2, &ct3);
+        assert_eq!((b1 & b2) | (b1 & b3) | (b2 & b3), cks.decrypt(&ct_res));
+
+        // the trivial shortcuts and the NOT gate do not bootstrap
+        let ct_res = sks.and(&ct1, &sks.trivial_encrypt(true));
+        assert_eq!(b1, cks.decrypt(&sks.not(&sks.not(&ct_res))));
+
+        // one bootstrap for AND and MAJ3, two for the MUX
+        assert_eq!(bootstrap_count() - count_before, 4);
+    }
+}
+
 fn test_deep_circuit(parameters: BooleanParameters) {
     // generate the client key set
     let cks = ClientKey::new(&parameters);
@@ -0,0 +1,150 @@
+//! Operation counters, enabled by the `stats` feature.
+//!
+//! Every thread records the operations it performs: the bootstraps, the key switches, the linear
+//! operations on ciphertexts, the trivial shortcuts taken instead of a bootstrap, and the number
+//! and duration of the gates evaluated through the [`ServerKey`](crate::server_key::ServerKey).
+//!
+//! The batched gates run on the rayon thread pool, the operations of the worker threads are added
+//! to the counters of the thread which called the batched gate.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(not(feature = "cuda"))]
+//! # fn main() {
+//! use concrete_boolean::prelude::*;
+//! use concrete_boolean::stats::{self, GateKind};
+//!
+//! let (cks, sks) = gen_keys();
+//!
+//! let ct_1 = cks.encrypt(true);
+//! let ct_2 = cks.encrypt(false);
+//!
+//! stats::reset();
+//! let _ = sks.and(&ct_1, &ct_2);
+//! let _ = sks.and(&ct_1, &sks.trivial_encrypt(true));
+//!
+//! let stats = stats::snapshot();
+//! assert_eq!(stats.bootstraps, 1);
+//! assert_eq!(stats.trivial_shortcuts, 1);
+//! assert_eq!(stats.gates[&GateKind::And].count, 2);
+//! # }
+//! # #[cfg(feature = "cuda")]
+//! # fn main() {}
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// The gates of the [`ServerKey`](crate::server_key::ServerKey).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GateKind {
+    And,
+    Nand,
+    Nor,
+    Or,
+    Xor,
+    Xnor,
+    Not,
+    Mux,
+    Maj3,
+    Xor3,
+    And3,
+    Or3,
+    TruthTable,
+    TruthTable3,
+}
+
+/// The number of evaluations of a gate and the time spent in them.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct GateStats {
+    pub count: u64,
+    pub time: Duration,
+}
+
+/// The operations recorded by a thread.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Number of programmable bootstraps.
+    pub bootstraps: u64,
+    /// Number of key switches.
+    pub keyswitches: u64,
+    /// Number of additions, negations and scalar multiplications of ciphertexts.
+    pub linear_ops: u64,
+    /// Number of gates, or parts of a gate, evaluated without bootstrap thanks to a trivially
+    /// encrypted input.
+    pub trivial_shortcuts: u64,
+    /// The gates evaluated through the server key.
+    pub gates: BTreeMap<GateKind, GateStats>,
+}
+
+impl Stats {
+    /// Adds the operations of `other` to these ones.
+    pub fn merge(&mut self, other: &Stats) {
+        self.bootstraps += other.bootstraps;
+        self.keyswitches += other.keyswitches;
+        self.linear_ops += other.linear_ops;
+        self.trivial_shortcuts += other.trivial_shortcuts;
+        for (gate, gate_stats) in &other.gates {
+            let entry = self.gates.entry(*gate).or_default();
+            entry.count += gate_stats.count;
+            entry.time += gate_stats.time;
+        }
+    }
+}
+
+thread_local! {
+    static STATS: RefCell<Stats> = RefCell::new(Stats::default());
+}
+
+/// Returns the operations recorded by the current thread since the last [`reset`].
+pub fn snapshot() -> Stats {
+    STATS.with(|stats| stats.borrow().clone())
+}
+
+/// Clears the operations recorded by the current thread.
+pub fn reset() {
+    STATS.with(|stats| *stats.borrow_mut() = Stats::default());
+}
+
+/// Updates the counters of the current thread.
+pub(crate) fn record<F>(update: F)
+where
+    F: FnOnce(&mut Stats),
+{
+    STATS.with(|stats| update(&mut stats.borrow_mut()));
+}
+
+/// Evaluates a gate, recording its duration.
+pub(crate) fn record_gate<R, F>(gate: GateKind, func: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let start = Instant::now();
+    let result = func();
+    let elapsed = start.elapsed();
+
+    record(|stats| {
+        let gate_stats = stats.gates.entry(gate).or_default();
+        gate_stats.count += 1;
+        gate_stats.time += elapsed;
+    });
+    result
+}
+
+/// Runs `func` with empty counters, returning the operations it recorded.
+///
+/// The counters of the current thread are restored afterwards.
+pub(crate) fn measure<R, F>(func: F) -> (R, Stats)
+where
+    F: FnOnce() -> R,
+{
+    let saved = STATS.with(|stats| stats.take());
+    let result = func();
+    let measured = STATS.with(|stats| stats.replace(saved));
+    (result, measured)
+}
@@ -0,0 +1,63 @@
+use crate::client_key::ClientKey;
+use crate::parameters::TFHE_LIB_PARAMETERS;
+use crate::server_key::{BinaryBooleanGates, ServerKey};
+use crate::stats::{self, GateKind};
+
+#[test]
+fn test_gate_counters() {
+    let cks = ClientKey::new(&TFHE_LIB_PARAMETERS);
+    let sks = ServerKey::new(&cks);
+
+    let ct_1 = cks.encrypt(true);
+    let ct_2 = cks.encrypt(false);
+    let ct_true = sks.trivial_encrypt(true);
+
+    stats::reset();
+
+    // one bootstrap and one key switch each
+    let ct_and = sks.and(&ct_1, &ct_2);
+    let ct_xor = sks.xor(&ct_1, &ct_2);
+    // two bootstraps and one key switch
+    let ct_mux = sks.mux(&ct_1, &ct_and, &ct_xor);
+    // linear only
+    let ct_not = sks.not(&ct_mux);
+    // trivial shortcuts
+    let ct_or = sks.or(&ct_not, &ct_true);
+    let ct_mux_trivial = sks.mux(&ct_true, &ct_1, &ct_2);
+
+    assert!(!cks.decrypt(&ct_and));
+    assert!(cks.decrypt(&ct_xor));
+    assert!(cks.decrypt(&ct_not));
+    assert!(cks.decrypt(&ct_or));
+    assert!(cks.decrypt(&ct_mux_trivial));
+
+    let snapshot = stats::snapshot();
+    assert_eq!(snapshot.bootstraps, 4);
+    assert_eq!(snapshot.keyswitches, 3);
+    assert_eq!(snapshot.linear_ops, 2 + 3 + 7 + 1);
+    assert_eq!(snapshot.trivial_shortcuts, 2);
+    assert_eq!(snapshot.gates[&GateKind::And].count, 1);
+    assert_eq!(snapshot.gates[&GateKind::Mux].count, 2);
+    assert_eq!(snapshot.gates[&GateKind::Not].count, 1);
+    assert!(!snapshot.gates.contains_key(&GateKind::Nand));
+
+    stats::reset();
+    assert_eq!(stats::snapshot(), stats::Stats::default());
+}
+
+#[test]
+fn test_batched_gate_counters() {
+    let cks = ClientKey::new(&TFHE_LIB_PARAMETERS);
+    let sks = ServerKey::new(&cks);
+
+    let cts: Vec<_> = (0..8).map(|i| cks.encrypt(i % 2 == 0)).collect();
+    let ct_pairs: Vec<_> = cts.iter().zip(cts.iter().skip(1)).collect();
+
+    stats::reset();
+    let _ = sks.xor_many(&ct_pairs);
+
+    // the operations of the rayon workers are gathered in the calling thread
+    let snapshot = stats::snapshot();
+    assert_eq!(snapshot.bootstraps, ct_pairs.len() as u64);
+    assert_eq!(snapshot.gates[&GateKind::Xor].count, ct_pairs.len() as u64);
+}
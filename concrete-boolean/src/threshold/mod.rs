@@ -0,0 +1,171 @@
+//! Distributed decryption.
+//!
+//! This module splits the LWE secret key of a [`ClientKey`] into additive shares, so that a
+//! ciphertext can only be decrypted when every share holder takes part. Each holder computes a
+//! [`PartialDecryption`] of the ciphertext with its [`SecretKeyShare`], hiding its share behind a
+//! smudging noise, and anyone can then combine all the partial decryptions into the message.
+//!
+//! The smudging noises of all the holders add up to the noise of the ciphertext, their standard
+//! deviation must stay small in front of the $1/8$ decryption margin. See the
+//! [`NoiseEstimate`](crate::parameters::NoiseEstimate) of the parameters for the noise of the
+//! ciphertexts output by the gates.
+//!
+//! # Security
+//!
+//! The partial decryptions leak the noise of the ciphertext. Combining them gives the message plus
+//! the noise of the ciphertext plus the smudging noises, so whoever sees every partial decryption
+//! learns the noise of the ciphertext, blurred only by the smudging noises. A smudging noise hides
+//! a noise $e$ up to a statistical distance of about $|e| / (2\sigma)$, hence $\lambda$ bits of
+//! statistical security need a smudging standard deviation $\sigma$ about $2^\lambda$ times larger
+//! than the noise of the ciphertext, while staying small in front of the decryption margin.
+//!
+//! The provided parameter sets cannot absorb such a noise: the ciphertexts output by the gates
+//! already have a standard deviation of about $2^{-6.5}$ with `DEFAULT_PARAMETERS` and $2^{-7.5}$
+//! with `TFHE_LIB_PARAMETERS`, only a few bits below the $1/8$ margin. The
+//! [`INSECURE_TEST_SMUDGING_STD_DEV`] of $2^{-10}$ keeps the decryption correct, but it is smaller
+//! than the noise of the gate outputs and only $3.4$ bits above the one of fresh encryptions: it
+//! gives no meaningful statistical hiding. As the noise of a gate output depends on the
+//! bootstrapping and keyswitching keys, decrypting many of them can leak information on the secret
+//! key.
+//!
+//! This module is thus only suited to settings where the partial decryptions are sent over
+//! private channels to a combiner trusted with the decrypted values, and must not be used to
+//! publish decryptions.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(not(feature = "cuda"))]
+//! # fn main() {
+//! use concrete_boolean::prelude::*;
+//! use concrete_boolean::threshold::{combine_partial_decryptions, INSECURE_TEST_SMUDGING_STD_DEV};
+//!
+//! let (cks, sks) = gen_keys();
+//!
+//! // The key owner splits its key between two parties, then forgets it
+//! let shares = cks.split_into_shares(2);
+//!
+//! let ct = sks.and(&cks.encrypt(true), &cks.encrypt(true));
+//!
+//! // Each party decrypts with its own share
+//! let partial_decryptions: Vec<_> = shares
+//!     .iter()
+//!     .map(|share| share.partial_decrypt(&ct, INSECURE_TEST_SMUDGING_STD_DEV))
+//!     .collect();
+//!
+//! assert_eq!(combine_partial_decryptions(&ct, &partial_decryptions), true);
+//! # }
+//! # #[cfg(feature = "cuda")]
+//! # fn main() {}
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use crate::ciphertext::Ciphertext;
+use crate::client_key::ClientKey;
+use crate::engine::{CpuBooleanEngine, WithThreadLocalEngine};
+use crate::parameters::{BooleanParameters, StandardDev};
+use crate::serialization::Versioned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A standard deviation of the smudging noise keeping the decryption correct with the provided
+/// parameter sets: $2^{-10}$, meant for tests and examples only.
+///
+/// It does not hide the noise of the ciphertexts, see the [security](self#security) section of
+/// the module.
+pub const INSECURE_TEST_SMUDGING_STD_DEV: StandardDev = StandardDev(0.000_976_562_5);
+
+/// An additive share of the LWE secret key of a [`ClientKey`], which must be kept secret.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecretKeyShare {
+    pub(crate) share: Vec<u32>,
+    pub(crate) parameters: BooleanParameters,
+}
+
+/// The share of the decryption of a ciphertext computed by one share holder.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialDecryption(pub(crate) u32);
+
+impl ClientKey {
+    /// Splits the LWE secret key into `num_shares` additive shares.
+    ///
+    /// All the shares are needed to decrypt, any smaller subset of them is uniformly random.
+    ///
+    /// # Panic
+    ///
+    /// Panics if `num_shares` is zero.
+    pub fn split_into_shares(&self, num_shares: usize) -> Vec<SecretKeyShare> {
+        CpuBooleanEngine::with_thread_local_mut(|engine| engine.split_secret_key(self, num_shares))
+    }
+}
+
+impl SecretKeyShare {
+    /// Returns the parameters of the split client key.
+    pub fn parameters(&self) -> &BooleanParameters {
+        &self.parameters
+    }
+
+    /// Computes the partial decryption of a ciphertext, adding a smudging noise of standard
+    /// deviation `smudging_std_dev`.
+    pub fn partial_decrypt(
+        &self,
+        ct: &Ciphertext,
+        smudging_std_dev: StandardDev,
+    ) -> PartialDecryption {
+        CpuBooleanEngine::with_thread_local_mut(|engine| {
+            engine.partial_decrypt(ct, self, smudging_std_dev)
+        })
+    }
+}
+
+/// Combines the partial decryptions of a ciphertext computed with every share of the key.
+///
+/// The result is meaningless if a share is missing or used twice.
+pub fn combine_partial_decryptions(
+    ct: &Ciphertext,
+    partial_decryptions: &[PartialDecryption],
+) -> bool {
+    CpuBooleanEngine::with_thread_local_mut(|engine| {
+        engine.combine_partial_decryptions(ct, partial_decryptions)
+    })
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializableSecretKeyShare<S> {
+    share: S,
+    parameters: BooleanParameters,
+}
+
+impl Versioned for SecretKeyShare {
+    const MAGIC: [u8; 4] = *b"CBKS";
+
+    fn envelope_parameters(&self) -> Option<BooleanParameters> {
+        Some(self.parameters)
+    }
+
+    fn serialize_payload<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SerializableSecretKeyShare {
+            share: &self.share,
+            parameters: self.parameters,
+        }
+        .serialize(serializer)
+    }
+
+    fn deserialize_payload<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let thing = SerializableSecretKeyShare::<Vec<u32>>::deserialize(deserializer)?;
+
+        Ok(Self {
+            share: thing.share,
+            parameters: thing.parameters,
+        })
+    }
+}
+
+impl_versioned_serde!(SecretKeyShare);
@@ -0,0 +1,72 @@
+use crate::client_key::ClientKey;
+use crate::parameters::BooleanParameters;
+use crate::random_boolean;
+use crate::server_key::{BinaryBooleanGates, ServerKey};
+use crate::threshold::{
+    combine_partial_decryptions, SecretKeyShare, INSECURE_TEST_SMUDGING_STD_DEV,
+};
+
+/// Number of assert in randomized tests
+const NB_TEST: usize = 32;
+
+#[cfg(not(feature = "cuda"))]
+mod default_parameters_tests {
+    use super::*;
+    use crate::parameters::DEFAULT_PARAMETERS;
+
+    #[test]
+    fn test_distributed_decryption_default_parameters() {
+        test_distributed_decryption(DEFAULT_PARAMETERS);
+    }
+}
+
+mod tfhe_lib_parameters_tests {
+    use super::*;
+    use crate::parameters::TFHE_LIB_PARAMETERS;
+
+    #[test]
+    fn test_distributed_decryption_tfhe_lib_parameters() {
+        test_distributed_decryption(TFHE_LIB_PARAMETERS);
+    }
+}
+
+fn test_distributed_decryption(parameters: BooleanParameters) {
+    // generate the client key set
+    let cks = ClientKey::new(&parameters);
+
+    // generate the server key set
+    let sks = ServerKey::new(&cks);
+
+    for num_shares in [1, 2, 3] {
+        let shares = cks.split_into_shares(num_shares);
+        assert_eq!(shares.len(), num_shares);
+
+        // the shares are sent to the parties
+        let shares: Vec<SecretKeyShare> = shares
+            .iter()
+            .map(|share| bincode::deserialize(&bincode::serialize(share).unwrap()).unwrap())
+            .collect();
+
+        for _ in 0..NB_TEST {
+            let b1 = random_boolean();
+            let b2 = random_boolean();
+
+            // fresh ciphertext, gate output and trivial ciphertext
+            let ct1 = cks.encrypt(b1);
+            let ct_xor = sks.xor(&ct1, &cks.encrypt(b2));
+            let ct_trivial = sks.trivial_encrypt(b2);
+
+            for (ct, expected) in [(ct1, b1), (ct_xor, b1 ^ b2), (ct_trivial, b2)] {
+                let partial_decryptions: Vec<_> = shares
+                    .iter()
+                    .map(|share| share.partial_decrypt(&ct, INSECURE_TEST_SMUDGING_STD_DEV))
+                    .collect();
+
+                assert_eq!(
+                    combine_partial_decryptions(&ct, &partial_decryptions),
+                    expected
+                );
+            }
+        }
+    }
+}
@@ -14,6 +14,11 @@ create_parametrized_test!(integer_smart_crt_scalar_mul);
 create_parametrized_test!(integer_smart_crt_scalar_sub);
 create_parametrized_test!(integer_smart_crt_sub);
 
+create_parametrized_test!(integer_crt_full_extract {
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_3
+});
+
 /// Number of loop iteration within randomized tests
 const NB_TEST: usize = 30;
 
@@ -271,3 +276,36 @@ fn integer_smart_crt_sub(param: Parameters) {
         assert_eq!(clear_0, dec_res);
     }
 }
+
+fn integer_crt_full_extract(param: Parameters) {
+    // Blocks with distinct moduli, extracted with the same server key
+    let basis = vec![2, 3, 5];
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ct_zero = cks.encrypt_crt(clear_0, basis.clone());
+        let ct_one = cks.encrypt_crt(clear_1, basis.clone());
+
+        sks.unchecked_crt_add_assign(&mut ct_zero, &ct_one);
+        sks.full_extract(&mut ct_zero);
+
+        // each block is reduced by its own modulus
+        for (block, b_i) in ct_zero.blocks.iter().zip(basis.iter()) {
+            assert_eq!(block.degree.0 as u64, b_i - 1);
+            assert_eq!(
+                cks.key.decrypt_message_and_carry(block),
+                (clear_0 + clear_1) % b_i
+            );
+        }
+
+        assert_eq!((clear_0 + clear_1) % modulus, cks.decrypt_crt(&ct_zero));
+    }
+}
@@ -85,12 +85,9 @@ impl ServerKey {
             .map(|b| self.key.generate_accumulator(|x| f(x) % b))
             .collect::<Vec<_>>();
 
-        ct1.blocks
-            .par_iter_mut()
-            .zip(&accumulators)
-            .for_each(|(block, acc)| {
-                self.key.keyswitch_programmable_bootstrap_assign(block, acc);
-            });
+        ct1.blocks = self
+            .key
+            .keyswitch_programmable_bootstrap_many(&ct1.blocks, &accumulators);
     }
 
     pub fn pbs_crt_compliant_function_parallelized<F>(
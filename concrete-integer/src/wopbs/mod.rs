@@ -9,7 +9,7 @@ mod test;
 use crate::client_key::utils::i_crt;
 use crate::{ClientKey, CrtCiphertext, IntegerCiphertext, RadixCiphertext, ServerKey};
 use concrete_core::prelude::*;
-use concrete_shortint::ciphertext::Degree;
+use concrete_shortint::ciphertext::{Degree, NoiseLevel};
 use rayon::prelude::*;
 
 use concrete_shortint::Parameters;
@@ -230,6 +230,7 @@ impl WopbsKey {
             ct_vec_out.push(concrete_shortint::Ciphertext {
                 ct: block_out,
                 degree: Degree(block.message_modulus.0 - 1),
+                noise_level: NoiseLevel::NOMINAL,
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
             });
@@ -289,6 +290,7 @@ impl WopbsKey {
             ct_vec_out.push(concrete_shortint::Ciphertext {
                 ct: block_out,
                 degree: Degree(block.message_modulus.0 - 1),
+                noise_level: NoiseLevel::NOMINAL,
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
             });
@@ -964,6 +966,7 @@ impl WopbsKey {
             ct_vec_out.push(concrete_shortint::Ciphertext {
                 ct: block_out,
                 degree: Degree(block.message_modulus.0 - 1),
+                noise_level: NoiseLevel::NOMINAL,
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
             });
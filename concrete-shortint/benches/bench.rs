@@ -137,6 +137,35 @@ fn programmable_bootstrapping(c: &mut Criterion) {
     bench_group.finish();
 }
 
+fn apply_lookup_table(c: &mut Criterion) {
+    let mut bench_group = c.benchmark_group("apply_lookup_table");
+
+    for (param_name, param) in SERVER_KEY_BENCH_PARAMS {
+        let keys = KEY_CACHE.get_from_param(param);
+        let (cks, sks) = (keys.client_key(), keys.server_key());
+
+        let mut rng = rand::thread_rng();
+
+        let modulus = cks.parameters.message_modulus.0 as u64;
+
+        let lut = sks.generate_lookup_table(|x| x * x % modulus);
+
+        let clear_0 = rng.gen::<u64>() % modulus;
+
+        let ctxt = cks.encrypt(clear_0);
+
+        let id = format!("ServerKey::apply_lookup_table::{}", param_name);
+
+        bench_group.bench_function(&id, |b| {
+            b.iter(|| {
+                sks.apply_lookup_table(&ctxt, &lut);
+            })
+        });
+    }
+
+    bench_group.finish();
+}
+
 fn bench_wopbs_param_message_8_norm2_5(c: &mut Criterion) {
     let mut bench_group = c.benchmark_group("programmable_bootstrap");
 
@@ -192,6 +221,7 @@ define_server_key_bench_fn!(unchecked_add);
 define_server_key_bench_fn!(unchecked_sub);
 define_server_key_bench_fn!(unchecked_mul_lsb);
 define_server_key_bench_fn!(unchecked_mul_msb);
+define_server_key_bench_fn!(unchecked_greater);
 define_server_key_bench_fn!(smart_bitand);
 define_server_key_bench_fn!(smart_bitor);
 define_server_key_bench_fn!(smart_bitxor);
@@ -208,6 +238,7 @@ criterion_group!(
     unchecked_sub,
     unchecked_mul_lsb,
     unchecked_mul_msb,
+    unchecked_greater,
     smart_bitand,
     smart_bitor,
     smart_bitxor,
@@ -220,7 +251,8 @@ criterion_group!(
     //bench_two_block_pbs
     //wopbs_v0_norm2_2,
     bench_wopbs_param_message_8_norm2_5,
-    programmable_bootstrapping
+    programmable_bootstrapping,
+    apply_lookup_table
 );
 
 criterion_group!(
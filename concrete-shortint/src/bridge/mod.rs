@@ -0,0 +1,193 @@
+//! Module with the definition of the BridgeKey, enabled by the `boolean` feature.
+//!
+//! This module implements the conversion of the boolean ciphertexts of `concrete-boolean` into
+//! short integer ciphertexts of message 0 or 1, and back, so that Boolean gates and arithmetic
+//! operations can be mixed in a single circuit.
+//!
+//! A boolean ciphertext is key switched to the small LWE key of the short integers, then
+//! bootstrapped with the [`ServerKey`]. In the other direction, a sign bootstrap maps the short
+//! integer to $\pm 1/8$ before a key switch to the boolean LWE key.
+
+use crate::engine::ShortintEngine;
+use crate::parameters::Parameters;
+use crate::{Ciphertext, ClientKey, ServerKey};
+use concrete_boolean::ciphertext::Ciphertext as BooleanCiphertext;
+use concrete_boolean::client_key::ClientKey as BooleanClientKey;
+use concrete_boolean::parameters::BooleanParameters;
+use concrete_core::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(test)]
+mod tests;
+
+/// The public key converting boolean ciphertexts into short integer ciphertexts, and back.
+#[derive(Clone, Debug)]
+pub struct BridgeKey {
+    // Key switching key from the boolean LWE key to the small shortint LWE key
+    pub(crate) ksk_boolean_to_shortint: LweKeyswitchKey64,
+    // Key switching key from the large shortint LWE key to the boolean LWE key
+    pub(crate) ksk_shortint_to_boolean: LweKeyswitchKey64,
+    pub(crate) boolean_parameters: BooleanParameters,
+    pub(crate) parameters: Parameters,
+}
+
+impl BridgeKey {
+    /// Generates a bridge key from a boolean client key and a shortint client key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::client_key::ClientKey as BooleanClientKey;
+    /// use concrete_boolean::parameters::TFHE_LIB_PARAMETERS;
+    /// use concrete_shortint::bridge::BridgeKey;
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let boolean_cks = BooleanClientKey::new(&TFHE_LIB_PARAMETERS);
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let bridge_key = BridgeKey::new(&boolean_cks, &cks);
+    /// ```
+    pub fn new(boolean_cks: &BooleanClientKey, cks: &ClientKey) -> BridgeKey {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_bridge_key(boolean_cks, cks).unwrap()
+        })
+    }
+
+    /// Returns the parameters of the boolean client key.
+    pub fn boolean_parameters(&self) -> &BooleanParameters {
+        &self.boolean_parameters
+    }
+
+    /// Returns the parameters of the shortint client key.
+    pub fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    /// Converts a boolean ciphertext into a shortint ciphertext encrypting 0 or 1.
+    ///
+    /// The output has a degree of 1, and the noise of a freshly bootstrapped ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::client_key::ClientKey as BooleanClientKey;
+    /// use concrete_boolean::parameters::TFHE_LIB_PARAMETERS;
+    /// use concrete_shortint::bridge::BridgeKey;
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let boolean_cks = BooleanClientKey::new(&TFHE_LIB_PARAMETERS);
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    /// let bridge_key = BridgeKey::new(&boolean_cks, &cks);
+    ///
+    /// let ct_bool = boolean_cks.encrypt(true);
+    /// let ct = bridge_key.boolean_to_shortint(&sks, &ct_bool);
+    ///
+    /// // The bit can now be added to a short integer
+    /// let ct_res = sks.unchecked_add(&ct, &cks.encrypt(2));
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn boolean_to_shortint(
+        &self,
+        server_key: &ServerKey,
+        ct: &BooleanCiphertext,
+    ) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.boolean_to_shortint(self, server_key, ct).unwrap()
+        })
+    }
+
+    /// Converts a shortint ciphertext into a boolean ciphertext, encrypting `true` if and only if
+    /// the message and carry of the input are not zero.
+    ///
+    /// The output has the noise of a boolean gate output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_boolean::client_key::ClientKey as BooleanClientKey;
+    /// use concrete_boolean::parameters::TFHE_LIB_PARAMETERS;
+    /// use concrete_shortint::bridge::BridgeKey;
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// let boolean_cks = BooleanClientKey::new(&TFHE_LIB_PARAMETERS);
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    /// let bridge_key = BridgeKey::new(&boolean_cks, &cks);
+    ///
+    /// let ct = cks.encrypt(2);
+    /// let ct_bool = bridge_key.shortint_to_boolean(&sks, &ct);
+    /// assert_eq!(boolean_cks.decrypt(&ct_bool), true);
+    /// ```
+    pub fn shortint_to_boolean(
+        &self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+    ) -> BooleanCiphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.shortint_to_boolean(self, server_key, ct).unwrap()
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializableBridgeKey {
+    ksk_boolean_to_shortint: Vec<u8>,
+    ksk_shortint_to_boolean: Vec<u8>,
+    boolean_parameters: BooleanParameters,
+    parameters: Parameters,
+}
+
+impl Serialize for BridgeKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut default_ser_eng =
+            DefaultSerializationEngine::new(()).map_err(serde::ser::Error::custom)?;
+
+        let ksk_boolean_to_shortint = default_ser_eng
+            .serialize(&self.ksk_boolean_to_shortint)
+            .map_err(serde::ser::Error::custom)?;
+
+        let ksk_shortint_to_boolean = default_ser_eng
+            .serialize(&self.ksk_shortint_to_boolean)
+            .map_err(serde::ser::Error::custom)?;
+
+        SerializableBridgeKey {
+            ksk_boolean_to_shortint,
+            ksk_shortint_to_boolean,
+            boolean_parameters: self.boolean_parameters,
+            parameters: self.parameters,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BridgeKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let thing = SerializableBridgeKey::deserialize(deserializer)?;
+
+        let mut default_ser_eng =
+            DefaultSerializationEngine::new(()).map_err(serde::de::Error::custom)?;
+
+        let ksk_boolean_to_shortint = default_ser_eng
+            .deserialize(thing.ksk_boolean_to_shortint.as_slice())
+            .map_err(serde::de::Error::custom)?;
+
+        let ksk_shortint_to_boolean = default_ser_eng
+            .deserialize(thing.ksk_shortint_to_boolean.as_slice())
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            ksk_boolean_to_shortint,
+            ksk_shortint_to_boolean,
+            boolean_parameters: thing.boolean_parameters,
+            parameters: thing.parameters,
+        })
+    }
+}
@@ -0,0 +1,107 @@
+use crate::bridge::BridgeKey;
+use crate::keycache::KEY_CACHE;
+use crate::parameters::*;
+use concrete_boolean::ciphertext::Ciphertext as BooleanCiphertext;
+use concrete_boolean::client_key::ClientKey as BooleanClientKey;
+use concrete_boolean::parameters::{BooleanParameters, DEFAULT_PARAMETERS, TFHE_LIB_PARAMETERS};
+use paste::paste;
+use rand::Rng;
+
+/// Number of assert in randomized tests
+const NB_TEST: usize = 30;
+
+// Macro to generate tests for some shortint parameter sets, with both boolean parameter sets
+macro_rules! create_parametrized_test{
+    ($name:ident { $($param:ident),* }) => {
+        paste! {
+            $(
+            #[test]
+            fn [<test_ $name _ $param:lower _default_parameters>]() {
+                $name($param, DEFAULT_PARAMETERS)
+            }
+
+            #[test]
+            fn [<test_ $name _ $param:lower _tfhe_lib_parameters>]() {
+                $name($param, TFHE_LIB_PARAMETERS)
+            }
+            )*
+        }
+    };
+     ($name:ident)=> {
+        create_parametrized_test!($name
+        {
+            PARAM_MESSAGE_1_CARRY_1,
+            PARAM_MESSAGE_2_CARRY_2,
+            PARAM_MESSAGE_3_CARRY_3
+        });
+    };
+}
+
+create_parametrized_test!(bridge_boolean_to_shortint);
+create_parametrized_test!(bridge_shortint_to_boolean);
+create_parametrized_test!(bridge_round_trip);
+
+fn bridge_boolean_to_shortint(param: Parameters, boolean_param: BooleanParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let boolean_cks = BooleanClientKey::new(&boolean_param);
+    let bridge_key = BridgeKey::new(&boolean_cks, cks);
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let b = rng.gen::<bool>();
+
+        let ct = bridge_key.boolean_to_shortint(sks, &boolean_cks.encrypt(b));
+        assert_eq!(ct.degree.0, 1);
+        assert_eq!(cks.decrypt_message_and_carry(&ct), b as u64);
+
+        // trivial boolean ciphertexts
+        let ct = bridge_key.boolean_to_shortint(sks, &BooleanCiphertext::Trivial(b));
+        assert_eq!(cks.decrypt_message_and_carry(&ct), b as u64);
+    }
+}
+
+fn bridge_shortint_to_boolean(param: Parameters, boolean_param: BooleanParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let boolean_cks = BooleanClientKey::new(&boolean_param);
+    let bridge_key = BridgeKey::new(&boolean_cks, cks);
+
+    let modulus = (param.message_modulus.0 * param.carry_modulus.0) as u64;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        // the carry is used as well
+        let ct = cks.unchecked_encrypt(clear);
+        let ct_bool = bridge_key.shortint_to_boolean(sks, &ct);
+        assert_eq!(boolean_cks.decrypt(&ct_bool), clear != 0);
+
+        let ct = sks.create_trivial(0);
+        let ct_bool = bridge_key.shortint_to_boolean(sks, &ct);
+        assert!(!boolean_cks.decrypt(&ct_bool));
+    }
+}
+
+fn bridge_round_trip(param: Parameters, boolean_param: BooleanParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let boolean_cks = BooleanClientKey::new(&boolean_param);
+    let bridge_key = BridgeKey::new(&boolean_cks, cks);
+
+    let bridge_key: BridgeKey =
+        bincode::deserialize(&bincode::serialize(&bridge_key).unwrap()).unwrap();
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let b = rng.gen::<bool>();
+
+        let ct = bridge_key.boolean_to_shortint(sks, &boolean_cks.encrypt(b));
+        let ct_bool = bridge_key.shortint_to_boolean(sks, &ct);
+        assert_eq!(boolean_cks.decrypt(&ct_bool), b);
+    }
+}
@@ -0,0 +1,174 @@
+//! Module with the definition of the CastingKey.
+//!
+//! This module implements the conversion of short integer ciphertexts from a parameter set to
+//! another one, so that a circuit can start with small and fast parameters, and move to larger ones
+//! when more precision is needed.
+//!
+//! A ciphertext is key switched from the large LWE key of the input parameters to the small LWE
+//! key of the output parameters, then bootstrapped with the [`ServerKey`] of the output
+//! parameters. The accumulator of this bootstrap re-encodes the message with the scaling factor
+//! of the output parameters, and clears the carry.
+
+use crate::engine::ShortintEngine;
+use crate::parameters::Parameters;
+use crate::{Ciphertext, ClientKey, ServerKey};
+use concrete_core::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(test)]
+mod tests;
+
+/// The public key converting short integer ciphertexts from a parameter set to another one.
+#[derive(Clone, Debug)]
+pub struct CastingKey {
+    // Key switching key from the large input LWE key to the small output LWE key
+    pub(crate) ksk: LweKeyswitchKey64,
+    pub(crate) parameters_in: Parameters,
+    pub(crate) parameters_out: Parameters,
+}
+
+impl CastingKey {
+    /// Generates a casting key from the client key of the input parameters and the client key of
+    /// the output parameters.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus, or the whole plaintext space of the input
+    /// parameters, is larger than the one of the output parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::casting::CastingKey;
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::{PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// let (cks_in, sks_in) = gen_keys(PARAM_MESSAGE_1_CARRY_1);
+    /// let (cks_out, sks_out) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let casting_key = CastingKey::new(&cks_in, &cks_out);
+    /// ```
+    pub fn new(cks_in: &ClientKey, cks_out: &ClientKey) -> CastingKey {
+        let (param_in, param_out) = (&cks_in.parameters, &cks_out.parameters);
+        assert!(param_in.message_modulus.0 <= param_out.message_modulus.0);
+        assert!(
+            param_in.message_modulus.0 * param_in.carry_modulus.0
+                <= param_out.message_modulus.0 * param_out.carry_modulus.0
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_casting_key(cks_in, cks_out).unwrap()
+        })
+    }
+
+    /// Returns the parameters of the input client key.
+    pub fn parameters_in(&self) -> &Parameters {
+        &self.parameters_in
+    }
+
+    /// Returns the parameters of the output client key.
+    pub fn parameters_out(&self) -> &Parameters {
+        &self.parameters_out
+    }
+
+    /// Converts a ciphertext of the input parameters into a ciphertext of the output parameters,
+    /// encrypting the same message.
+    ///
+    /// The carry of the input is cleared, and the output has the noise of a freshly bootstrapped
+    /// ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `ct` was not encrypted with the input parameters, or if
+    /// `server_key_out` is not a server key of the output parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::casting::CastingKey;
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::{PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// let (cks_in, sks_in) = gen_keys(PARAM_MESSAGE_1_CARRY_1);
+    /// let (cks_out, sks_out) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    /// let casting_key = CastingKey::new(&cks_in, &cks_out);
+    ///
+    /// let ct_in = cks_in.encrypt(1);
+    /// let ct_out = casting_key.cast(&sks_out, &ct_in);
+    ///
+    /// // The message can now be used with the output parameters
+    /// let ct_res = sks_out.unchecked_add(&ct_out, &cks_out.encrypt(2));
+    /// assert_eq!(cks_out.decrypt(&ct_res), 3);
+    /// ```
+    pub fn cast(&self, server_key_out: &ServerKey, ct: &Ciphertext) -> Ciphertext {
+        let (param_in, param_out) = (&self.parameters_in, &self.parameters_out);
+        assert!(
+            ct.message_modulus.0.checked_mul(ct.carry_modulus.0)
+                == Some(param_in.message_modulus.0 * param_in.carry_modulus.0)
+                && ct.ct.lwe_dimension() == self.ksk.input_lwe_dimension(),
+            "The ciphertext was not encrypted with the input parameters of the casting key"
+        );
+        assert!(
+            server_key_out.message_modulus == param_out.message_modulus
+                && server_key_out.carry_modulus == param_out.carry_modulus
+                && server_key_out.bootstrapping_key.polynomial_size() == param_out.polynomial_size
+                && server_key_out.bootstrapping_key.input_lwe_dimension()
+                    == self.ksk.output_lwe_dimension(),
+            "The server key does not match the output parameters of the casting key"
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.cast(self, server_key_out, ct).unwrap()
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializableCastingKey {
+    ksk: Vec<u8>,
+    parameters_in: Parameters,
+    parameters_out: Parameters,
+}
+
+impl Serialize for CastingKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut default_ser_eng =
+            DefaultSerializationEngine::new(()).map_err(serde::ser::Error::custom)?;
+
+        let ksk = default_ser_eng
+            .serialize(&self.ksk)
+            .map_err(serde::ser::Error::custom)?;
+
+        SerializableCastingKey {
+            ksk,
+            parameters_in: self.parameters_in,
+            parameters_out: self.parameters_out,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CastingKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let thing = SerializableCastingKey::deserialize(deserializer)?;
+
+        let mut default_ser_eng =
+            DefaultSerializationEngine::new(()).map_err(serde::de::Error::custom)?;
+
+        let ksk = default_ser_eng
+            .deserialize(thing.ksk.as_slice())
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            ksk,
+            parameters_in: thing.parameters_in,
+            parameters_out: thing.parameters_out,
+        })
+    }
+}
@@ -0,0 +1,132 @@
+use crate::casting::CastingKey;
+use crate::keycache::KEY_CACHE;
+use crate::parameters::*;
+use paste::paste;
+use rand::Rng;
+
+/// Number of assert in randomized tests
+const NB_TEST: usize = 30;
+
+// Macro to generate tests for some pairs of input and output parameter sets
+macro_rules! create_parametrized_test{
+    ($name:ident { $(($param_in:ident, $param_out:ident)),* }) => {
+        paste! {
+            $(
+            #[test]
+            fn [<test_ $name _ $param_in:lower _to_ $param_out:lower>]() {
+                $name($param_in, $param_out)
+            }
+            )*
+        }
+    };
+     ($name:ident)=> {
+        create_parametrized_test!($name
+        {
+            (PARAM_MESSAGE_1_CARRY_1, PARAM_MESSAGE_2_CARRY_2),
+            (PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_3_CARRY_3),
+            (PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_3),
+            (PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2)
+        });
+    };
+}
+
+create_parametrized_test!(casting_cast);
+create_parametrized_test!(casting_cast_clears_carry);
+create_parametrized_test!(casting_serialization);
+
+#[test]
+#[should_panic(expected = "The server key does not match the output parameters")]
+fn test_casting_cast_with_input_server_key() {
+    let keys_in = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1);
+    let (cks_in, sks_in) = (keys_in.client_key(), keys_in.server_key());
+    let keys_out = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+    let casting_key = CastingKey::new(cks_in, keys_out.client_key());
+
+    casting_key.cast(sks_in, &cks_in.encrypt(1));
+}
+
+#[test]
+#[should_panic(expected = "The ciphertext was not encrypted with the input parameters")]
+fn test_casting_cast_output_ciphertext() {
+    let keys_in = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1);
+    let keys_out = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2);
+    let (cks_out, sks_out) = (keys_out.client_key(), keys_out.server_key());
+    let casting_key = CastingKey::new(keys_in.client_key(), cks_out);
+
+    casting_key.cast(sks_out, &cks_out.encrypt(1));
+}
+
+fn casting_cast(param_in: Parameters, param_out: Parameters) {
+    let keys_in = KEY_CACHE.get_from_param(param_in);
+    let cks_in = keys_in.client_key();
+    let keys_out = KEY_CACHE.get_from_param(param_out);
+    let (cks_out, sks_out) = (keys_out.client_key(), keys_out.server_key());
+    let casting_key = CastingKey::new(cks_in, cks_out);
+
+    let modulus_in = param_in.message_modulus.0 as u64;
+    let modulus_out = param_out.message_modulus.0 as u64;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus_in;
+        let clear_1 = rng.gen::<u64>() % modulus_out;
+
+        let ct_out = casting_key.cast(sks_out, &cks_in.encrypt(clear_0));
+        assert_eq!(ct_out.degree.0, param_in.message_modulus.0 - 1);
+        assert_eq!(ct_out.message_modulus, param_out.message_modulus);
+        assert_eq!(cks_out.decrypt_message_and_carry(&ct_out), clear_0);
+
+        // the output can be used with the output parameters
+        let ct_res = sks_out.unchecked_add(&ct_out, &cks_out.encrypt(clear_1));
+        assert_eq!(cks_out.decrypt(&ct_res), (clear_0 + clear_1) % modulus_out);
+    }
+}
+
+fn casting_cast_clears_carry(param_in: Parameters, param_out: Parameters) {
+    let keys_in = KEY_CACHE.get_from_param(param_in);
+    let cks_in = keys_in.client_key();
+    let keys_out = KEY_CACHE.get_from_param(param_out);
+    let (cks_out, sks_out) = (keys_out.client_key(), keys_out.server_key());
+    let casting_key = CastingKey::new(cks_in, cks_out);
+
+    let modulus_in = param_in.message_modulus.0 as u64;
+    let full_modulus_in = (param_in.message_modulus.0 * param_in.carry_modulus.0) as u64;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % full_modulus_in;
+
+        // the carry of the input is used as well
+        let ct_out = casting_key.cast(sks_out, &cks_in.unchecked_encrypt(clear));
+        assert_eq!(
+            cks_out.decrypt_message_and_carry(&ct_out),
+            clear % modulus_in
+        );
+    }
+}
+
+fn casting_serialization(param_in: Parameters, param_out: Parameters) {
+    let keys_in = KEY_CACHE.get_from_param(param_in);
+    let cks_in = keys_in.client_key();
+    let keys_out = KEY_CACHE.get_from_param(param_out);
+    let (cks_out, sks_out) = (keys_out.client_key(), keys_out.server_key());
+    let casting_key = CastingKey::new(cks_in, cks_out);
+
+    let casting_key: CastingKey =
+        bincode::deserialize(&bincode::serialize(&casting_key).unwrap()).unwrap();
+    assert_eq!(*casting_key.parameters_in(), param_in);
+    assert_eq!(*casting_key.parameters_out(), param_out);
+
+    let modulus_in = param_in.message_modulus.0 as u64;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus_in;
+
+        let ct_out = casting_key.cast(sks_out, &cks_in.encrypt(clear));
+        assert_eq!(cks_out.decrypt(&ct_out), clear);
+    }
+}
@@ -1,9 +1,11 @@
 //! Module with the definition of a short-integer ciphertext.
+use crate::engine::ShortintEngine;
 use crate::parameters::{CarryModulus, MessageModulus};
 use concrete_core::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::cmp;
 use std::fmt::Debug;
+use std::ops::{Add, Mul};
 
 /// This indicates the number of operations that has been done.
 ///
@@ -59,7 +61,6 @@ impl Degree {
         Degree(result)
     }
 
-    #[allow(dead_code)]
     pub(crate) fn after_pbs<F>(&self, f: F) -> Degree
     where
         F: Fn(usize) -> usize,
@@ -75,6 +76,57 @@ impl Degree {
 
         Degree(result)
     }
+
+    pub(crate) fn after_bivariate_pbs<F>(&self, other: Degree, f: F) -> Degree
+    where
+        F: Fn(usize, usize) -> usize,
+    {
+        let mut result = 0;
+
+        //Try every possibility to find the worst case
+        for i in 0..self.0 + 1 {
+            for j in 0..other.0 + 1 {
+                let tmp = f(i, j);
+                if tmp > result {
+                    result = tmp;
+                }
+            }
+        }
+
+        Degree(result)
+    }
+}
+
+/// This indicates the amount of noise held by a ciphertext, as a multiple of the noise of a
+/// freshly encrypted or bootstrapped ciphertext.
+///
+/// For instances, computing an addition sums the noise levels of the operands, whereas a
+/// multiplication by a constant $\lambda$ multiplies it by $\lambda$. A bootstrap resets it to
+/// [`NoiseLevel::NOMINAL`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Serialize, Deserialize)]
+pub struct NoiseLevel(pub usize);
+
+impl NoiseLevel {
+    /// The noise level of a trivial encryption, which holds no noise.
+    pub const ZERO: NoiseLevel = NoiseLevel(0);
+    /// The noise level of a freshly encrypted or bootstrapped ciphertext.
+    pub const NOMINAL: NoiseLevel = NoiseLevel(1);
+}
+
+impl Add for NoiseLevel {
+    type Output = NoiseLevel;
+
+    fn add(self, rhs: NoiseLevel) -> NoiseLevel {
+        NoiseLevel(self.0 + rhs.0)
+    }
+}
+
+impl Mul<usize> for NoiseLevel {
+    type Output = NoiseLevel;
+
+    fn mul(self, rhs: usize) -> NoiseLevel {
+        NoiseLevel(self.0 * rhs)
+    }
 }
 
 /// A structure representing a short-integer ciphertext.
@@ -84,6 +136,7 @@ impl Degree {
 pub struct Ciphertext {
     pub ct: LweCiphertext64,
     pub degree: Degree,
+    pub noise_level: NoiseLevel,
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
 }
@@ -92,6 +145,7 @@ pub struct Ciphertext {
 struct SerializableCiphertext {
     data: Vec<u8>,
     pub degree: Degree,
+    pub noise_level: NoiseLevel,
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
 }
@@ -110,6 +164,7 @@ impl Serialize for Ciphertext {
         SerializableCiphertext {
             data,
             degree: self.degree,
+            noise_level: self.noise_level,
             message_modulus: self.message_modulus,
             carry_modulus: self.carry_modulus,
         }
@@ -126,6 +181,105 @@ impl<'de> Deserialize<'de> for Ciphertext {
 
         let mut de_eng = DefaultSerializationEngine::new(()).map_err(serde::de::Error::custom)?;
 
+        let ct = de_eng
+            .deserialize(thing.data.as_slice())
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            ct,
+            degree: thing.degree,
+            noise_level: thing.noise_level,
+            message_modulus: thing.message_modulus,
+            carry_modulus: thing.carry_modulus,
+        })
+    }
+}
+
+/// A structure representing a compressed short-integer ciphertext.
+///
+/// The uniformly random mask of the LWE ciphertext is replaced by the seed used to generate it,
+/// which makes it much smaller to send to the server. It has to be decompressed into a
+/// [`Ciphertext`] before being used in a circuit.
+#[derive(Clone)]
+pub struct CompressedCiphertext {
+    pub ct: LweSeededCiphertext64,
+    pub degree: Degree,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+}
+
+impl CompressedCiphertext {
+    /// Regenerates the mask of the ciphertext from its seed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // Encryption of one message on the client side:
+    /// let compressed_ct = cks.encrypt_compressed(3);
+    ///
+    /// // Decompression on the server side:
+    /// let ct = compressed_ct.decompress();
+    /// let ct_res = sks.unchecked_scalar_add(&ct, 1);
+    ///
+    /// // Decryption:
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(0, dec);
+    /// ```
+    pub fn decompress(&self) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| engine.decompress_ciphertext(self).unwrap())
+    }
+}
+
+impl From<CompressedCiphertext> for Ciphertext {
+    fn from(compressed_ct: CompressedCiphertext) -> Self {
+        compressed_ct.decompress()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializableCompressedCiphertext {
+    data: Vec<u8>,
+    pub degree: Degree,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+}
+
+impl Serialize for CompressedCiphertext {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_eng = DefaultSerializationEngine::new(()).map_err(serde::ser::Error::custom)?;
+
+        let data = ser_eng
+            .serialize(&self.ct)
+            .map_err(serde::ser::Error::custom)?;
+
+        SerializableCompressedCiphertext {
+            data,
+            degree: self.degree,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompressedCiphertext {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let thing = SerializableCompressedCiphertext::deserialize(deserializer)?;
+
+        let mut de_eng = DefaultSerializationEngine::new(()).map_err(serde::de::Error::custom)?;
+
         let ct = de_eng
             .deserialize(thing.data.as_slice())
             .map_err(serde::de::Error::custom)?;
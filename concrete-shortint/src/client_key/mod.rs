@@ -1,6 +1,6 @@
 //! Module with the definition of the ClientKey.
 
-use crate::ciphertext::Ciphertext;
+use crate::ciphertext::{Ciphertext, CompressedCiphertext};
 use crate::engine::ShortintEngine;
 use crate::parameters::{MessageModulus, Parameters};
 use concrete_core::prelude::*;
@@ -72,6 +72,33 @@ impl ClientKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.encrypt(self, message).unwrap())
     }
 
+    /// Encrypts a small integer message using the client key, returning a compressed ciphertext.
+    ///
+    /// The input message is reduced to the encrypted message space modulus
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use concrete_shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let compressed_ct = cks.encrypt_compressed(msg);
+    ///
+    /// // The ciphertext is decompressed before being used
+    /// let ct = compressed_ct.decompress();
+    ///
+    /// let dec = cks.decrypt(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn encrypt_compressed(&self, message: u64) -> CompressedCiphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.encrypt_compressed(self, message).unwrap()
+        })
+    }
+
     /// Encrypts a small integer message using the client key with a specific message modulus
     ///
     /// # Example
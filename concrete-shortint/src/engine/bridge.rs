@@ -0,0 +1,179 @@
+use crate::bridge::BridgeKey;
+use crate::ciphertext::{Degree, NoiseLevel};
+use crate::engine::{EngineResult, ShortintEngine};
+use crate::{Ciphertext, ClientKey, ServerKey};
+use concrete_boolean::ciphertext::Ciphertext as BooleanCiphertext;
+use concrete_boolean::client_key::ClientKey as BooleanClientKey;
+use concrete_core::prelude::*;
+
+impl ShortintEngine {
+    pub(crate) fn new_bridge_key(
+        &mut self,
+        boolean_cks: &BooleanClientKey,
+        cks: &ClientKey,
+    ) -> EngineResult<BridgeKey> {
+        let boolean_parameters = *boolean_cks.parameters();
+
+        // The binary boolean secret key, on 64 bits
+        let boolean_secret_key: Vec<u64> = self
+            .engine
+            .consume_retrieve_lwe_secret_key(boolean_cks.lwe_secret_key().clone())?
+            .into_iter()
+            .map(u64::from)
+            .collect();
+        let boolean_secret_key: LweSecretKey64 =
+            self.engine.create_lwe_secret_key_from(boolean_secret_key)?;
+
+        // KSK boolean key -> small shortint key, the bootstrap of the server key follows
+        let ksk_boolean_to_shortint = self.engine.generate_new_lwe_keyswitch_key(
+            &boolean_secret_key,
+            &cks.lwe_secret_key_after_ks,
+            cks.parameters.ks_level,
+            cks.parameters.ks_base_log,
+            Variance(cks.parameters.lwe_modular_std_dev.get_variance()),
+        )?;
+
+        // KSK large shortint key -> boolean key, applied after the bootstrap of the server key
+        let ksk_shortint_to_boolean = self.engine.generate_new_lwe_keyswitch_key(
+            &cks.lwe_secret_key,
+            &boolean_secret_key,
+            boolean_parameters.ks_level,
+            boolean_parameters.ks_base_log,
+            Variance(boolean_parameters.lwe_modular_std_dev.get_variance()),
+        )?;
+
+        Ok(BridgeKey {
+            ksk_boolean_to_shortint,
+            ksk_shortint_to_boolean,
+            boolean_parameters,
+            parameters: cks.parameters,
+        })
+    }
+
+    pub(crate) fn boolean_to_shortint(
+        &mut self,
+        bridge_key: &BridgeKey,
+        server_key: &ServerKey,
+        ct: &BooleanCiphertext,
+    ) -> EngineResult<Ciphertext> {
+        let ct_boolean = match ct {
+            BooleanCiphertext::Encrypted(ct_boolean) => ct_boolean,
+            BooleanCiphertext::Trivial(message) => {
+                return self.create_trivial(server_key, *message as u8);
+            }
+        };
+
+        // Lift the ciphertext to 64 bits, and move the messages from -1/8 and 1/8 to 1/8 and 3/8,
+        // so that both lie in the first half of the torus
+        let mut data: Vec<u64> = self
+            .engine
+            .consume_retrieve_lwe_ciphertext(ct_boolean.clone())?
+            .into_iter()
+            .map(|coefficient| u64::from(coefficient) << 32)
+            .collect();
+        let body = data.last_mut().unwrap();
+        *body = body.wrapping_add(1 << 62);
+        let ct_lifted: LweCiphertext64 = self.engine.create_lwe_ciphertext_from(data)?;
+
+        // This accumulator outputs 0 below 1/4 and 1 above
+        let delta =
+            (1_u64 << 63) / (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
+        let polynomial_size = server_key.bootstrapping_key.polynomial_size().0;
+        let accumulator_u64: Vec<u64> = (0..polynomial_size)
+            .map(|i| if i < polynomial_size / 2 { 0 } else { delta })
+            .collect();
+        let accumulator = self.create_accumulator(server_key, &accumulator_u64)?;
+
+        let (buffers, engine, fft_engine) = self.buffers_for_key(server_key);
+
+        // Compute a key switch to the small shortint key
+        engine.discard_keyswitch_lwe_ciphertext(
+            &mut buffers.buffer_lwe_after_ks,
+            &ct_lifted,
+            &bridge_key.ksk_boolean_to_shortint,
+        )?;
+
+        // Compute a bootstrap
+        let lwe_size = server_key
+            .bootstrapping_key
+            .output_lwe_dimension()
+            .to_lwe_size();
+        let mut ct_res: LweCiphertext64 =
+            engine.create_lwe_ciphertext_from(vec![0u64; lwe_size.0])?;
+        fft_engine.discard_bootstrap_lwe_ciphertext(
+            &mut ct_res,
+            &buffers.buffer_lwe_after_ks,
+            &accumulator,
+            &server_key.bootstrapping_key,
+        )?;
+
+        Ok(Ciphertext {
+            ct: ct_res,
+            degree: Degree(1),
+            noise_level: NoiseLevel::NOMINAL,
+            message_modulus: server_key.message_modulus,
+            carry_modulus: server_key.carry_modulus,
+        })
+    }
+
+    pub(crate) fn shortint_to_boolean(
+        &mut self,
+        bridge_key: &BridgeKey,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+    ) -> EngineResult<BooleanCiphertext> {
+        // Move the message 0 to -delta/2, the other messages stay in the first half of the torus
+        let delta =
+            (1_u64 << 63) / (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
+        let shift_plaintext = self
+            .engine
+            .create_plaintext_from(&(delta / 2).wrapping_neg())?;
+        let mut ct_shifted = ct.clone();
+        self.engine
+            .fuse_add_lwe_ciphertext_plaintext(&mut ct_shifted.ct, &shift_plaintext)?;
+
+        // Sign bootstrap: the negacyclic accumulator outputs 1/8 on the first half of the torus,
+        // and -1/8 on the second one
+        let polynomial_size = server_key.bootstrapping_key.polynomial_size().0;
+        let accumulator = self.create_accumulator(server_key, &vec![1 << 61; polynomial_size])?;
+        self.programmable_bootstrap_keyswitch_assign(server_key, &mut ct_shifted, &accumulator)?;
+
+        // Compute a key switch to the boolean key
+        let lwe_size = bridge_key.boolean_parameters.lwe_dimension.to_lwe_size();
+        let mut ct_boolean: LweCiphertext64 = self
+            .engine
+            .create_lwe_ciphertext_from(vec![0u64; lwe_size.0])?;
+        self.engine.discard_keyswitch_lwe_ciphertext(
+            &mut ct_boolean,
+            &ct_shifted.ct,
+            &bridge_key.ksk_shortint_to_boolean,
+        )?;
+
+        // Round the ciphertext to 32 bits
+        let data: Vec<u32> = self
+            .engine
+            .consume_retrieve_lwe_ciphertext(ct_boolean)?
+            .into_iter()
+            .map(|coefficient| (coefficient.wrapping_add(1 << 31) >> 32) as u32)
+            .collect();
+
+        Ok(BooleanCiphertext::Encrypted(
+            self.engine.create_lwe_ciphertext_from(data)?,
+        ))
+    }
+
+    fn create_accumulator(
+        &mut self,
+        server_key: &ServerKey,
+        accumulator_u64: &[u64],
+    ) -> EngineResult<GlweCiphertext64> {
+        let accumulator_plaintext = self.engine.create_plaintext_vector_from(accumulator_u64)?;
+
+        let accumulator = self.engine.trivially_encrypt_glwe_ciphertext(
+            server_key.bootstrapping_key.glwe_dimension().to_glwe_size(),
+            &accumulator_plaintext,
+        )?;
+
+        Ok(accumulator)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::casting::CastingKey;
+use crate::ciphertext::{Degree, NoiseLevel};
+use crate::engine::{EngineResult, LutId, ShortintEngine};
+use crate::{Ciphertext, ClientKey, ServerKey};
+use concrete_core::prelude::*;
+use std::collections::btree_map::Entry;
+
+impl ShortintEngine {
+    pub(crate) fn new_casting_key(
+        &mut self,
+        cks_in: &ClientKey,
+        cks_out: &ClientKey,
+    ) -> EngineResult<CastingKey> {
+        // KSK large input key -> small output key, the bootstrap of the output server key follows
+        let ksk = self.engine.generate_new_lwe_keyswitch_key(
+            &cks_in.lwe_secret_key,
+            &cks_out.lwe_secret_key_after_ks,
+            cks_out.parameters.ks_level,
+            cks_out.parameters.ks_base_log,
+            Variance(cks_out.parameters.lwe_modular_std_dev.get_variance()),
+        )?;
+
+        Ok(CastingKey {
+            ksk,
+            parameters_in: cks_in.parameters,
+            parameters_out: cks_out.parameters,
+        })
+    }
+
+    pub(crate) fn cast(
+        &mut self,
+        casting_key: &CastingKey,
+        server_key_out: &ServerKey,
+        ct: &Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let message_modulus_in = ct.message_modulus.0 as u64;
+        let modulus_in = (ct.message_modulus.0 * ct.carry_modulus.0) as u64;
+
+        let (buffers, engine, fft_engine) = self.buffers_for_key(server_key_out);
+
+        let accumulator = match buffers
+            .luts
+            .entry(LutId::Cast(message_modulus_in, modulus_in))
+        {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Self::generate_casting_accumulator_with_engine(
+                engine,
+                server_key_out,
+                message_modulus_in,
+                modulus_in,
+            )?),
+        };
+
+        // Compute a key switch to the small output key
+        engine.discard_keyswitch_lwe_ciphertext(
+            &mut buffers.buffer_lwe_after_ks,
+            &ct.ct,
+            &casting_key.ksk,
+        )?;
+
+        // Compute a bootstrap to the large output key
+        let lwe_size = server_key_out
+            .bootstrapping_key
+            .output_lwe_dimension()
+            .to_lwe_size();
+        let mut ct_res: LweCiphertext64 =
+            engine.create_lwe_ciphertext_from(vec![0u64; lwe_size.0])?;
+        fft_engine.discard_bootstrap_lwe_ciphertext(
+            &mut ct_res,
+            &buffers.buffer_lwe_after_ks,
+            accumulator,
+            &server_key_out.bootstrapping_key,
+        )?;
+
+        Ok(Ciphertext {
+            ct: ct_res,
+            degree: ct.degree.after_pbs(|x| x % ct.message_modulus.0),
+            noise_level: NoiseLevel::NOMINAL,
+            message_modulus: server_key_out.message_modulus,
+            carry_modulus: server_key_out.carry_modulus,
+        })
+    }
+
+    fn generate_casting_accumulator_with_engine(
+        engine: &mut DefaultEngine,
+        server_key_out: &ServerKey,
+        message_modulus_in: u64,
+        modulus_in: u64,
+    ) -> EngineResult<GlweCiphertext64> {
+        // The boxes of the accumulator follow the encoding of the input, while the values stored
+        // in them follow the encoding of the output
+        let modulus_out = server_key_out.message_modulus.0 * server_key_out.carry_modulus.0;
+        let polynomial_size = server_key_out.bootstrapping_key.polynomial_size().0;
+        let box_size = polynomial_size / modulus_in as usize;
+        let delta_out = (1_u64 << 63) / modulus_out as u64;
+
+        // This accumulator clears the carry of the input
+        let mut accumulator_u64 = vec![0_u64; polynomial_size];
+        for i in 0..modulus_in as usize {
+            let index = i * box_size;
+            accumulator_u64[index..index + box_size]
+                .iter_mut()
+                .for_each(|a| *a = (i as u64 % message_modulus_in) * delta_out);
+        }
+
+        // Negate the first half_box_size coefficients, and rotate the accumulator
+        let half_box_size = box_size / 2;
+        for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+            *a_i = (*a_i).wrapping_neg();
+        }
+        accumulator_u64.rotate_left(half_box_size);
+
+        let accumulator_plaintext = engine.create_plaintext_vector_from(&accumulator_u64)?;
+        let accumulator = engine.trivially_encrypt_glwe_ciphertext(
+            server_key_out
+                .bootstrapping_key
+                .glwe_dimension()
+                .to_glwe_size(),
+            &accumulator_plaintext,
+        )?;
+
+        Ok(accumulator)
+    }
+}
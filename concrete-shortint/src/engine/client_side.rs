@@ -1,8 +1,8 @@
 //! All the `ShortintEngine` method related to client side (encrypt / decrypt)
 use super::{EngineResult, ShortintEngine};
-use crate::ciphertext::Degree;
+use crate::ciphertext::{Degree, NoiseLevel};
 use crate::parameters::{CarryModulus, MessageModulus};
-use crate::{Ciphertext, ClientKey, Parameters};
+use crate::{Ciphertext, ClientKey, CompressedCiphertext, Parameters};
 use concrete_core::prelude::*;
 
 impl ShortintEngine {
@@ -77,11 +77,47 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct,
             degree: Degree(message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
         })
     }
 
+    pub(crate) fn encrypt_compressed(
+        &mut self,
+        client_key: &ClientKey,
+        message: u64,
+    ) -> EngineResult<CompressedCiphertext> {
+        let message_modulus = client_key.parameters.message_modulus;
+
+        //The delta is the one defined by the parameters
+        let delta = (1_u64 << 63)
+            / (client_key.parameters.message_modulus.0 * client_key.parameters.carry_modulus.0)
+                as u64;
+
+        //The input is reduced modulus the message_modulus
+        let m = message % message_modulus.0 as u64;
+
+        let shifted_message = m * delta;
+        // encode the message
+        let plain: Plaintext64 = self.engine.create_plaintext_from(&shifted_message)?;
+
+        // convert into a variance
+        let var = Variance(client_key.parameters.lwe_modular_std_dev.get_variance());
+
+        // encryption, the mask is replaced by a seed
+        let ct =
+            self.engine
+                .encrypt_lwe_seeded_ciphertext(&client_key.lwe_secret_key, &plain, var)?;
+
+        Ok(CompressedCiphertext {
+            ct,
+            degree: Degree(message_modulus.0 - 1),
+            message_modulus,
+            carry_modulus: client_key.parameters.carry_modulus,
+        })
+    }
+
     pub(crate) fn unchecked_encrypt(
         &mut self,
         client_key: &ClientKey,
@@ -106,6 +142,7 @@ impl ShortintEngine {
             degree: Degree(
                 client_key.parameters.message_modulus.0 * client_key.parameters.carry_modulus.0 - 1,
             ),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: client_key.parameters.message_modulus,
             carry_modulus: client_key.parameters.carry_modulus,
         })
@@ -169,6 +206,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct,
             degree: Degree(client_key.parameters.message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: client_key.parameters.message_modulus,
             carry_modulus: client_key.parameters.carry_modulus,
         })
@@ -235,6 +273,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct,
             degree: Degree(message_modulus as usize - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
         })
@@ -1,13 +1,20 @@
+use crate::parameters::{CarryModulus, MessageModulus};
 use crate::ServerKey;
 use concrete_core::prelude::*;
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::fmt::Debug;
 
+#[cfg(feature = "boolean")]
+mod bridge;
+mod casting;
 mod client_side;
+mod public_side;
 mod server_side;
 mod wopbs;
 
+pub(crate) use server_side::{rotate_left, rotate_right};
+
 thread_local! {
     static LOCAL_ENGINE: RefCell<ShortintEngine> = RefCell::new(ShortintEngine::new());
 }
@@ -34,16 +41,69 @@ fn new_seeder() -> Box<dyn Seeder> {
 pub struct Buffers {
     pub(crate) accumulator: GlweCiphertext64,
     pub(crate) buffer_lwe_after_ks: LweCiphertext64,
+    // Accumulators of the built-in functions, generated on their first use
+    pub(crate) luts: BTreeMap<LutId, GlweCiphertext64>,
+}
+
+/// Identifies the accumulator of a built-in function in the `Buffers`.
+///
+/// Bivariate functions are identified along with the modulus used to pack the right operand, and
+/// scalar functions along with their scalar, as the accumulators depend on them. Divisions and
+/// remainders are also identified by the message modulus of the dividend and by the value they
+/// return when the divisor is zero.
+///
+/// Functions depending on the message modulus of the ciphertext are identified along with it: under
+/// a single key, the blocks of a CRT decomposition each have their own message modulus.
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+pub(crate) enum LutId {
+    MessageExtract(u64),
+    CarryExtract(u64),
+    BitAnd(u64),
+    BitXor(u64),
+    BitOr(u64),
+    Greater(u64, u64),
+    GreaterOrEqual(u64, u64),
+    Less(u64, u64),
+    LessOrEqual(u64, u64),
+    Equal(u64, u64),
+    Min(u64),
+    Max(u64),
+    Div(u64, u64, u64),
+    Rem(u64, u64, u64),
+    DivisorIsZero(u64),
+    ShiftLeft(u64),
+    ShiftRight(u64),
+    RotateLeft(u64),
+    RotateRight(u64),
+    MulLsb(u64, u64),
+    MulMsb(u64),
+    ScalarDiv(u8),
+    ScalarMod(u8),
+    ScalarBitAnd(u8),
+    ScalarBitXor(u8),
+    ScalarBitOr(u8),
+    ScalarMin(u8),
+    ScalarMax(u8),
+    ScalarRightShift(u8),
+    ScalarRotateLeft(u8),
+    ScalarRotateRight(u8),
+    // Casting from another parameter set, identified by the message modulus and the whole
+    // plaintext space of the input
+    Cast(u64, u64),
 }
 
 /// This allows to store and retrieve the `Buffers`
 /// corresponding to a `ServerKey` in a `BTreeMap`
+///
+/// The moduli are part of the id since the accumulators encode the messages with them.
 #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
 struct KeyId {
     accumulator_dim: GlweSize,
     lwe_dim_after_pbs: usize,
     glwe_size: GlweSize,
     poly_size: PolynomialSize,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
 }
 
 impl ServerKey {
@@ -54,21 +114,32 @@ impl ServerKey {
             lwe_dim_after_pbs: self.bootstrapping_key.output_lwe_dimension().0,
             glwe_size: self.bootstrapping_key.glwe_dimension().to_glwe_size(),
             poly_size: self.bootstrapping_key.polynomial_size(),
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
         }
     }
 }
 
 /// Simple wrapper around `std::error::Error` to be able to
 /// forward all the possible `EngineError` type from `concrete-core`
-#[allow(dead_code)]
+///
+/// It does not implement `std::error::Error` itself, as it would conflict with its `From`
+/// conversion of any error. The wrapped error is `Send` and `Sync`, so that the errors of the
+/// operations can be moved across threads.
 #[derive(Debug)]
 pub struct EngineError {
-    error: Box<dyn std::error::Error>,
+    error: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl std::fmt::Display for EngineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.error, f)
+    }
 }
 
 impl<T> From<T> for EngineError
 where
-    T: std::error::Error + 'static,
+    T: std::error::Error + Send + Sync + 'static,
 {
     fn from(error: T) -> Self {
         Self {
@@ -213,6 +284,7 @@ impl ShortintEngine {
             Buffers {
                 accumulator,
                 buffer_lwe_after_ks: buffer_lwe_after_pbs,
+                luts: BTreeMap::new(),
             }
         });
 
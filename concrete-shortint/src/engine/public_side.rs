@@ -0,0 +1,152 @@
+//! All the `ShortintEngine` method related to public side (public key generation / encrypt)
+use super::{EngineResult, ShortintEngine};
+use crate::ciphertext::{Degree, NoiseLevel};
+use crate::parameters::{CarryModulus, MessageModulus};
+use crate::{Ciphertext, ClientKey, PublicKey};
+use concrete_core::prelude::*;
+
+const LOG2_Q_64: usize = 64;
+
+impl ShortintEngine {
+    pub(crate) fn new_public_key(&mut self, client_key: &ClientKey) -> EngineResult<PublicKey> {
+        // The ciphertexts are encrypted under the large lwe secret key, i.e. the glwe secret key
+        let lwe_dimension =
+            client_key.parameters.glwe_dimension.0 * client_key.parameters.polynomial_size.0;
+
+        // Formula is (n + 1) * log2(q) + 128
+        let zero_encryption_count =
+            LwePublicKeyZeroEncryptionCount((lwe_dimension + 1) * LOG2_Q_64 + 128);
+
+        // convert into a variance, the one the glwe secret key is secure with
+        let var = Variance(client_key.parameters.glwe_modular_std_dev.get_variance());
+
+        // generate the zero encryptions
+        let lwe_public_key: LwePublicKey64 = self.engine.generate_new_lwe_public_key(
+            &client_key.lwe_secret_key,
+            var,
+            zero_encryption_count,
+        )?;
+
+        Ok(PublicKey {
+            lwe_public_key,
+            parameters: client_key.parameters,
+        })
+    }
+
+    pub(crate) fn encrypt_with_public_key(
+        &mut self,
+        public_key: &PublicKey,
+        message: u64,
+    ) -> EngineResult<Ciphertext> {
+        self.encrypt_with_message_modulus_and_public_key(
+            public_key,
+            message,
+            public_key.parameters.message_modulus,
+        )
+    }
+
+    pub(crate) fn encrypt_with_message_modulus_and_public_key(
+        &mut self,
+        public_key: &PublicKey,
+        message: u64,
+        message_modulus: MessageModulus,
+    ) -> EngineResult<Ciphertext> {
+        //This ensures that the space message_modulus*carry_modulus < param.message_modulus *
+        // param.carry_modulus
+        let carry_modulus = (public_key.parameters.message_modulus.0
+            * public_key.parameters.carry_modulus.0)
+            / message_modulus.0;
+
+        //The delta is the one defined by the parameters
+        let delta = (1_u64 << 63)
+            / (public_key.parameters.message_modulus.0 * public_key.parameters.carry_modulus.0)
+                as u64;
+
+        //The input is reduced modulus the message_modulus
+        let m = message % message_modulus.0 as u64;
+
+        let shifted_message = m * delta;
+
+        // encryption
+        let ct = self.encrypt_plaintext_with_public_key(public_key, shifted_message)?;
+
+        Ok(Ciphertext {
+            ct,
+            degree: Degree(message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
+            message_modulus,
+            carry_modulus: CarryModulus(carry_modulus),
+        })
+    }
+
+    pub(crate) fn encrypt_without_padding_with_public_key(
+        &mut self,
+        public_key: &PublicKey,
+        message: u64,
+    ) -> EngineResult<Ciphertext> {
+        //Multiply by 2 to reshift and exclude the padding bit
+        let delta = ((1_u64 << 63)
+            / (public_key.parameters.message_modulus.0 * public_key.parameters.carry_modulus.0)
+                as u64)
+            * 2;
+
+        let shifted_message = message * delta;
+
+        // encryption
+        let ct = self.encrypt_plaintext_with_public_key(public_key, shifted_message)?;
+
+        Ok(Ciphertext {
+            ct,
+            degree: Degree(public_key.parameters.message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
+            message_modulus: public_key.parameters.message_modulus,
+            carry_modulus: public_key.parameters.carry_modulus,
+        })
+    }
+
+    pub(crate) fn encrypt_native_crt_with_public_key(
+        &mut self,
+        public_key: &PublicKey,
+        message: u64,
+        message_modulus: u8,
+    ) -> EngineResult<Ciphertext> {
+        let carry_modulus = 1;
+        let m = (message % message_modulus as u64) as u128;
+        let shifted_message = m * (1 << 64) / message_modulus as u128;
+
+        // encryption
+        let ct = self.encrypt_plaintext_with_public_key(public_key, shifted_message as u64)?;
+
+        Ok(Ciphertext {
+            ct,
+            degree: Degree(message_modulus as usize - 1),
+            noise_level: NoiseLevel::NOMINAL,
+            message_modulus: MessageModulus(message_modulus as usize),
+            carry_modulus: CarryModulus(carry_modulus),
+        })
+    }
+
+    fn encrypt_plaintext_with_public_key(
+        &mut self,
+        public_key: &PublicKey,
+        shifted_message: u64,
+    ) -> EngineResult<LweCiphertext64> {
+        // encode the message
+        let plain: Plaintext64 = self.engine.create_plaintext_from(&shifted_message)?;
+
+        // allocate the output ciphertext
+        let lwe_size = public_key.lwe_public_key.lwe_dimension().to_lwe_size();
+        let mut ct = self
+            .engine
+            .create_lwe_ciphertext_from(vec![0u64; lwe_size.0])?;
+
+        // encryption: random subset sum of the zero encryptions, plus the plaintext
+        self.engine.discard_encrypt_lwe_ciphertext_with_public_key(
+            &public_key.lwe_public_key,
+            &mut ct,
+            &plain,
+        )?;
+
+        Ok(ct)
+    }
+}
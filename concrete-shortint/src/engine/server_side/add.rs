@@ -1,4 +1,4 @@
-use crate::ciphertext::Degree;
+use crate::ciphertext::{Degree, NoiseLevel};
 use crate::engine::{EngineResult, ShortintEngine};
 use crate::{Ciphertext, ServerKey};
 use concrete_core::prelude::*;
@@ -22,6 +22,7 @@ impl ShortintEngine {
         self.engine
             .fuse_add_lwe_ciphertext(&mut ct_left.ct, &ct_right.ct)?;
         ct_left.degree = Degree(ct_left.degree.0 + ct_right.degree.0);
+        ct_left.noise_level = ct_left.noise_level + ct_right.noise_level;
         Ok(())
     }
 
@@ -44,9 +45,19 @@ impl ShortintEngine {
     ) -> EngineResult<()> {
         //If the ciphertext cannot be added together without exceeding the capacity of a ciphertext
         if !server_key.is_add_possible(ct_left, ct_right) {
-            if ct_left.message_modulus.0 - 1 + ct_right.degree.0 <= server_key.max_degree.0 {
+            // The message extraction resets the noise level of the extracted ciphertext
+            let max_noise_level = server_key.max_noise_level;
+            if ct_left.message_modulus.0 - 1 + ct_right.degree.0 <= server_key.max_degree.0
+                && max_noise_level
+                    .validate(NoiseLevel::NOMINAL + ct_right.noise_level)
+                    .is_ok()
+            {
                 self.message_extract_assign(server_key, ct_left)?;
-            } else if ct_right.message_modulus.0 - 1 + ct_left.degree.0 <= server_key.max_degree.0 {
+            } else if ct_right.message_modulus.0 - 1 + ct_left.degree.0 <= server_key.max_degree.0
+                && max_noise_level
+                    .validate(ct_left.noise_level + NoiseLevel::NOMINAL)
+                    .is_ok()
+            {
                 self.message_extract_assign(server_key, ct_right)?;
             } else {
                 self.message_extract_assign(server_key, ct_left)?;
@@ -1,4 +1,4 @@
-use crate::engine::{EngineResult, ShortintEngine};
+use crate::engine::{EngineResult, LutId, ShortintEngine};
 use crate::{Ciphertext, ServerKey};
 
 impl ShortintEngine {
@@ -20,9 +20,13 @@ impl ShortintEngine {
         ct_right: &Ciphertext,
     ) -> EngineResult<()> {
         let modulus = (ct_right.degree.0 + 1) as u64;
-        self.unchecked_functional_bivariate_pbs_assign(server_key, ct_left, ct_right, |x| {
-            ((x / modulus) & (x % modulus)) as u64
-        })?;
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::BitAnd(modulus),
+            |x| ((x / modulus) & (x % modulus)) as u64,
+        )?;
         ct_left.degree = ct_left.degree.after_bitand(ct_right.degree);
         Ok(())
     }
@@ -70,9 +74,13 @@ impl ShortintEngine {
         ct_right: &Ciphertext,
     ) -> EngineResult<()> {
         let modulus = (ct_right.degree.0 + 1) as u64;
-        self.unchecked_functional_bivariate_pbs_assign(server_key, ct_left, ct_right, |x| {
-            ((x / modulus) ^ (x % modulus)) as u64
-        })?;
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::BitXor(modulus),
+            |x| ((x / modulus) ^ (x % modulus)) as u64,
+        )?;
         ct_left.degree = ct_left.degree.after_bitxor(ct_right.degree);
         Ok(())
     }
@@ -120,9 +128,13 @@ impl ShortintEngine {
         ct_right: &Ciphertext,
     ) -> EngineResult<()> {
         let modulus = (ct_right.degree.0 + 1) as u64;
-        self.unchecked_functional_bivariate_pbs_assign(server_key, ct_left, ct_right, |x| {
-            ((x / modulus) | (x % modulus)) as u64
-        })?;
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::BitOr(modulus),
+            |x| ((x / modulus) | (x % modulus)) as u64,
+        )?;
         ct_left.degree = ct_left.degree.after_bitor(ct_right.degree);
         Ok(())
     }
@@ -151,4 +163,168 @@ impl ShortintEngine {
         self.unchecked_bitor_assign(server_key, ct_left, ct_right)?;
         Ok(())
     }
+
+    pub(crate) fn unchecked_scalar_bitand(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_bitand_assign(server_key, &mut result, scalar)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_scalar_bitand_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        let degree = ct.degree.after_pbs(|x| x & scalar as usize);
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::ScalarBitAnd(scalar),
+            |x| x & scalar as u64,
+        )?;
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_scalar_bitand(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.smart_scalar_bitand_assign(server_key, &mut result, scalar)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_scalar_bitand_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        if !server_key.is_scalar_bitand_possible(ct, scalar) {
+            self.message_extract_assign(server_key, ct)?;
+        }
+        self.unchecked_scalar_bitand_assign(server_key, ct, scalar)?;
+        Ok(())
+    }
+
+    pub(crate) fn unchecked_scalar_bitxor(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_bitxor_assign(server_key, &mut result, scalar)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_scalar_bitxor_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        let degree = ct.degree.after_pbs(|x| x ^ scalar as usize);
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::ScalarBitXor(scalar),
+            |x| x ^ scalar as u64,
+        )?;
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_scalar_bitxor(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.smart_scalar_bitxor_assign(server_key, &mut result, scalar)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_scalar_bitxor_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        if scalar as usize >= ct.message_modulus.0 {
+            // The result is reduced modulo the message modulus, as in smart_scalar_mul
+            return self.reduced_message_pbs_assign(server_key, ct, |x| x ^ scalar as u64);
+        }
+        if !server_key.is_scalar_bitxor_possible(ct, scalar) {
+            self.message_extract_assign(server_key, ct)?;
+        }
+        self.unchecked_scalar_bitxor_assign(server_key, ct, scalar)?;
+        Ok(())
+    }
+
+    pub(crate) fn unchecked_scalar_bitor(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_bitor_assign(server_key, &mut result, scalar)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_scalar_bitor_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        let degree = ct.degree.after_pbs(|x| x | scalar as usize);
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::ScalarBitOr(scalar),
+            |x| x | scalar as u64,
+        )?;
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_scalar_bitor(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.smart_scalar_bitor_assign(server_key, &mut result, scalar)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_scalar_bitor_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        if scalar as usize >= ct.message_modulus.0 {
+            // The result is reduced modulo the message modulus, as in smart_scalar_mul
+            return self.reduced_message_pbs_assign(server_key, ct, |x| x | scalar as u64);
+        }
+        if !server_key.is_scalar_bitor_possible(ct, scalar) {
+            self.message_extract_assign(server_key, ct)?;
+        }
+        self.unchecked_scalar_bitor_assign(server_key, ct, scalar)?;
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use crate::engine::{EngineResult, ShortintEngine};
+use crate::engine::{EngineResult, LutId, ShortintEngine};
 use crate::{Ciphertext, ServerKey};
 
 impl ShortintEngine {
@@ -22,9 +22,13 @@ impl ShortintEngine {
         let modulus = (ct_right.degree.0 + 1) as u64;
         let modulus_msg = ct_left.message_modulus.0 as u64;
         let large_mod = modulus * modulus_msg;
-        self.unchecked_functional_bivariate_pbs_assign(server_key, ct_left, ct_right, |x| {
-            (((x % large_mod / modulus) % modulus_msg) > (x % modulus_msg)) as u64
-        })?;
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::Greater(modulus, modulus_msg),
+            |x| (((x % large_mod / modulus) % modulus_msg) > (x % modulus_msg)) as u64,
+        )?;
 
         ct_left.degree.0 = 1;
         Ok(())
@@ -76,9 +80,13 @@ impl ShortintEngine {
         let modulus = (ct_right.degree.0 + 1) as u64;
         let modulus_msg = ct_left.message_modulus.0 as u64;
         let large_mod = modulus * modulus_msg;
-        self.unchecked_functional_bivariate_pbs_assign(server_key, ct_left, ct_right, |x| {
-            (((x % large_mod / modulus) % modulus_msg) >= (x % modulus_msg)) as u64
-        })?;
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::GreaterOrEqual(modulus, modulus_msg),
+            |x| (((x % large_mod / modulus) % modulus_msg) >= (x % modulus_msg)) as u64,
+        )?;
 
         ct_left.degree.0 = 1;
         Ok(())
@@ -129,9 +137,13 @@ impl ShortintEngine {
         let modulus = (ct_right.degree.0 + 1) as u64;
         let modulus_msg = ct_left.message_modulus.0 as u64;
         let large_mod = modulus * modulus_msg;
-        self.unchecked_functional_bivariate_pbs_assign(server_key, ct_left, ct_right, |x| {
-            (((x % large_mod / modulus) % modulus_msg) < (x % modulus_msg)) as u64
-        })?;
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::Less(modulus, modulus_msg),
+            |x| (((x % large_mod / modulus) % modulus_msg) < (x % modulus_msg)) as u64,
+        )?;
 
         ct_left.degree.0 = 1;
         Ok(())
@@ -182,9 +194,13 @@ impl ShortintEngine {
         let modulus = (ct_right.degree.0 + 1) as u64;
         let modulus_msg = ct_left.message_modulus.0 as u64;
         let large_mod = modulus * modulus_msg;
-        self.unchecked_functional_bivariate_pbs_assign(server_key, ct_left, ct_right, |x| {
-            (((x % large_mod / modulus) % modulus_msg) <= (x % modulus_msg)) as u64
-        })?;
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::LessOrEqual(modulus, modulus_msg),
+            |x| (((x % large_mod / modulus) % modulus_msg) <= (x % modulus_msg)) as u64,
+        )?;
 
         ct_left.degree.0 = 1;
         Ok(())
@@ -235,9 +251,13 @@ impl ShortintEngine {
         let modulus = (ct_right.degree.0 + 1) as u64;
         let modulus_msg = ct_left.message_modulus.0 as u64;
         let large_mod = modulus * modulus_msg;
-        self.unchecked_functional_bivariate_pbs_assign(server_key, ct_left, ct_right, |x| {
-            ((((x % large_mod) / modulus) % modulus_msg) == (x % modulus_msg)) as u64
-        })?;
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::Equal(modulus, modulus_msg),
+            |x| ((((x % large_mod) / modulus) % modulus_msg) == (x % modulus_msg)) as u64,
+        )?;
         ct_left.degree.0 = 1;
         Ok(())
     }
@@ -1,16 +1,27 @@
-use crate::ciphertext::Degree;
-use crate::engine::{EngineResult, ShortintEngine};
+use crate::ciphertext::{Degree, NoiseLevel};
+use crate::engine::{EngineResult, LutId, ShortintEngine};
+use crate::server_key::DivisionByZeroPolicy;
 use crate::{Ciphertext, ServerKey};
+use std::cmp::{max, min};
 
-// Specific division function returning 0 in case of a division by 0
-pub(crate) fn division(x: u64, modulus: u64) -> u64 {
+// Specific division function returning `on_zero` in case of a division by 0
+pub(crate) fn division(x: u64, modulus: u64, on_zero: u64) -> u64 {
     if x % modulus == 0 {
-        0
+        on_zero
     } else {
         (x / modulus) / (x % modulus)
     }
 }
 
+// Specific remainder function returning `on_zero` in case of a division by 0
+pub(crate) fn remainder(x: u64, modulus: u64, on_zero: u64) -> u64 {
+    if x % modulus == 0 {
+        on_zero
+    } else {
+        (x / modulus) % (x % modulus)
+    }
+}
+
 impl ShortintEngine {
     pub(crate) fn unchecked_div(
         &mut self,
@@ -30,11 +41,18 @@ impl ShortintEngine {
         ct_right: &Ciphertext,
     ) -> EngineResult<()> {
         let modulus = (ct_right.degree.0 + 1) as u64;
+        let message_modulus = ct_left.message_modulus.0 as u64;
 
         //In this case the degree of the result is equal to the degree of ct_left
-        self.unchecked_functional_bivariate_pbs_assign(server_key, ct_left, ct_right, |x| {
-            division(x, modulus)
-        })?;
+        let degree = ct_left.degree;
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::Div(modulus, message_modulus, 0),
+            |x| division(x, modulus, 0),
+        )?;
+        ct_left.degree = degree;
         Ok(())
     }
 
@@ -54,11 +72,158 @@ impl ShortintEngine {
         server_key: &ServerKey,
         ct_left: &mut Ciphertext,
         ct_right: &mut Ciphertext,
+    ) -> EngineResult<()> {
+        self.smart_div_operands_assign(server_key, ct_left, ct_right)?;
+        self.unchecked_div_assign(server_key, ct_left, ct_right)?;
+        Ok(())
+    }
+
+    pub(crate) fn unchecked_rem(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct_left.clone();
+        self.unchecked_rem_assign(server_key, &mut result, ct_right)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_rem_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> EngineResult<()> {
+        let modulus = (ct_right.degree.0 + 1) as u64;
+        let message_modulus = ct_left.message_modulus.0 as u64;
+
+        // The remainder is smaller than both the dividend and the divisor
+        let degree = Degree(min(ct_left.degree.0, ct_right.degree.0.saturating_sub(1)));
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::Rem(modulus, message_modulus, 0),
+            |x| remainder(x, modulus, 0),
+        )?;
+        ct_left.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_rem(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct_left.clone();
+        self.smart_rem_assign(server_key, &mut result, ct_right)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_rem_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+    ) -> EngineResult<()> {
+        self.smart_div_operands_assign(server_key, ct_left, ct_right)?;
+        self.unchecked_rem_assign(server_key, ct_left, ct_right)?;
+        Ok(())
+    }
+
+    /// Computes the quotient and the remainder of the division, and a flag set when the divisor
+    /// is zero if the policy asks for it.
+    ///
+    /// The messages are packed once, and the bootstraps of all the results share the same
+    /// keyswitch.
+    pub(crate) fn unchecked_div_rem_with_policy(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+        policy: DivisionByZeroPolicy,
+    ) -> EngineResult<(Ciphertext, Ciphertext, Option<Ciphertext>)> {
+        let modulus = (ct_right.degree.0 + 1) as u64;
+        let message_modulus = ct_left.message_modulus.0 as u64;
+        // the largest message is the one of the dividend, which may be a block of a CRT
+        // decomposition
+        let on_zero = policy.value_on_zero(ct_left.message_modulus);
+
+        let quotient_degree = Degree(max(ct_left.degree.0, on_zero as usize));
+        let remainder_degree = Degree(max(
+            min(ct_left.degree.0, ct_right.degree.0.saturating_sub(1)),
+            on_zero as usize,
+        ));
+
+        // Message 1 is shifted to the carry bits and message 2 is placed in the message bits
+        let mut packed = self.unchecked_scalar_mul(ct_left, modulus as u8)?;
+        self.unchecked_add_assign(&mut packed, ct_right)?;
+
+        let div = |x: u64| division(x, modulus, on_zero);
+        let rem = |x: u64| remainder(x, modulus, on_zero);
+        let is_zero = |x: u64| (x % modulus == 0) as u64;
+
+        let mut luts: Vec<(LutId, &dyn Fn(u64) -> u64)> = vec![
+            (LutId::Div(modulus, message_modulus, on_zero), &div),
+            (LutId::Rem(modulus, message_modulus, on_zero), &rem),
+        ];
+        if policy == DivisionByZeroPolicy::ErrorFlag {
+            luts.push((LutId::DivisorIsZero(modulus), &is_zero));
+        }
+
+        let mut results = self
+            .cached_keyswitch_programmable_bootstrap_multi(server_key, &packed, &luts)?
+            .into_iter();
+
+        let mut quotient = results.next().unwrap();
+        quotient.degree = quotient_degree;
+
+        let mut remainder = results.next().unwrap();
+        remainder.degree = remainder_degree;
+
+        let flag = results.next().map(|mut flag| {
+            flag.degree = Degree(1);
+            flag
+        });
+
+        Ok((quotient, remainder, flag))
+    }
+
+    pub(crate) fn smart_div_rem_with_policy(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+        policy: DivisionByZeroPolicy,
+    ) -> EngineResult<(Ciphertext, Ciphertext, Option<Ciphertext>)> {
+        self.smart_div_operands_assign(server_key, ct_left, ct_right)?;
+        self.unchecked_div_rem_with_policy(server_key, ct_left, ct_right, policy)
+    }
+
+    /// Cleans the carries of the operands until their messages can be packed together.
+    fn smart_div_operands_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
     ) -> EngineResult<()> {
         if !server_key.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            if ct_left.message_modulus.0 + ct_right.degree.0 <= server_key.max_degree.0 {
+            // The message extraction resets the noise level of the extracted ciphertext
+            let max_noise_level = server_key.max_noise_level;
+            if ct_left.message_modulus.0 + ct_right.degree.0 <= server_key.max_degree.0
+                && max_noise_level
+                    .validate(NoiseLevel::NOMINAL * (ct_right.degree.0 + 1) + ct_right.noise_level)
+                    .is_ok()
+            {
                 self.message_extract_assign(server_key, ct_left)?;
             } else if ct_right.message_modulus.0 + (ct_left.degree.0 + 1) <= server_key.max_degree.0
+                && max_noise_level
+                    .validate(
+                        ct_left.noise_level * ct_right.message_modulus.0 + NoiseLevel::NOMINAL,
+                    )
+                    .is_ok()
             {
                 self.message_extract_assign(server_key, ct_right)?;
             } else {
@@ -66,7 +231,6 @@ impl ShortintEngine {
                 self.message_extract_assign(server_key, ct_right)?;
             }
         }
-        self.unchecked_div_assign(server_key, ct_left, ct_right)?;
         Ok(())
     }
 
@@ -94,9 +258,12 @@ impl ShortintEngine {
         scalar: u8,
     ) -> EngineResult<()> {
         assert_ne!(scalar, 0);
-        //generate the accumulator for the multiplication
-        let acc = self.generate_accumulator(server_key, |x| x / (scalar as u64))?;
-        self.programmable_bootstrap_keyswitch_assign(server_key, ct, &acc)?;
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::ScalarDiv(scalar),
+            |x| x / (scalar as u64),
+        )?;
         ct.degree = Degree(ct.degree.0 / scalar as usize);
         Ok(())
     }
@@ -122,8 +289,12 @@ impl ShortintEngine {
         modulus: u8,
     ) -> EngineResult<()> {
         assert_ne!(modulus, 0);
-        let acc = self.generate_accumulator(server_key, |x| x % modulus as u64)?;
-        self.programmable_bootstrap_keyswitch_assign(server_key, ct, &acc)?;
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::ScalarMod(modulus),
+            |x| x % modulus as u64,
+        )?;
         ct.degree = Degree(modulus as usize - 1);
         Ok(())
     }
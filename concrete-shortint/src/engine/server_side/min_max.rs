@@ -0,0 +1,221 @@
+use crate::ciphertext::Degree;
+use crate::engine::{EngineResult, LutId, ShortintEngine};
+use crate::{Ciphertext, ServerKey};
+
+impl ShortintEngine {
+    pub(crate) fn unchecked_min(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct_left.clone();
+        self.unchecked_min_assign(server_key, &mut result, ct_right)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_min_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> EngineResult<()> {
+        let modulus = (ct_right.degree.0 + 1) as u64;
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::Min(modulus),
+            |x| (x / modulus).min(x % modulus),
+        )?;
+        ct_left.degree = Degree(ct_left.degree.0.min(ct_right.degree.0));
+        Ok(())
+    }
+
+    pub(crate) fn smart_min(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct_left.clone();
+        self.smart_min_assign(server_key, &mut result, ct_right)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_min_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+    ) -> EngineResult<()> {
+        if !server_key.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
+            self.message_extract_assign(server_key, ct_left)?;
+            self.message_extract_assign(server_key, ct_right)?;
+        }
+        self.unchecked_min_assign(server_key, ct_left, ct_right)?;
+        Ok(())
+    }
+
+    pub(crate) fn unchecked_scalar_min(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_min_assign(server_key, &mut result, scalar)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_scalar_min_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        let degree = ct.degree.after_pbs(|x| x.min(scalar as usize));
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::ScalarMin(scalar),
+            |x| x.min(scalar as u64),
+        )?;
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_scalar_min(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.smart_scalar_min_assign(server_key, &mut result, scalar)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_scalar_min_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        if !server_key.is_scalar_min_possible(ct, scalar) {
+            self.message_extract_assign(server_key, ct)?;
+        }
+        self.unchecked_scalar_min_assign(server_key, ct, scalar)?;
+        Ok(())
+    }
+
+    pub(crate) fn unchecked_max(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct_left.clone();
+        self.unchecked_max_assign(server_key, &mut result, ct_right)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_max_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> EngineResult<()> {
+        let modulus = (ct_right.degree.0 + 1) as u64;
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct_left,
+            ct_right,
+            LutId::Max(modulus),
+            |x| (x / modulus).max(x % modulus),
+        )?;
+        ct_left.degree = Degree(ct_left.degree.0.max(ct_right.degree.0));
+        Ok(())
+    }
+
+    pub(crate) fn smart_max(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct_left.clone();
+        self.smart_max_assign(server_key, &mut result, ct_right)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_max_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+    ) -> EngineResult<()> {
+        if !server_key.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
+            self.message_extract_assign(server_key, ct_left)?;
+            self.message_extract_assign(server_key, ct_right)?;
+        }
+        self.unchecked_max_assign(server_key, ct_left, ct_right)?;
+        Ok(())
+    }
+
+    pub(crate) fn unchecked_scalar_max(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_max_assign(server_key, &mut result, scalar)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_scalar_max_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        let degree = ct.degree.after_pbs(|x| x.max(scalar as usize));
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::ScalarMax(scalar),
+            |x| x.max(scalar as u64),
+        )?;
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_scalar_max(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.smart_scalar_max_assign(server_key, &mut result, scalar)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_scalar_max_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        if scalar as usize >= ct.message_modulus.0 {
+            // The result is reduced modulo the message modulus, as in smart_scalar_mul
+            return self.reduced_message_pbs_assign(server_key, ct, |x| x.max(scalar as u64));
+        }
+        if !server_key.is_scalar_max_possible(ct, scalar) {
+            self.message_extract_assign(server_key, ct)?;
+        }
+        self.unchecked_scalar_max_assign(server_key, ct, scalar)?;
+        Ok(())
+    }
+}
@@ -1,23 +1,28 @@
 use super::ShortintEngine;
-use crate::ciphertext::Degree;
-use crate::engine::EngineResult;
-use crate::server_key::MaxDegree;
-use crate::{Ciphertext, ClientKey, ServerKey};
+use crate::ciphertext::{Degree, NoiseLevel};
+use crate::engine::{EngineResult, LutId};
+use crate::server_key::{LookupTable, MaxDegree, MaxNoiseLevel};
+use crate::{Ciphertext, ClientKey, CompressedCiphertext, CompressedServerKey, ServerKey};
 use concrete_core::prelude::*;
 use std::cmp::min;
+use std::collections::btree_map::Entry;
 
 mod add;
 mod bitwise_op;
 mod comp_op;
 mod div_mod;
+mod min_max;
 mod mul;
 mod neg;
+mod rotate;
 mod scalar_add;
 mod scalar_mul;
 mod scalar_sub;
 mod shift;
 mod sub;
 
+pub(crate) use rotate::{rotate_left, rotate_right};
+
 impl ShortintEngine {
     pub(crate) fn new_server_key(&mut self, cks: &ClientKey) -> EngineResult<ServerKey> {
         // Plaintext Max Value
@@ -68,6 +73,113 @@ impl ShortintEngine {
             message_modulus: cks.parameters.message_modulus,
             carry_modulus: cks.parameters.carry_modulus,
             max_degree,
+            max_noise_level: MaxNoiseLevel::from_msg_carry_modulus(
+                cks.parameters.message_modulus,
+                cks.parameters.carry_modulus,
+            ),
+        })
+    }
+
+    pub(crate) fn new_compressed_server_key(
+        &mut self,
+        cks: &ClientKey,
+    ) -> EngineResult<CompressedServerKey> {
+        // Plaintext Max Value
+        let max_value = cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0 - 1;
+
+        // The maximum number of operations before we need to clean the carry buffer
+        let max = MaxDegree(max_value);
+        self.new_compressed_server_key_with_max_degree(cks, max)
+    }
+
+    pub(crate) fn new_compressed_server_key_with_max_degree(
+        &mut self,
+        cks: &ClientKey,
+        max_degree: MaxDegree,
+    ) -> EngineResult<CompressedServerKey> {
+        // Convert into a variance for rlwe context
+        let var_rlwe = Variance(cks.parameters.glwe_modular_std_dev.get_variance());
+
+        // Creation of the seeded bootstrapping key, the masks are replaced by a seed
+        let bootstrap_key: LweSeededBootstrapKey64 =
+            self.par_engine.generate_new_lwe_seeded_bootstrap_key(
+                &cks.lwe_secret_key_after_ks,
+                &cks.glwe_secret_key,
+                cks.parameters.pbs_base_log,
+                cks.parameters.pbs_level,
+                var_rlwe,
+            )?;
+
+        // Convert into a variance for lwe context
+        let var_lwe = Variance(cks.parameters.lwe_modular_std_dev.get_variance());
+
+        // Creation of the seeded key switching key
+        let ksk = self.engine.generate_new_lwe_seeded_keyswitch_key(
+            &cks.lwe_secret_key,
+            &cks.lwe_secret_key_after_ks,
+            cks.parameters.ks_level,
+            cks.parameters.ks_base_log,
+            var_lwe,
+        )?;
+
+        // Pack the keys in the compressed server key set:
+        Ok(CompressedServerKey {
+            key_switching_key: ksk,
+            bootstrapping_key: bootstrap_key,
+            message_modulus: cks.parameters.message_modulus,
+            carry_modulus: cks.parameters.carry_modulus,
+            max_degree,
+        })
+    }
+
+    pub(crate) fn decompress_server_key(
+        &mut self,
+        compressed_server_key: &CompressedServerKey,
+    ) -> EngineResult<ServerKey> {
+        // Regenerate the masks from the seeds
+        let bootstrap_key: LweBootstrapKey64 = self
+            .engine
+            .transform_lwe_seeded_bootstrap_key_to_lwe_bootstrap_key(
+                compressed_server_key.bootstrapping_key.clone(),
+            )?;
+        let ksk = self
+            .engine
+            .transform_lwe_seeded_keyswitch_key_to_lwe_keyswitch_key(
+                compressed_server_key.key_switching_key.clone(),
+            )?;
+
+        // Creation of the bootstrapping key in the Fourier domain
+        let fourier_bsk: FftFourierLweBootstrapKey64 =
+            self.fft_engine.convert_lwe_bootstrap_key(&bootstrap_key)?;
+
+        Ok(ServerKey {
+            key_switching_key: ksk,
+            bootstrapping_key: fourier_bsk,
+            message_modulus: compressed_server_key.message_modulus,
+            carry_modulus: compressed_server_key.carry_modulus,
+            max_degree: compressed_server_key.max_degree,
+            max_noise_level: MaxNoiseLevel::from_msg_carry_modulus(
+                compressed_server_key.message_modulus,
+                compressed_server_key.carry_modulus,
+            ),
+        })
+    }
+
+    pub(crate) fn decompress_ciphertext(
+        &mut self,
+        compressed_ct: &CompressedCiphertext,
+    ) -> EngineResult<Ciphertext> {
+        // Regenerate the mask from the seed
+        let ct = self
+            .engine
+            .transform_lwe_seeded_ciphertext_to_lwe_ciphertext(compressed_ct.ct.clone())?;
+
+        Ok(Ciphertext {
+            ct,
+            degree: compressed_ct.degree,
+            noise_level: NoiseLevel::NOMINAL,
+            message_modulus: compressed_ct.message_modulus,
+            carry_modulus: compressed_ct.carry_modulus,
         })
     }
 
@@ -82,6 +194,45 @@ impl ShortintEngine {
         Self::generate_accumulator_with_engine(&mut self.engine, server_key, f)
     }
 
+    pub(crate) fn generate_lookup_table<F>(
+        &mut self,
+        server_key: &ServerKey,
+        f: F,
+    ) -> EngineResult<LookupTable>
+    where
+        F: Fn(u64) -> u64,
+    {
+        let acc = self.generate_accumulator(server_key, &f)?;
+
+        // The degree of the output is the worst case over the whole plaintext space
+        let max_value = server_key.message_modulus.0 * server_key.carry_modulus.0 - 1;
+        let degree = Degree(max_value).after_pbs(|x| f(x as u64) as usize);
+
+        Ok(LookupTable { acc, degree })
+    }
+
+    pub(crate) fn apply_lookup_table(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        lut: &LookupTable,
+    ) -> EngineResult<Ciphertext> {
+        let mut ct_res = ct.clone();
+        self.apply_lookup_table_assign(server_key, &mut ct_res, lut)?;
+        Ok(ct_res)
+    }
+
+    pub(crate) fn apply_lookup_table_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        lut: &LookupTable,
+    ) -> EngineResult<()> {
+        self.programmable_bootstrap_keyswitch_assign(server_key, ct, &lut.acc)?;
+        ct.degree = lut.degree;
+        Ok(())
+    }
+
     pub(crate) fn keyswitch_bootstrap(
         &mut self,
         server_key: &ServerKey,
@@ -114,6 +265,9 @@ impl ShortintEngine {
             &buffers.accumulator,
             &server_key.bootstrapping_key,
         )?;
+
+        // The bootstrap resets the noise
+        ct.noise_level = NoiseLevel::NOMINAL;
         Ok(())
     }
 
@@ -151,9 +305,134 @@ impl ShortintEngine {
             acc,
             &server_key.bootstrapping_key,
         )?;
+
+        // The bootstrap resets the noise
+        ct.noise_level = NoiseLevel::NOMINAL;
         Ok(())
     }
 
+    /// Computes a keyswitch and a programmable bootstrap with the accumulator of a built-in
+    /// function.
+    ///
+    /// The accumulator is only generated from `f` the first time `lut_id` is used with this key,
+    /// it is then taken from the `Buffers` of the key.
+    pub(crate) fn cached_programmable_bootstrap_keyswitch_assign<F>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        lut_id: LutId,
+        f: F,
+    ) -> EngineResult<()>
+    where
+        F: Fn(u64) -> u64,
+    {
+        let (buffers, engine, fftw_engine) = self.buffers_for_key(server_key);
+
+        let acc = match buffers.luts.entry(lut_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Self::generate_accumulator_with_engine(
+                engine, server_key, f,
+            )?),
+        };
+
+        // Compute a key switch
+        engine.discard_keyswitch_lwe_ciphertext(
+            &mut buffers.buffer_lwe_after_ks,
+            &ct.ct,
+            &server_key.key_switching_key,
+        )?;
+
+        // Compute a bootstrap
+        fftw_engine.discard_bootstrap_lwe_ciphertext(
+            &mut ct.ct,
+            &buffers.buffer_lwe_after_ks,
+            acc,
+            &server_key.bootstrapping_key,
+        )?;
+
+        // The bootstrap resets the noise
+        ct.noise_level = NoiseLevel::NOMINAL;
+        Ok(())
+    }
+
+    /// Computes a single keyswitch of `ct`, followed by a bootstrap with the accumulator of each
+    /// of the given built-in functions, returning one ciphertext per function.
+    pub(crate) fn cached_keyswitch_programmable_bootstrap_multi(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        luts: &[(LutId, &dyn Fn(u64) -> u64)],
+    ) -> EngineResult<Vec<Ciphertext>> {
+        let (buffers, engine, fftw_engine) = self.buffers_for_key(server_key);
+
+        // Compute a key switch, shared by all the bootstraps
+        engine.discard_keyswitch_lwe_ciphertext(
+            &mut buffers.buffer_lwe_after_ks,
+            &ct.ct,
+            &server_key.key_switching_key,
+        )?;
+
+        let mut results = Vec::with_capacity(luts.len());
+        for (lut_id, f) in luts {
+            let acc = match buffers.luts.entry(*lut_id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(Self::generate_accumulator_with_engine(
+                    engine, server_key, f,
+                )?),
+            };
+
+            // Compute a bootstrap
+            let mut ct_res = ct.clone();
+            fftw_engine.discard_bootstrap_lwe_ciphertext(
+                &mut ct_res.ct,
+                &buffers.buffer_lwe_after_ks,
+                acc,
+                &server_key.bootstrapping_key,
+            )?;
+
+            // The bootstrap resets the noise
+            ct_res.noise_level = NoiseLevel::NOMINAL;
+            results.push(ct_res);
+        }
+        Ok(results)
+    }
+
+    /// Computes a single keyswitch of `ct`, followed by a bootstrap with the accumulator of each
+    /// lookup table, returning one ciphertext per table.
+    pub(crate) fn apply_lookup_table_multi(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        luts: &[LookupTable],
+    ) -> EngineResult<Vec<Ciphertext>> {
+        let (buffers, engine, fftw_engine) = self.buffers_for_key(server_key);
+
+        // Compute a key switch, shared by all the bootstraps
+        engine.discard_keyswitch_lwe_ciphertext(
+            &mut buffers.buffer_lwe_after_ks,
+            &ct.ct,
+            &server_key.key_switching_key,
+        )?;
+
+        let mut results = Vec::with_capacity(luts.len());
+        for lut in luts {
+            // Compute a bootstrap
+            let mut ct_res = ct.clone();
+            fftw_engine.discard_bootstrap_lwe_ciphertext(
+                &mut ct_res.ct,
+                &buffers.buffer_lwe_after_ks,
+                &lut.acc,
+                &server_key.bootstrapping_key,
+            )?;
+
+            // The bootstrap resets the noise
+            ct_res.noise_level = NoiseLevel::NOMINAL;
+            ct_res.degree = lut.degree;
+            results.push(ct_res);
+        }
+        Ok(results)
+    }
+
     pub(crate) fn unchecked_functional_bivariate_pbs<F>(
         &mut self,
         server_key: &ServerKey,
@@ -195,6 +474,32 @@ impl ShortintEngine {
         Ok(())
     }
 
+    /// Same as `unchecked_functional_bivariate_pbs_assign` for built-in functions, whose
+    /// accumulators are cached.
+    pub(crate) fn unchecked_cached_bivariate_pbs_assign<F>(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &mut Ciphertext,
+        ct_right: &Ciphertext,
+        lut_id: LutId,
+        f: F,
+    ) -> EngineResult<()>
+    where
+        F: Fn(u64) -> u64,
+    {
+        let modulus = (ct_right.degree.0 + 1) as u64;
+
+        // Message 1 is shifted to the carry bits
+        self.unchecked_scalar_mul_assign(ct_left, modulus as u8)?;
+
+        // Message 2 is placed in the message bits
+        self.unchecked_add_assign(ct_left, ct_right)?;
+
+        // Compute the PBS
+        self.cached_programmable_bootstrap_keyswitch_assign(server_key, ct_left, lut_id, f)?;
+        Ok(())
+    }
+
     pub(crate) fn carry_extract_assign(
         &mut self,
         server_key: &ServerKey,
@@ -202,9 +507,12 @@ impl ShortintEngine {
     ) -> EngineResult<()> {
         let modulus = ct.message_modulus.0 as u64;
 
-        let accumulator = self.generate_accumulator(server_key, |x| x / modulus)?;
-
-        self.programmable_bootstrap_keyswitch_assign(server_key, ct, &accumulator)?;
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::CarryExtract(modulus),
+            |x| x / modulus,
+        )?;
 
         // The degree of the carry
         ct.degree = Degree(min(modulus - 1, ct.degree.0 as u64 / modulus) as usize);
@@ -228,11 +536,38 @@ impl ShortintEngine {
     ) -> EngineResult<()> {
         let modulus = ct.message_modulus.0 as u64;
 
-        let acc = self.generate_accumulator(server_key, |x| x % modulus)?;
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::MessageExtract(modulus),
+            |x| x % modulus,
+        )?;
+
+        ct.degree = Degree(ct.message_modulus.0 - 1);
+        Ok(())
+    }
+
+    /// Replaces the message of `ct` by `f(message)` reduced modulo the message modulus, with a
+    /// single bootstrap.
+    ///
+    /// This is the fallback of the `smart_scalar_*` operations whose scalar does not fit in the
+    /// message, for which the result of `f` could overflow the plaintext space.
+    pub(crate) fn reduced_message_pbs_assign<F>(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        f: F,
+    ) -> EngineResult<()>
+    where
+        F: Fn(u64) -> u64,
+    {
+        let modulus = ct.message_modulus.0 as u64;
+        let reduced_f = |x: u64| f(x % modulus) % modulus;
 
+        let acc = self.generate_accumulator(server_key, reduced_f)?;
         self.programmable_bootstrap_keyswitch_assign(server_key, ct, &acc)?;
 
-        ct.degree = Degree(ct.message_modulus.0 - 1);
+        ct.degree = Degree(ct.message_modulus.0 - 1).after_pbs(|x| reduced_f(x as u64) as usize);
         Ok(())
     }
 
@@ -246,6 +581,34 @@ impl ShortintEngine {
         Ok(result)
     }
 
+    pub(crate) fn message_and_carry_extract(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+    ) -> EngineResult<(Ciphertext, Ciphertext)> {
+        let modulus = ct.message_modulus.0 as u64;
+
+        let message = |x: u64| x % modulus;
+        let carry = |x: u64| x / modulus;
+        let luts: [(LutId, &dyn Fn(u64) -> u64); 2] = [
+            (LutId::MessageExtract(modulus), &message),
+            (LutId::CarryExtract(modulus), &carry),
+        ];
+
+        // Both extractions share the key switch of the input
+        let mut results = self
+            .cached_keyswitch_programmable_bootstrap_multi(server_key, ct, &luts)?
+            .into_iter();
+
+        let mut ct_message = results.next().unwrap();
+        ct_message.degree = Degree(ct.message_modulus.0 - 1);
+
+        let mut ct_carry = results.next().unwrap();
+        ct_carry.degree = Degree(min(modulus - 1, ct.degree.0 as u64 / modulus) as usize);
+
+        Ok((ct_message, ct_carry))
+    }
+
     // Impossible to call the assign function in this case
     pub(crate) fn create_trivial(
         &mut self,
@@ -276,6 +639,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct,
             degree,
+            noise_level: NoiseLevel::ZERO,
             message_modulus: server_key.message_modulus,
             carry_modulus: server_key.carry_modulus,
         })
@@ -306,6 +670,7 @@ impl ShortintEngine {
             .trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)
             .unwrap();
         ct.degree = Degree(modular_value);
+        ct.noise_level = NoiseLevel::ZERO;
         Ok(())
     }
 }
@@ -1,5 +1,5 @@
-use crate::ciphertext::Degree;
-use crate::engine::{EngineResult, ShortintEngine};
+use crate::ciphertext::{Degree, NoiseLevel};
+use crate::engine::{EngineResult, LutId, ShortintEngine};
 use crate::{Ciphertext, ServerKey};
 
 impl ShortintEngine {
@@ -31,12 +31,13 @@ impl ShortintEngine {
         //Modulus of the msg in the msg bits
         let res_modulus = ct_left.message_modulus.0 as u64;
 
-        //generate the accumulator for the multiplication
-        let acc = self.generate_accumulator(server_key, |x| {
-            ((x / modulus) * (x % modulus)) % res_modulus
-        })?;
-
-        self.programmable_bootstrap_keyswitch_assign(server_key, ct_left, &acc)?;
+        //compute the PBS with the accumulator for the multiplication
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct_left,
+            LutId::MulLsb(modulus, res_modulus),
+            |x| ((x / modulus) * (x % modulus)) % res_modulus,
+        )?;
         ct_left.degree = Degree(ct_left.message_modulus.0 - 1);
         Ok(())
     }
@@ -71,12 +72,13 @@ impl ShortintEngine {
         // Modulus of the msg in the msg bits
         let res_modulus = server_key.message_modulus.0 as u64;
 
-        // Generate the accumulator for the multiplication
-        let acc = self.generate_accumulator(server_key, |x| {
-            ((x / modulus) * (x % modulus)) / res_modulus
-        })?;
-
-        self.programmable_bootstrap_keyswitch_assign(server_key, ct_left, &acc)?;
+        // Compute the PBS with the accumulator for the multiplication
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct_left,
+            LutId::MulMsb(modulus),
+            |x| ((x / modulus) * (x % modulus)) / res_modulus,
+        )?;
 
         ct_left.degree = Degree(deg);
         Ok(())
@@ -137,12 +139,25 @@ impl ShortintEngine {
             //If the ciphertext cannot be added together without exceeding the capacity of a
             // ciphertext
             if !server_key.is_mul_possible(ct_left, ct_right) {
+                // The message extraction resets the noise level of the extracted ciphertext
+                let max_noise_level = server_key.max_noise_level;
                 if server_key.message_modulus.0 * (ct_right.degree.0 + 1)
                     < (ct_right.carry_modulus.0 * ct_right.message_modulus.0 - 1)
+                    && max_noise_level
+                        .validate(
+                            NoiseLevel::NOMINAL * (ct_right.degree.0 + 1) + ct_right.noise_level,
+                        )
+                        .is_ok()
                 {
                     self.message_extract_assign(server_key, ct_left)?;
                 } else if (server_key.message_modulus.0 + 1) + (ct_left.degree.0 + 1)
                     < (ct_right.carry_modulus.0 * ct_right.message_modulus.0 - 1)
+                    && max_noise_level
+                        .validate(
+                            ct_left.noise_level * server_key.message_modulus.0
+                                + NoiseLevel::NOMINAL,
+                        )
+                        .is_ok()
                 {
                     self.message_extract_assign(server_key, ct_right)?;
                 } else {
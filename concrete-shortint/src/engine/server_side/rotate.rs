@@ -0,0 +1,262 @@
+use crate::engine::{EngineResult, LutId, ShortintEngine};
+use crate::{Ciphertext, ServerKey};
+
+// Left rotation of the bits of the message
+//
+// The message modulus has to be a power of two.
+pub(crate) fn rotate_left(value: u64, amount: u64, message_modulus: u64) -> u64 {
+    let nb_bits = message_modulus.trailing_zeros() as u64;
+    let amount = amount % nb_bits;
+    let value = value % message_modulus;
+    ((value << amount) | (value >> (nb_bits - amount))) % message_modulus
+}
+
+// Right rotation of the bits of the message
+//
+// The message modulus has to be a power of two.
+pub(crate) fn rotate_right(value: u64, amount: u64, message_modulus: u64) -> u64 {
+    let nb_bits = message_modulus.trailing_zeros() as u64;
+    let amount = amount % nb_bits;
+    let value = value % message_modulus;
+    ((value >> amount) | (value << (nb_bits - amount))) % message_modulus
+}
+
+impl ShortintEngine {
+    pub(crate) fn unchecked_scalar_rotate_left(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        amount: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_rotate_left_assign(server_key, &mut result, amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_scalar_rotate_left_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        amount: u8,
+    ) -> EngineResult<()> {
+        let message_modulus = server_key.message_modulus.0 as u64;
+        assert!(message_modulus.is_power_of_two());
+
+        let degree = ct
+            .degree
+            .after_pbs(|value| rotate_left(value as u64, amount as u64, message_modulus) as usize);
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::ScalarRotateLeft(amount),
+            |x| rotate_left(x, amount as u64, message_modulus),
+        )?;
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_scalar_rotate_left(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        amount: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.smart_scalar_rotate_left_assign(server_key, &mut result, amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_scalar_rotate_left_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        amount: u8,
+    ) -> EngineResult<()> {
+        if !server_key.is_scalar_rotate_left_possible(ct, amount) {
+            self.message_extract_assign(server_key, ct)?;
+        }
+        self.unchecked_scalar_rotate_left_assign(server_key, ct, amount)?;
+        Ok(())
+    }
+
+    pub(crate) fn unchecked_scalar_rotate_right(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        amount: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.unchecked_scalar_rotate_right_assign(server_key, &mut result, amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_scalar_rotate_right_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        amount: u8,
+    ) -> EngineResult<()> {
+        let message_modulus = server_key.message_modulus.0 as u64;
+        assert!(message_modulus.is_power_of_two());
+
+        let degree = ct
+            .degree
+            .after_pbs(|value| rotate_right(value as u64, amount as u64, message_modulus) as usize);
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::ScalarRotateRight(amount),
+            |x| rotate_right(x, amount as u64, message_modulus),
+        )?;
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_scalar_rotate_right(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        amount: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.smart_scalar_rotate_right_assign(server_key, &mut result, amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_scalar_rotate_right_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        amount: u8,
+    ) -> EngineResult<()> {
+        if !server_key.is_scalar_rotate_right_possible(ct, amount) {
+            self.message_extract_assign(server_key, ct)?;
+        }
+        self.unchecked_scalar_rotate_right_assign(server_key, ct, amount)?;
+        Ok(())
+    }
+
+    pub(crate) fn unchecked_rotate_left(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.unchecked_rotate_left_assign(server_key, &mut result, ct_amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_rotate_left_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> EngineResult<()> {
+        let modulus = (ct_amount.degree.0 + 1) as u64;
+        let message_modulus = server_key.message_modulus.0 as u64;
+        assert!(message_modulus.is_power_of_two());
+
+        let degree = ct
+            .degree
+            .after_bivariate_pbs(ct_amount.degree, |value, amount| {
+                rotate_left(value as u64, amount as u64, message_modulus) as usize
+            });
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct,
+            ct_amount,
+            LutId::RotateLeft(modulus),
+            |x| rotate_left(x / modulus, x % modulus, message_modulus),
+        )?;
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_rotate_left(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &mut Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.smart_rotate_left_assign(server_key, &mut result, ct_amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_rotate_left_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &mut Ciphertext,
+    ) -> EngineResult<()> {
+        if !server_key.is_functional_bivariate_pbs_possible(ct, ct_amount) {
+            self.message_extract_assign(server_key, ct)?;
+            self.message_extract_assign(server_key, ct_amount)?;
+        }
+        self.unchecked_rotate_left_assign(server_key, ct, ct_amount)?;
+        Ok(())
+    }
+
+    pub(crate) fn unchecked_rotate_right(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.unchecked_rotate_right_assign(server_key, &mut result, ct_amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_rotate_right_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> EngineResult<()> {
+        let modulus = (ct_amount.degree.0 + 1) as u64;
+        let message_modulus = server_key.message_modulus.0 as u64;
+        assert!(message_modulus.is_power_of_two());
+
+        let degree = ct
+            .degree
+            .after_bivariate_pbs(ct_amount.degree, |value, amount| {
+                rotate_right(value as u64, amount as u64, message_modulus) as usize
+            });
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct,
+            ct_amount,
+            LutId::RotateRight(modulus),
+            |x| rotate_right(x / modulus, x % modulus, message_modulus),
+        )?;
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_rotate_right(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &mut Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.smart_rotate_right_assign(server_key, &mut result, ct_amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_rotate_right_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &mut Ciphertext,
+    ) -> EngineResult<()> {
+        if !server_key.is_functional_bivariate_pbs_possible(ct, ct_amount) {
+            self.message_extract_assign(server_key, ct)?;
+            self.message_extract_assign(server_key, ct_amount)?;
+        }
+        self.unchecked_rotate_right_assign(server_key, ct, ct_amount)?;
+        Ok(())
+    }
+}
@@ -26,6 +26,7 @@ impl ShortintEngine {
             .fuse_mul_lwe_ciphertext_cleartext(&mut ct.ct, &cleartext_scalar)?;
 
         ct.degree = Degree(ct.degree.0 * scalar as usize);
+        ct.noise_level = ct.noise_level * scalar as usize;
         Ok(())
     }
 
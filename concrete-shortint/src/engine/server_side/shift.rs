@@ -1,7 +1,26 @@
 use crate::ciphertext::Degree;
-use crate::engine::{EngineResult, ShortintEngine};
+use crate::engine::{EngineResult, LutId, ShortintEngine};
 use crate::{Ciphertext, ServerKey};
 
+// Left shift of the message, the bits going out of the message being dropped
+pub(crate) fn shift_left(value: u64, amount: u64, message_modulus: u64) -> u64 {
+    let value = value % message_modulus;
+    u32::try_from(amount)
+        .ok()
+        .and_then(|amount| value.checked_shl(amount))
+        .unwrap_or(0)
+        % message_modulus
+}
+
+// Right shift of the message, the carries being ignored
+pub(crate) fn shift_right(value: u64, amount: u64, message_modulus: u64) -> u64 {
+    let value = value % message_modulus;
+    u32::try_from(amount)
+        .ok()
+        .and_then(|amount| value.checked_shr(amount))
+        .unwrap_or(0)
+}
+
 impl ShortintEngine {
     pub(crate) fn unchecked_scalar_right_shift(
         &mut self,
@@ -20,8 +39,12 @@ impl ShortintEngine {
         ct: &mut Ciphertext,
         shift: u8,
     ) -> EngineResult<()> {
-        let acc = self.generate_accumulator(server_key, |x| x >> shift)?;
-        self.programmable_bootstrap_keyswitch_assign(server_key, ct, &acc)?;
+        self.cached_programmable_bootstrap_keyswitch_assign(
+            server_key,
+            ct,
+            LutId::ScalarRightShift(shift),
+            |x| x >> shift,
+        )?;
 
         ct.degree = Degree(ct.degree.0 >> shift);
         Ok(())
@@ -74,4 +97,126 @@ impl ShortintEngine {
         }
         Ok(())
     }
+
+    pub(crate) fn unchecked_shift_left(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.unchecked_shift_left_assign(server_key, &mut result, ct_amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_shift_left_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> EngineResult<()> {
+        let modulus = (ct_amount.degree.0 + 1) as u64;
+        let message_modulus = server_key.message_modulus.0 as u64;
+
+        let degree = ct
+            .degree
+            .after_bivariate_pbs(ct_amount.degree, |value, amount| {
+                shift_left(value as u64, amount as u64, message_modulus) as usize
+            });
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct,
+            ct_amount,
+            LutId::ShiftLeft(modulus),
+            |x| shift_left(x / modulus, x % modulus, message_modulus),
+        )?;
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_shift_left(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &mut Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.smart_shift_left_assign(server_key, &mut result, ct_amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_shift_left_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &mut Ciphertext,
+    ) -> EngineResult<()> {
+        if !server_key.is_functional_bivariate_pbs_possible(ct, ct_amount) {
+            self.message_extract_assign(server_key, ct)?;
+            self.message_extract_assign(server_key, ct_amount)?;
+        }
+        self.unchecked_shift_left_assign(server_key, ct, ct_amount)?;
+        Ok(())
+    }
+
+    pub(crate) fn unchecked_shift_right(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.unchecked_shift_right_assign(server_key, &mut result, ct_amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn unchecked_shift_right_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> EngineResult<()> {
+        let modulus = (ct_amount.degree.0 + 1) as u64;
+        let message_modulus = server_key.message_modulus.0 as u64;
+
+        let degree = ct
+            .degree
+            .after_bivariate_pbs(ct_amount.degree, |value, amount| {
+                shift_right(value as u64, amount as u64, message_modulus) as usize
+            });
+        self.unchecked_cached_bivariate_pbs_assign(
+            server_key,
+            ct,
+            ct_amount,
+            LutId::ShiftRight(modulus),
+            |x| shift_right(x / modulus, x % modulus, message_modulus),
+        )?;
+        ct.degree = degree;
+        Ok(())
+    }
+
+    pub(crate) fn smart_shift_right(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &mut Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        let mut result = ct.clone();
+        self.smart_shift_right_assign(server_key, &mut result, ct_amount)?;
+        Ok(result)
+    }
+
+    pub(crate) fn smart_shift_right_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        ct_amount: &mut Ciphertext,
+    ) -> EngineResult<()> {
+        if !server_key.is_functional_bivariate_pbs_possible(ct, ct_amount) {
+            self.message_extract_assign(server_key, ct)?;
+            self.message_extract_assign(server_key, ct_amount)?;
+        }
+        self.unchecked_shift_right_assign(server_key, ct, ct_amount)?;
+        Ok(())
+    }
 }
@@ -50,6 +50,7 @@ impl ShortintEngine {
             .fuse_add_lwe_ciphertext(&mut ct_left.ct, &neg_right.ct)?;
 
         ct_left.degree = Degree(ct_left.degree.0 + z as usize);
+        ct_left.noise_level = ct_left.noise_level + neg_right.noise_level;
 
         Ok(z)
     }
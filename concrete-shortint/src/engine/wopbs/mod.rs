@@ -1,10 +1,10 @@
 //! # WARNING: this module is experimental.
-use crate::ciphertext::Degree;
+use crate::ciphertext::{Degree, NoiseLevel};
 use crate::engine::{EngineResult, ShortintEngine};
 use crate::wopbs::WopbsKey;
 use crate::{Ciphertext, ClientKey, Parameters, ServerKey};
 
-use crate::server_key::MaxDegree;
+use crate::server_key::{MaxDegree, MaxNoiseLevel};
 use concrete_core::prelude::*;
 
 impl ShortintEngine {
@@ -119,6 +119,10 @@ impl ShortintEngine {
             message_modulus: parameters.message_modulus,
             carry_modulus: parameters.carry_modulus,
             max_degree: MaxDegree(parameters.message_modulus.0 * parameters.carry_modulus.0 - 1),
+            max_noise_level: MaxNoiseLevel::from_msg_carry_modulus(
+                parameters.message_modulus,
+                parameters.carry_modulus,
+            ),
         };
 
         let pbs_server_key = ServerKey {
@@ -129,6 +133,10 @@ impl ShortintEngine {
             max_degree: MaxDegree(
                 cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0 - 1,
             ),
+            max_noise_level: MaxNoiseLevel::from_msg_carry_modulus(
+                cks.parameters.message_modulus,
+                cks.parameters.carry_modulus,
+            ),
         };
 
         let wopbs_key = WopbsKey {
@@ -242,6 +250,7 @@ impl ShortintEngine {
         let ct_out = Ciphertext {
             ct: ct_out,
             degree: Degree(sks.message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: sks.message_modulus,
             carry_modulus: sks.carry_modulus,
         };
@@ -305,6 +314,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct: buffer_lwe_after_ks,
             degree: ct_clean.degree,
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: ct_clean.message_modulus,
             carry_modulus: ct_clean.carry_modulus,
         })
@@ -346,6 +356,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct: ct_out,
             degree: ct_in.degree,
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: ct_in.message_modulus,
             carry_modulus: ct_in.carry_modulus,
         })
@@ -3,7 +3,7 @@ use crate::parameters::parameters_wopbs_message_carry::*;
 use crate::parameters::parameters_wopbs_prime_moduli::*;
 use crate::parameters::*;
 use crate::wopbs::WopbsKey;
-use crate::{ClientKey, ServerKey};
+use crate::{ClientKey, PublicKey, ServerKey};
 use concrete_utils::keycache::{
     FileStorage, KeyCache as TKeyCache, NamedParam, SharedKey as GenericSharedKey,
 };
@@ -277,7 +277,63 @@ impl KeycacheWopbsV0 {
     }
 }
 
+impl From<Parameters> for PublicKey {
+    fn from(param: Parameters) -> Self {
+        // use with_key to avoid doing a temporary cloning
+        KEY_CACHE
+            .inner
+            .with_key(param, |keys| PublicKey::new(&keys.0))
+    }
+}
+
+pub struct SharedPublicKey {
+    inner: GenericSharedKey<(ClientKey, ServerKey)>,
+    public: GenericSharedKey<PublicKey>,
+}
+
+impl SharedPublicKey {
+    pub fn client_key(&self) -> &ClientKey {
+        &self.inner.0
+    }
+    pub fn server_key(&self) -> &ServerKey {
+        &self.inner.1
+    }
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public
+    }
+}
+
+/// The KeyCache struct for the shortint public keys.
+///
+/// Public keys are several gigabytes large, so they are generated at most once per parameter set.
+///
+/// You should not create an instance yourself,
+/// but rather use the global variable defined: [KEY_CACHE_PUBLIC_KEY]
+pub struct KeycachePublicKey {
+    inner: TKeyCache<Parameters, PublicKey, FileStorage>,
+}
+
+impl Default for KeycachePublicKey {
+    fn default() -> Self {
+        Self {
+            inner: TKeyCache::new(FileStorage::new("../keys/shortint/public_key".to_string())),
+        }
+    }
+}
+
+impl KeycachePublicKey {
+    pub fn get_from_param(&self, param: Parameters) -> SharedPublicKey {
+        let key = KEY_CACHE.get_from_param(param);
+        let pk = self.inner.get(param);
+        SharedPublicKey {
+            inner: key.inner,
+            public: pk,
+        }
+    }
+}
+
 lazy_static! {
     pub static ref KEY_CACHE: Keycache = Default::default();
     pub static ref KEY_CACHE_WOPBS: KeycacheWopbsV0 = Default::default();
+    pub static ref KEY_CACHE_PUBLIC_KEY: KeycachePublicKey = Default::default();
 }
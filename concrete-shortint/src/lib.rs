@@ -20,6 +20,8 @@
 //! * The [ClientKey] is used to encrypt and decrypt and has to be kept secret;
 //! * The [ServerKey] is used to perform homomorphic operations on the server side and it is meant
 //!   to be published (the client sends it to the server).
+//! * The [PublicKey] is used to encrypt without the secret key, it is meant to be published as
+//!   well.
 //!
 //!
 //! # Quick Example
@@ -47,21 +49,28 @@
 //! let output = client_key.decrypt(&ct_3);
 //! assert_eq!(output, 1);
 //! ```
+#[cfg(feature = "boolean")]
+pub mod bridge;
+pub mod casting;
 pub mod ciphertext;
 pub mod client_key;
 pub mod engine;
 #[cfg(any(test, feature = "internal-keycache"))]
 pub mod keycache;
 pub mod parameters;
+pub mod public_key;
 pub mod server_key;
 #[cfg(doctest)]
 mod test_user_docs;
 pub mod wopbs;
 
-pub use ciphertext::Ciphertext;
+pub use ciphertext::{Ciphertext, CompressedCiphertext};
 pub use client_key::ClientKey;
 pub use parameters::Parameters;
-pub use server_key::{CheckError, ServerKey};
+pub use public_key::PublicKey;
+pub use server_key::{
+    CheckError, CompressedServerKey, LookupTable, ServerKey, ShortintError, ValidationError,
+};
 
 /// Generate a couple of client and server keys.
 ///
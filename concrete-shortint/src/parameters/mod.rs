@@ -15,11 +15,11 @@ pub mod parameters_wopbs_message_carry;
 pub(crate) mod parameters_wopbs_prime_moduli;
 
 /// The number of bits on which the message will be encoded.
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Serialize, Deserialize)]
 pub struct MessageModulus(pub usize);
 
 /// The number of bits on which the carry will be encoded.
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Serialize, Deserialize)]
 pub struct CarryModulus(pub usize);
 
 /// A structure defining the set of cryptographic parameters for homomorphic integer circuit
@@ -0,0 +1,220 @@
+//! Module with the definition of the PublicKey.
+
+#[cfg(test)]
+mod tests;
+
+use crate::ciphertext::Ciphertext;
+use crate::engine::ShortintEngine;
+use crate::parameters::{MessageModulus, Parameters};
+use crate::ClientKey;
+use concrete_core::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Debug;
+
+/// A structure containing a public key.
+///
+/// Anyone holding the public key can encrypt messages that the corresponding [`ClientKey`]
+/// decrypts, without having access to the secret key.
+///
+/// In more details, it contains:
+/// * `lwe_public_key` - a set of LWE encryptions of zero under the large LWE secret key of the
+/// client key, the one the ciphertexts are encrypted under.
+/// * `parameters` - the cryptographic parameter set.
+#[derive(Clone, Debug)]
+pub struct PublicKey {
+    pub(crate) lwe_public_key: LwePublicKey64,
+    pub parameters: Parameters,
+}
+
+impl PublicKey {
+    /// Generates a public key from a client key.
+    ///
+    /// The public key holds `(k * N + 1) * 64 + 128` LWE encryptions of zero of dimension `k * N`,
+    /// where `k` is the GLWE dimension and `N` the polynomial size of the parameters. Its size is
+    /// therefore quadratic in `k * N`: about 2 GB for `k * N = 2048` (e.g.
+    /// [`PARAM_MESSAGE_2_CARRY_2`](crate::parameters::PARAM_MESSAGE_2_CARRY_2)), 8.6 GB for
+    /// `k * N = 4096` and 34 GB for `k * N = 8192`, and its generation time grows in the same
+    /// proportion.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use concrete_shortint::{ClientKey, PublicKey};
+    ///
+    /// // Generate the client key and the public key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let pk = PublicKey::new(&cks);
+    /// ```
+    pub fn new(client_key: &ClientKey) -> PublicKey {
+        ShortintEngine::with_thread_local_mut(|engine| engine.new_public_key(client_key).unwrap())
+    }
+
+    /// Encrypts a small integer message using the public key.
+    ///
+    /// The input message is reduced to the encrypted message space modulus
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_1_CARRY_1;
+    /// use concrete_shortint::{ClientKey, PublicKey};
+    ///
+    /// // The smallest parameter set keeps the public key around 1 GB
+    /// let cks = ClientKey::new(PARAM_MESSAGE_1_CARRY_1);
+    /// let pk = PublicKey::new(&cks);
+    ///
+    /// // Encryption of one message that is within the encrypted message modulus:
+    /// let msg = 1;
+    /// let ct = pk.encrypt(msg);
+    ///
+    /// let dec = cks.decrypt(&ct);
+    /// assert_eq!(msg, dec);
+    ///
+    /// // Encryption of one message that is outside the encrypted message modulus:
+    /// let msg = 3;
+    /// let ct = pk.encrypt(msg);
+    ///
+    /// let dec = cks.decrypt(&ct);
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    /// assert_eq!(msg % modulus, dec);
+    /// ```
+    pub fn encrypt(&self, message: u64) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.encrypt_with_public_key(self, message).unwrap()
+        })
+    }
+
+    /// Encrypts a small integer message using the public key with a specific message modulus
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use concrete_shortint::parameters::{MessageModulus, PARAM_MESSAGE_2_CARRY_2};
+    /// use concrete_shortint::{ClientKey, PublicKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let pk = PublicKey::new(&cks);
+    ///
+    /// let msg = 3;
+    ///
+    /// // Encryption of one message:
+    /// let ct = pk.encrypt_with_message_modulus(msg, MessageModulus(6));
+    ///
+    /// // Decryption:
+    /// let dec = cks.decrypt(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn encrypt_with_message_modulus(
+        &self,
+        message: u64,
+        message_modulus: MessageModulus,
+    ) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .encrypt_with_message_modulus_and_public_key(self, message, message_modulus)
+                .unwrap()
+        })
+    }
+
+    /// Encrypts a small integer message using the public key without padding bit.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use concrete_shortint::{ClientKey, PublicKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let pk = PublicKey::new(&cks);
+    ///
+    /// // Encryption of one message that is within the encrypted message modulus:
+    /// let msg = 6;
+    /// let ct = pk.encrypt_without_padding(msg);
+    ///
+    /// let dec = cks.decrypt_message_and_carry_without_padding(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn encrypt_without_padding(&self, message: u64) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .encrypt_without_padding_with_public_key(self, message)
+                .unwrap()
+        })
+    }
+
+    /// Encrypts a small integer message using the public key without padding bit with some
+    /// modulus.
+    ///
+    /// The input message is reduced to the encrypted message space modulus
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use concrete_shortint::{ClientKey, PublicKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let pk = PublicKey::new(&cks);
+    ///
+    /// let msg = 2;
+    /// let modulus = 3;
+    ///
+    /// // Encryption of one message:
+    /// let ct = pk.encrypt_native_crt(msg, modulus);
+    ///
+    /// // Decryption:
+    /// let dec = cks.decrypt_message_native_crt(&ct, modulus);
+    /// assert_eq!(msg, dec % modulus as u64);
+    /// ```
+    pub fn encrypt_native_crt(&self, message: u64, message_modulus: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .encrypt_native_crt_with_public_key(self, message, message_modulus)
+                .unwrap()
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializablePublicKey {
+    lwe_public_key: Vec<u8>,
+    parameters: Parameters,
+}
+
+impl Serialize for PublicKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_eng = DefaultSerializationEngine::new(()).map_err(serde::ser::Error::custom)?;
+
+        let lwe_public_key = ser_eng
+            .serialize(&self.lwe_public_key)
+            .map_err(serde::ser::Error::custom)?;
+
+        SerializablePublicKey {
+            lwe_public_key,
+            parameters: self.parameters,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let thing =
+            SerializablePublicKey::deserialize(deserializer).map_err(serde::de::Error::custom)?;
+        let mut de_eng = DefaultSerializationEngine::new(()).map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            lwe_public_key: de_eng
+                .deserialize(thing.lwe_public_key.as_slice())
+                .map_err(serde::de::Error::custom)?,
+            parameters: thing.parameters,
+        })
+    }
+}
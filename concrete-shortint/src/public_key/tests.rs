@@ -0,0 +1,228 @@
+use crate::keycache::KEY_CACHE_PUBLIC_KEY;
+use crate::parameters::*;
+use crate::PublicKey;
+use paste::paste;
+use rand::Rng;
+
+/// Number of assert in randomized tests
+const NB_TEST: usize = 30;
+
+// Macro to generate tests for all parameter sets
+macro_rules! create_parametrized_test{
+    ($name:ident { $($param:ident),* }) => {
+        paste! {
+            $(
+            #[test]
+            fn [<test_ $name _ $param:lower>]() {
+                $name($param)
+            }
+            )*
+        }
+    };
+     ($name:ident)=> {
+        // A public key holds (k * N + 1) * 64 + 128 encryptions of zero, so only the parameter
+        // sets with k * N <= 2048 are tested: the larger ones need from 8 GB to 137 GB per key
+        create_parametrized_test!($name
+        {
+            PARAM_MESSAGE_1_CARRY_1,
+            PARAM_MESSAGE_1_CARRY_2,
+            PARAM_MESSAGE_1_CARRY_3,
+            PARAM_MESSAGE_1_CARRY_4,
+            PARAM_MESSAGE_2_CARRY_1,
+            PARAM_MESSAGE_2_CARRY_2,
+            PARAM_MESSAGE_2_CARRY_3,
+            PARAM_MESSAGE_3_CARRY_1,
+            PARAM_MESSAGE_3_CARRY_2,
+            PARAM_MESSAGE_4_CARRY_1
+        });
+    };
+}
+
+create_parametrized_test!(shortint_public_key_encrypt_decrypt);
+create_parametrized_test!(shortint_public_key_encrypt_with_message_modulus_decrypt);
+create_parametrized_test!(shortint_public_key_encrypt_decrypt_without_padding);
+create_parametrized_test!(shortint_public_key_encrypt_native_crt_decrypt);
+create_parametrized_test!(shortint_public_key_smart_add);
+create_parametrized_test!(shortint_public_key_serialization);
+
+/// test encryption with the public key and decryption with the LWE client key
+fn shortint_public_key_encrypt_decrypt(param: Parameters) {
+    let keys = KEY_CACHE_PUBLIC_KEY.get_from_param(param);
+    let (cks, pk) = (keys.client_key(), keys.public_key());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = pk.encrypt(clear);
+
+        // the ciphertext is the same as the one of the client key, apart from the encryption
+        let ct_cks = cks.encrypt(clear);
+        assert_eq!(ct.degree, ct_cks.degree);
+        assert_eq!(ct.message_modulus, ct_cks.message_modulus);
+        assert_eq!(ct.carry_modulus, ct_cks.carry_modulus);
+
+        // decryption of ct
+        let dec = cks.decrypt(&ct);
+
+        // assert
+        assert_eq!(clear, dec);
+    }
+}
+
+/// test encryption with the public key with a specific message modulus
+fn shortint_public_key_encrypt_with_message_modulus_decrypt(param: Parameters) {
+    let keys = KEY_CACHE_PUBLIC_KEY.get_from_param(param);
+    let (cks, pk) = (keys.client_key(), keys.public_key());
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let mut modulus = rng.gen::<u64>() % cks.parameters.message_modulus.0 as u64;
+        while modulus == 0 {
+            modulus = rng.gen::<u64>() % cks.parameters.message_modulus.0 as u64;
+        }
+
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = pk.encrypt_with_message_modulus(clear, MessageModulus(modulus as usize));
+
+        let ct_cks = cks.encrypt_with_message_modulus(clear, MessageModulus(modulus as usize));
+        assert_eq!(ct.degree, ct_cks.degree);
+        assert_eq!(ct.message_modulus, ct_cks.message_modulus);
+        assert_eq!(ct.carry_modulus, ct_cks.carry_modulus);
+
+        // decryption of ct
+        let dec = cks.decrypt(&ct);
+
+        // assert
+        assert_eq!(clear, dec);
+    }
+}
+
+/// test encryption with the public key without padding bit
+fn shortint_public_key_encrypt_decrypt_without_padding(param: Parameters) {
+    let keys = KEY_CACHE_PUBLIC_KEY.get_from_param(param);
+    let (cks, pk) = (keys.client_key(), keys.public_key());
+
+    let mut rng = rand::thread_rng();
+
+    // We assume that the modulus is the largest possible without padding bit
+    let modulus = (cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0) as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = pk.encrypt_without_padding(clear);
+
+        let ct_cks = cks.encrypt_without_padding(clear);
+        assert_eq!(ct.degree, ct_cks.degree);
+        assert_eq!(ct.message_modulus, ct_cks.message_modulus);
+        assert_eq!(ct.carry_modulus, ct_cks.carry_modulus);
+
+        // decryption of ct
+        let dec = cks.decrypt_message_and_carry_without_padding(&ct);
+
+        // assert
+        assert_eq!(clear, dec);
+    }
+}
+
+/// test encryption with the public key in the native crt representation
+fn shortint_public_key_encrypt_native_crt_decrypt(param: Parameters) {
+    let keys = KEY_CACHE_PUBLIC_KEY.get_from_param(param);
+    let (cks, pk) = (keys.client_key(), keys.public_key());
+
+    let mut rng = rand::thread_rng();
+
+    // The moduli of the native crt representation are at most the whole message space
+    let max_modulus = ((cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0) as u64)
+        .min(u8::MAX as u64);
+
+    for _ in 0..NB_TEST {
+        let modulus = (2 + rng.gen::<u64>() % (max_modulus - 1)) as u8;
+
+        let clear = rng.gen::<u64>() % modulus as u64;
+
+        let ct = pk.encrypt_native_crt(clear, modulus);
+
+        let ct_cks = cks.encrypt_native_crt(clear, modulus);
+        assert_eq!(ct.degree, ct_cks.degree);
+        assert_eq!(ct.message_modulus, ct_cks.message_modulus);
+        assert_eq!(ct.carry_modulus, ct_cks.carry_modulus);
+
+        // decryption of ct
+        let dec = cks.decrypt_message_native_crt(&ct, modulus);
+
+        // assert
+        assert_eq!(clear, dec);
+    }
+}
+
+/// test the addition with the LWE server key of ciphertexts encrypted with the public key
+fn shortint_public_key_smart_add(param: Parameters) {
+    let keys = KEY_CACHE_PUBLIC_KEY.get_from_param(param);
+    let (cks, sks, pk) = (keys.client_key(), keys.server_key(), keys.public_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..10 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let mut ctxt_0 = pk.encrypt(clear_0);
+
+        // encryption of an integer
+        let mut ctxt_1 = pk.encrypt(clear_1);
+
+        // add the two ciphertexts
+        let mut ct_res = sks.smart_add(&mut ctxt_0, &mut ctxt_1);
+        let mut clear = clear_0 + clear_1;
+
+        //add multiple times to raise the degree and test the smart operation
+        for _ in 0..40 {
+            ct_res = sks.smart_add(&mut ct_res, &mut ctxt_0);
+            clear += clear_0;
+
+            // decryption of ct_res
+            let dec_res = cks.decrypt(&ct_res);
+
+            // assert
+            assert_eq!(clear % modulus, dec_res);
+        }
+    }
+}
+
+/// test the serialization of the public key
+fn shortint_public_key_serialization(param: Parameters) {
+    let keys = KEY_CACHE_PUBLIC_KEY.get_from_param(param);
+    let (cks, pk) = (keys.client_key(), keys.public_key());
+
+    let serialized = bincode::serialize(pk).unwrap();
+    let pk: PublicKey = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(pk.parameters, param);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = pk.encrypt(clear);
+
+        // decryption of ct
+        let dec = cks.decrypt(&ct);
+
+        // assert
+        assert_eq!(clear, dec);
+    }
+}
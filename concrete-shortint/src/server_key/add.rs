@@ -99,14 +99,26 @@ impl ServerKey {
     /// assert_eq!(can_be_added, true);
     /// ```
     pub fn is_add_possible(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> bool {
+        self.check_add_possible(ct_left, ct_right).is_ok()
+    }
+
+    pub(crate) fn check_add_possible(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> Result<(), CheckError> {
         let final_operation_count = ct_left.degree.0 + ct_right.degree.0;
-        final_operation_count <= self.max_degree.0
+        if final_operation_count > self.max_degree.0 {
+            return Err(CarryFull);
+        }
+        self.max_noise_level
+            .validate(ct_left.noise_level + ct_right.noise_level)
     }
 
     /// Computes homomorphically an addition between two ciphertexts encrypting integer values.
     ///
     /// If the operation can be performed, the result is returned a _new_ ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -137,18 +149,16 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_add_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_add(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        self.check_add_possible(ct_left, ct_right)?;
+        let ct_result = self.unchecked_add(ct_left, ct_right);
+        Ok(ct_result)
     }
 
     /// Computes homomorphically an addition between two ciphertexts encrypting integer values.
     ///
     /// If the operation can be performed, the result is stored in the `ct_left` ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned, and `ct_left` is not modified.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct_left`
+    /// is not modified.
     ///
     /// # Example
     ///
@@ -178,12 +188,9 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
-        if self.is_add_possible(ct_left, ct_right) {
-            self.unchecked_add_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_add_possible(ct_left, ct_right)?;
+        self.unchecked_add_assign(ct_left, ct_right);
+        Ok(())
     }
 
     /// Computes homomorphically an addition between two ciphertexts encrypting integer values.
@@ -1,6 +1,5 @@
 use super::ServerKey;
 use crate::engine::ShortintEngine;
-use crate::CheckError::CarryFull;
 use crate::{CheckError, Ciphertext};
 
 impl ServerKey {
@@ -67,7 +66,7 @@ impl ServerKey {
     /// Compute bitwise AND between two ciphertexts without checks.
     ///
     /// If the operation can be performed, the result is returned a _new_ ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -98,18 +97,16 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_bitand(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        let ct_result = self.unchecked_bitand(ct_left, ct_right);
+        Ok(ct_result)
     }
 
     /// Compute bitwise AND between two ciphertexts without checks.
     ///
     /// If the operation can be performed, the result is stored in the `ct_left` ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned, and `ct_left` is not modified.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct_left`
+    /// is not modified.
     ///
     /// # Example
     ///
@@ -139,12 +136,9 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            self.unchecked_bitand_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        self.unchecked_bitand_assign(ct_left, ct_right);
+        Ok(())
     }
 
     /// Computes homomorphically an AND between two ciphertexts encrypting integer values.
@@ -282,7 +276,7 @@ impl ServerKey {
     /// Compute bitwise XOR between two ciphertexts without checks.
     ///
     /// If the operation can be performed, the result is returned a _new_ ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -313,18 +307,16 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_bitxor(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        let ct_result = self.unchecked_bitxor(ct_left, ct_right);
+        Ok(ct_result)
     }
 
     /// Compute bitwise XOR between two ciphertexts without checks.
     ///
     /// If the operation can be performed, the result is stored in the `ct_left` ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned, and `ct_left` is not modified.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct_left`
+    /// is not modified.
     ///
     /// # Example
     ///
@@ -354,12 +346,9 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            self.unchecked_bitxor_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        self.unchecked_bitxor_assign(ct_left, ct_right);
+        Ok(())
     }
 
     /// Computes homomorphically an XOR between two ciphertexts encrypting integer values.
@@ -499,7 +488,7 @@ impl ServerKey {
     /// Compute bitwise OR between two ciphertexts without checks.
     ///
     /// If the operation can be performed, the result is returned a _new_ ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -530,18 +519,16 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_bitor(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        let ct_result = self.unchecked_bitor(ct_left, ct_right);
+        Ok(ct_result)
     }
 
     /// Compute bitwise OR between two ciphertexts without checks.
     ///
     /// If the operation can be performed, the result is stored in the `ct_left` ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned, and `ct_left` is not modified.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct_left`
+    /// is not modified.
     ///
     /// # Example
     ///
@@ -571,12 +558,9 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            self.unchecked_bitor_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        self.unchecked_bitor_assign(ct_left, ct_right);
+        Ok(())
     }
 
     /// Computes homomorphically an OR between two ciphertexts encrypting integer values.
@@ -648,4 +632,742 @@ impl ServerKey {
             engine.smart_bitor_assign(self, ct_left, ct_right).unwrap()
         })
     }
+
+    /// Computes homomorphically an AND between a ciphertext and a clear scalar without checks.
+    ///
+    /// This only needs a single univariate programmable bootstrapping.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an AND:
+    /// let ct_res = sks.unchecked_scalar_bitand(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg & scalar as u64, res);
+    /// ```
+    pub fn unchecked_scalar_bitand(&self, ct: &Ciphertext, scalar: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_scalar_bitand(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically an AND between a ciphertext and a clear scalar without checks.
+    ///
+    /// The result is assigned in the `ct` ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an AND:
+    /// sks.unchecked_scalar_bitand_assign(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg & scalar as u64, res);
+    /// ```
+    pub fn unchecked_scalar_bitand_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_bitand_assign(self, ct, scalar)
+                .unwrap()
+        })
+    }
+
+    /// Verifies if an AND between a ciphertext and a clear scalar can be computed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(3);
+    ///
+    /// let res = sks.is_scalar_bitand_possible(&ct, 1);
+    /// assert_eq!(true, res);
+    /// ```
+    pub fn is_scalar_bitand_possible(&self, ct: &Ciphertext, scalar: u8) -> bool {
+        self.check_scalar_bitand_possible(ct, scalar).is_ok()
+    }
+
+    pub(crate) fn check_scalar_bitand_possible(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        let degree_after_pbs = ct.degree.after_pbs(|x| x & scalar as usize);
+        self.check_univariate_pbs_possible(ct, degree_after_pbs)
+    }
+
+    /// Computes homomorphically an AND between a ciphertext and a clear scalar.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an AND:
+    /// let ct_res = sks.checked_scalar_bitand(&ct, scalar);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(msg & scalar as u64, res);
+    /// ```
+    pub fn checked_scalar_bitand(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_scalar_bitand_possible(ct, scalar)?;
+        let ct_result = self.unchecked_scalar_bitand(ct, scalar);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically an AND between a ciphertext and a clear scalar.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct` is
+    /// not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an AND:
+    /// let res = sks.checked_scalar_bitand_assign(&mut ct, scalar);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg & scalar as u64, res);
+    /// ```
+    pub fn checked_scalar_bitand_assign(
+        &self,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        self.check_scalar_bitand_possible(ct, scalar)?;
+        self.unchecked_scalar_bitand_assign(ct, scalar);
+        Ok(())
+    }
+
+    /// Computes homomorphically an AND between a ciphertext and a clear scalar.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an AND:
+    /// let ct_res = sks.smart_scalar_bitand(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg & scalar as u64, res);
+    /// ```
+    pub fn smart_scalar_bitand(&self, ct: &mut Ciphertext, scalar: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_bitand(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically an AND between a ciphertext and a clear scalar.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is stored in the `ct` ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an AND:
+    /// sks.smart_scalar_bitand_assign(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg & scalar as u64, res);
+    /// ```
+    pub fn smart_scalar_bitand_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_bitand_assign(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a XOR between a ciphertext and a clear scalar without checks.
+    ///
+    /// This only needs a single univariate programmable bootstrapping.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// The scalar has to be smaller than the message modulus, for the result to fit in the
+    /// plaintext space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a XOR:
+    /// let ct_res = sks.unchecked_scalar_bitxor(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg ^ scalar as u64, res);
+    /// ```
+    pub fn unchecked_scalar_bitxor(&self, ct: &Ciphertext, scalar: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_scalar_bitxor(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a XOR between a ciphertext and a clear scalar without checks.
+    ///
+    /// The result is assigned in the `ct` ciphertext.
+    ///
+    /// The scalar has to be smaller than the message modulus, for the result to fit in the
+    /// plaintext space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a XOR:
+    /// sks.unchecked_scalar_bitxor_assign(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg ^ scalar as u64, res);
+    /// ```
+    pub fn unchecked_scalar_bitxor_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_bitxor_assign(self, ct, scalar)
+                .unwrap()
+        })
+    }
+
+    /// Verifies if a XOR between a ciphertext and a clear scalar can be computed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(3);
+    ///
+    /// let res = sks.is_scalar_bitxor_possible(&ct, 1);
+    /// assert_eq!(true, res);
+    /// ```
+    pub fn is_scalar_bitxor_possible(&self, ct: &Ciphertext, scalar: u8) -> bool {
+        self.check_scalar_bitxor_possible(ct, scalar).is_ok()
+    }
+
+    pub(crate) fn check_scalar_bitxor_possible(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        let degree_after_pbs = ct.degree.after_pbs(|x| x ^ scalar as usize);
+        self.check_univariate_pbs_possible(ct, degree_after_pbs)
+    }
+
+    /// Computes homomorphically a XOR between a ciphertext and a clear scalar.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a XOR:
+    /// let ct_res = sks.checked_scalar_bitxor(&ct, scalar);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(msg ^ scalar as u64, res);
+    /// ```
+    pub fn checked_scalar_bitxor(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_scalar_bitxor_possible(ct, scalar)?;
+        let ct_result = self.unchecked_scalar_bitxor(ct, scalar);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically a XOR between a ciphertext and a clear scalar.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct` is
+    /// not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a XOR:
+    /// let res = sks.checked_scalar_bitxor_assign(&mut ct, scalar);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg ^ scalar as u64, res);
+    /// ```
+    pub fn checked_scalar_bitxor_assign(
+        &self,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        self.check_scalar_bitxor_possible(ct, scalar)?;
+        self.unchecked_scalar_bitxor_assign(ct, scalar);
+        Ok(())
+    }
+
+    /// Computes homomorphically a XOR between a ciphertext and a clear scalar.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// The scalar may not fit in the message, the result is then reduced modulo the message
+    /// modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a XOR:
+    /// let ct_res = sks.smart_scalar_bitxor(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg ^ scalar as u64, res);
+    /// ```
+    pub fn smart_scalar_bitxor(&self, ct: &mut Ciphertext, scalar: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_bitxor(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a XOR between a ciphertext and a clear scalar.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is stored in the `ct` ciphertext.
+    ///
+    /// The scalar may not fit in the message, the result is then reduced modulo the message
+    /// modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a XOR:
+    /// sks.smart_scalar_bitxor_assign(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg ^ scalar as u64, res);
+    /// ```
+    pub fn smart_scalar_bitxor_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_bitxor_assign(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically an OR between a ciphertext and a clear scalar without checks.
+    ///
+    /// This only needs a single univariate programmable bootstrapping.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// The scalar has to be smaller than the message modulus, for the result to fit in the
+    /// plaintext space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let scalar = 1;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an OR:
+    /// let ct_res = sks.unchecked_scalar_bitor(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg | scalar as u64, res);
+    /// ```
+    pub fn unchecked_scalar_bitor(&self, ct: &Ciphertext, scalar: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_scalar_bitor(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically an OR between a ciphertext and a clear scalar without checks.
+    ///
+    /// The result is assigned in the `ct` ciphertext.
+    ///
+    /// The scalar has to be smaller than the message modulus, for the result to fit in the
+    /// plaintext space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an OR:
+    /// sks.unchecked_scalar_bitor_assign(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg | scalar as u64, res);
+    /// ```
+    pub fn unchecked_scalar_bitor_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_bitor_assign(self, ct, scalar)
+                .unwrap()
+        })
+    }
+
+    /// Verifies if an OR between a ciphertext and a clear scalar can be computed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(2);
+    ///
+    /// let res = sks.is_scalar_bitor_possible(&ct, 1);
+    /// assert_eq!(true, res);
+    /// ```
+    pub fn is_scalar_bitor_possible(&self, ct: &Ciphertext, scalar: u8) -> bool {
+        self.check_scalar_bitor_possible(ct, scalar).is_ok()
+    }
+
+    pub(crate) fn check_scalar_bitor_possible(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        let degree_after_pbs = ct.degree.after_pbs(|x| x | scalar as usize);
+        self.check_univariate_pbs_possible(ct, degree_after_pbs)
+    }
+
+    /// Computes homomorphically an OR between a ciphertext and a clear scalar.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let scalar = 1;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an OR:
+    /// let ct_res = sks.checked_scalar_bitor(&ct, scalar);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(msg | scalar as u64, res);
+    /// ```
+    pub fn checked_scalar_bitor(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_scalar_bitor_possible(ct, scalar)?;
+        let ct_result = self.unchecked_scalar_bitor(ct, scalar);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically an OR between a ciphertext and a clear scalar.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct` is
+    /// not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an OR:
+    /// let res = sks.checked_scalar_bitor_assign(&mut ct, scalar);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg | scalar as u64, res);
+    /// ```
+    pub fn checked_scalar_bitor_assign(
+        &self,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        self.check_scalar_bitor_possible(ct, scalar)?;
+        self.unchecked_scalar_bitor_assign(ct, scalar);
+        Ok(())
+    }
+
+    /// Computes homomorphically an OR between a ciphertext and a clear scalar.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// The scalar may not fit in the message, the result is then reduced modulo the message
+    /// modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an OR:
+    /// let ct_res = sks.smart_scalar_bitor(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg | scalar as u64, res);
+    /// ```
+    pub fn smart_scalar_bitor(&self, ct: &mut Ciphertext, scalar: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_bitor(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically an OR between a ciphertext and a clear scalar.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is stored in the `ct` ciphertext.
+    ///
+    /// The scalar may not fit in the message, the result is then reduced modulo the message
+    /// modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically an OR:
+    /// sks.smart_scalar_bitor_assign(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg | scalar as u64, res);
+    /// ```
+    pub fn smart_scalar_bitor_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_bitor_assign(self, ct, scalar).unwrap()
+        })
+    }
 }
@@ -1,7 +1,6 @@
 use super::ServerKey;
 use crate::engine::ShortintEngine;
 use crate::server_key::CheckError;
-use crate::server_key::CheckError::CarryFull;
 use crate::Ciphertext;
 
 // # Note:
@@ -43,7 +42,7 @@ impl ServerKey {
     /// Implements the "greater" (`>`) operator between two ciphertexts with checks.
     ///
     /// If the operation can be performed, the result is returned in a _new_ ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -73,11 +72,8 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_greater(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        Ok(self.unchecked_greater(ct_left, ct_right))
     }
 
     /// Computes homomorphically a `>` between two ciphertexts encrypting integer values.
@@ -188,7 +184,7 @@ impl ServerKey {
     /// Implements the "greater or equal" (`>=`) operator between two ciphertexts with checks.
     ///
     /// If the operation can be performed, the result is returned in a _new_ ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -218,11 +214,8 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_greater_or_equal(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        Ok(self.unchecked_greater_or_equal(ct_left, ct_right))
     }
 
     /// Implements the "less" (`<`) operator between two ciphertexts without checks.
@@ -258,7 +251,7 @@ impl ServerKey {
     /// Implements the "less" (`<`) operator between two ciphertexts with checks.
     ///
     /// If the operation can be performed, the result is returned in a _new_ ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -288,11 +281,8 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_less(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        Ok(self.unchecked_less(ct_left, ct_right))
     }
 
     /// Computes homomorphically a `<` between two ciphertexts encrypting integer values.
@@ -365,7 +355,7 @@ impl ServerKey {
     /// Implements the "less or equal" (`<=`) operator between two ciphertexts with checks.
     ///
     /// If the operation can be performed, the result is returned in a _new_ ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -395,11 +385,8 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_less(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        Ok(self.unchecked_less(ct_left, ct_right))
     }
 
     /// Computes homomorphically a `<=` between two ciphertexts encrypting integer values.
@@ -470,7 +457,7 @@ impl ServerKey {
     /// Implements the "less" (`==`) operator between two ciphertexts with checks.
     ///
     /// If the operation can be performed, the result is returned in a _new_ ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -500,11 +487,8 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_equal(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        Ok(self.unchecked_equal(ct_left, ct_right))
     }
 
     /// Computes homomorphically a `==` between two ciphertexts encrypting integer values.
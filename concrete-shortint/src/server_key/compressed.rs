@@ -0,0 +1,151 @@
+//! Module with the definition of the CompressedServerKey.
+
+use super::MaxDegree;
+use crate::client_key::ClientKey;
+use crate::engine::ShortintEngine;
+use crate::parameters::{CarryModulus, MessageModulus};
+use crate::ServerKey;
+use concrete_core::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A structure containing the server public key in a compressed form.
+///
+/// The uniformly random masks of the bootstrapping and key switching keys are replaced by the
+/// seeds used to generate them, which makes the key much smaller to send to the server. It is
+/// decompressed into a [`ServerKey`] on the server side.
+#[derive(Clone)]
+pub struct CompressedServerKey {
+    pub key_switching_key: LweSeededKeyswitchKey64,
+    pub bootstrapping_key: LweSeededBootstrapKey64,
+    // Size of the message buffer
+    pub message_modulus: MessageModulus,
+    // Size of the carry buffer
+    pub carry_modulus: CarryModulus,
+    // Maximum number of operations that can be done before emptying the operation buffer
+    pub max_degree: MaxDegree,
+}
+
+impl CompressedServerKey {
+    /// Generates a compressed server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use concrete_shortint::{ClientKey, CompressedServerKey};
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // Generate the compressed server key:
+    /// let compressed_sks = CompressedServerKey::new(&cks);
+    /// ```
+    pub fn new(cks: &ClientKey) -> CompressedServerKey {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_compressed_server_key(cks).unwrap()
+        })
+    }
+
+    /// Generates a compressed server key with a chosen maximum degree
+    pub fn new_with_max_degree(cks: &ClientKey, max_degree: MaxDegree) -> CompressedServerKey {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .new_compressed_server_key_with_max_degree(cks, max_degree)
+                .unwrap()
+        })
+    }
+
+    /// Regenerates the masks of the keys from their seeds, and converts the bootstrapping key to
+    /// the Fourier domain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use concrete_shortint::{ClientKey, CompressedServerKey};
+    ///
+    /// // Generate the client key and the compressed server key on the client side:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let compressed_sks = CompressedServerKey::new(&cks);
+    ///
+    /// // Decompress the server key on the server side:
+    /// let sks = compressed_sks.decompress();
+    ///
+    /// let ct1 = cks.encrypt(2);
+    /// let ct2 = cks.encrypt(3);
+    /// let ct_res = sks.unchecked_add(&ct1, &ct2);
+    /// let ct_res = sks.keyswitch_bootstrap(&ct_res);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(1, dec);
+    /// ```
+    pub fn decompress(&self) -> ServerKey {
+        ShortintEngine::with_thread_local_mut(|engine| engine.decompress_server_key(self).unwrap())
+    }
+}
+
+impl From<CompressedServerKey> for ServerKey {
+    fn from(compressed_server_key: CompressedServerKey) -> Self {
+        compressed_server_key.decompress()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializableCompressedServerKey {
+    pub key_switching_key: Vec<u8>,
+    pub bootstrapping_key: Vec<u8>,
+    // Size of the message buffer
+    pub message_modulus: MessageModulus,
+    // Size of the carry buffer
+    pub carry_modulus: CarryModulus,
+    // Maximum number of operations that can be done before emptying the operation buffer
+    pub max_degree: MaxDegree,
+}
+
+impl Serialize for CompressedServerKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_eng = DefaultSerializationEngine::new(()).map_err(serde::ser::Error::custom)?;
+
+        let key_switching_key = ser_eng
+            .serialize(&self.key_switching_key)
+            .map_err(serde::ser::Error::custom)?;
+        let bootstrapping_key = ser_eng
+            .serialize(&self.bootstrapping_key)
+            .map_err(serde::ser::Error::custom)?;
+
+        SerializableCompressedServerKey {
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            max_degree: self.max_degree,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompressedServerKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let thing = SerializableCompressedServerKey::deserialize(deserializer)
+            .map_err(serde::de::Error::custom)?;
+        let mut ser_eng = DefaultSerializationEngine::new(()).map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            key_switching_key: ser_eng
+                .deserialize(thing.key_switching_key.as_slice())
+                .map_err(serde::de::Error::custom)?,
+            bootstrapping_key: ser_eng
+                .deserialize(thing.bootstrapping_key.as_slice())
+                .map_err(serde::de::Error::custom)?,
+            message_modulus: thing.message_modulus,
+            carry_modulus: thing.carry_modulus,
+            max_degree: thing.max_degree,
+        })
+    }
+}
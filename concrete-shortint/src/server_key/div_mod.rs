@@ -1,7 +1,30 @@
 use super::ServerKey;
 use crate::engine::ShortintEngine;
+use crate::parameters::MessageModulus;
 use crate::Ciphertext;
 
+/// What the encrypted division and remainder return when the divisor is zero.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DivisionByZeroPolicy {
+    /// The quotient and the remainder are 0.
+    Zero,
+    /// The quotient and the remainder are the largest message of the dividend,
+    /// `message_modulus - 1`.
+    Max,
+    /// The quotient and the remainder are 0, and a flag encrypting 1 if the divisor is zero and 0
+    /// otherwise is returned along with them.
+    ErrorFlag,
+}
+
+impl DivisionByZeroPolicy {
+    pub(crate) fn value_on_zero(&self, message_modulus: MessageModulus) -> u64 {
+        match self {
+            DivisionByZeroPolicy::Zero | DivisionByZeroPolicy::ErrorFlag => 0,
+            DivisionByZeroPolicy::Max => message_modulus.0 as u64 - 1,
+        }
+    }
+}
+
 impl ServerKey {
     /// Compute a division between two ciphertexts without checks.
     ///
@@ -153,6 +176,325 @@ impl ServerKey {
         })
     }
 
+    /// Compute the remainder of a division between two ciphertexts without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A division by zero returns 0!
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a remainder
+    /// let ct_res = sks.unchecked_rem(&ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn unchecked_rem(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_rem(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Compute the remainder of a division between two ciphertexts without checks.
+    ///
+    /// The result is _assigned_ in `ct_left`.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A division by zero returns 0!
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a remainder
+    /// sks.unchecked_rem_assign(&mut ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_1);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn unchecked_rem_assign(&self, ct_left: &mut Ciphertext, ct_right: &Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_rem_assign(self, ct_left, ct_right)
+                .unwrap()
+        })
+    }
+
+    /// Compute the remainder of a division between two ciphertexts.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A division by zero returns 0!
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(clear_1);
+    /// let mut ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a remainder
+    /// let ct_res = sks.smart_rem(&mut ct_1, &mut ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn smart_rem(&self, ct_left: &mut Ciphertext, ct_right: &mut Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_rem(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Compute the remainder of a division between two ciphertexts.
+    ///
+    /// The result is _assigned_ in `ct_left`.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A division by zero returns 0!
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(clear_1);
+    /// let mut ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a remainder
+    /// sks.smart_rem_assign(&mut ct_1, &mut ct_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ct_1);
+    /// assert_eq!(clear_1 % clear_2, res);
+    /// ```
+    pub fn smart_rem_assign(&self, ct_left: &mut Ciphertext, ct_right: &mut Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_rem_assign(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Compute the quotient and the remainder of a division between two ciphertexts without
+    /// checks.
+    ///
+    /// Both results are computed from the same packing of the operands and share the same
+    /// keyswitch, which is cheaper than calling [ServerKey::unchecked_div] and
+    /// [ServerKey::unchecked_rem].
+    ///
+    /// # Warning
+    ///
+    /// /!\ A division by zero returns 0 for both results!
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a division
+    /// let (ct_q, ct_r) = sks.unchecked_div_rem(&ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// assert_eq!(clear_1 / clear_2, cks.decrypt(&ct_q));
+    /// assert_eq!(clear_1 % clear_2, cks.decrypt(&ct_r));
+    /// ```
+    pub fn unchecked_div_rem(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> (Ciphertext, Ciphertext) {
+        let (quotient, remainder, _) =
+            self.unchecked_div_rem_with_policy(ct_left, ct_right, DivisionByZeroPolicy::Zero);
+        (quotient, remainder)
+    }
+
+    /// Compute the quotient and the remainder of a division between two ciphertexts.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A division by zero returns 0 for both results!
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(clear_1);
+    /// let mut ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a division
+    /// let (ct_q, ct_r) = sks.smart_div_rem(&mut ct_1, &mut ct_2);
+    ///
+    /// // Decrypt
+    /// assert_eq!(clear_1 / clear_2, cks.decrypt(&ct_q));
+    /// assert_eq!(clear_1 % clear_2, cks.decrypt(&ct_r));
+    /// ```
+    pub fn smart_div_rem(
+        &self,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+    ) -> (Ciphertext, Ciphertext) {
+        let (quotient, remainder, _) =
+            self.smart_div_rem_with_policy(ct_left, ct_right, DivisionByZeroPolicy::Zero);
+        (quotient, remainder)
+    }
+
+    /// Compute the quotient and the remainder of a division between two ciphertexts without
+    /// checks, following the given policy when the divisor is zero.
+    ///
+    /// The third element is the error flag, only returned with
+    /// [DivisionByZeroPolicy::ErrorFlag].
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use concrete_shortint::server_key::DivisionByZeroPolicy;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // Encrypt two messages
+    /// let ct_1 = cks.encrypt(3);
+    /// let ct_2 = cks.encrypt(0);
+    ///
+    /// // Compute homomorphically a division by zero
+    /// let (ct_q, ct_r, flag) =
+    ///     sks.unchecked_div_rem_with_policy(&ct_1, &ct_2, DivisionByZeroPolicy::Max);
+    /// assert_eq!(3, cks.decrypt(&ct_q));
+    /// assert_eq!(3, cks.decrypt(&ct_r));
+    /// assert!(flag.is_none());
+    ///
+    /// let (ct_q, ct_r, flag) =
+    ///     sks.unchecked_div_rem_with_policy(&ct_1, &ct_2, DivisionByZeroPolicy::ErrorFlag);
+    /// assert_eq!(0, cks.decrypt(&ct_q));
+    /// assert_eq!(0, cks.decrypt(&ct_r));
+    /// assert_eq!(1, cks.decrypt(&flag.unwrap()));
+    /// ```
+    pub fn unchecked_div_rem_with_policy(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+        policy: DivisionByZeroPolicy,
+    ) -> (Ciphertext, Ciphertext, Option<Ciphertext>) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_div_rem_with_policy(self, ct_left, ct_right, policy)
+                .unwrap()
+        })
+    }
+
+    /// Compute the quotient and the remainder of a division between two ciphertexts, following
+    /// the given policy when the divisor is zero.
+    ///
+    /// The third element is the error flag, only returned with
+    /// [DivisionByZeroPolicy::ErrorFlag].
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use concrete_shortint::server_key::DivisionByZeroPolicy;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // Encrypt two messages
+    /// let mut ct_1 = cks.encrypt(3);
+    /// let mut ct_2 = cks.encrypt(2);
+    ///
+    /// // Compute homomorphically a division
+    /// let (ct_q, ct_r, flag) =
+    ///     sks.smart_div_rem_with_policy(&mut ct_1, &mut ct_2, DivisionByZeroPolicy::ErrorFlag);
+    /// assert_eq!(1, cks.decrypt(&ct_q));
+    /// assert_eq!(1, cks.decrypt(&ct_r));
+    /// assert_eq!(0, cks.decrypt(&flag.unwrap()));
+    /// ```
+    pub fn smart_div_rem_with_policy(
+        &self,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+        policy: DivisionByZeroPolicy,
+    ) -> (Ciphertext, Ciphertext, Option<Ciphertext>) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .smart_div_rem_with_policy(self, ct_left, ct_right, policy)
+                .unwrap()
+        })
+    }
+
     /// Compute a division of a ciphertext by a scalar without checks.
     ///
     /// # Panics
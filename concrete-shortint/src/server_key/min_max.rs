@@ -0,0 +1,904 @@
+use super::ServerKey;
+use crate::engine::ShortintEngine;
+use crate::server_key::CheckError;
+use crate::Ciphertext;
+
+impl ServerKey {
+    /// Computes homomorphically the minimum of two ciphertexts without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let ct_left = cks.encrypt(msg_1);
+    /// let ct_right = cks.encrypt(msg_2);
+    ///
+    /// let ct_res = sks.unchecked_min(&ct_left, &ct_right);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg_1.min(msg_2), res);
+    /// ```
+    pub fn unchecked_min(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_min(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts without checks.
+    ///
+    /// The result is assigned in the `ct_left` ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let mut ct_left = cks.encrypt(msg_1);
+    /// let ct_right = cks.encrypt(msg_2);
+    ///
+    /// sks.unchecked_min_assign(&mut ct_left, &ct_right);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_left);
+    /// assert_eq!(msg_1.min(msg_2), res);
+    /// ```
+    pub fn unchecked_min_assign(&self, ct_left: &mut Ciphertext, ct_right: &Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_min_assign(self, ct_left, ct_right)
+                .unwrap()
+        })
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let ct_left = cks.encrypt(msg_1);
+    /// let ct_right = cks.encrypt(msg_2);
+    ///
+    /// let ct_res = sks.checked_min(&ct_left, &ct_right);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(msg_1.min(msg_2), res);
+    /// ```
+    pub fn checked_min(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        let ct_result = self.unchecked_min(ct_left, ct_right);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct_left` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct_left`
+    /// is not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let mut ct_left = cks.encrypt(msg_1);
+    /// let ct_right = cks.encrypt(msg_2);
+    ///
+    /// let res = sks.checked_min_assign(&mut ct_left, &ct_right);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_left);
+    /// assert_eq!(msg_1.min(msg_2), res);
+    /// ```
+    pub fn checked_min_assign(
+        &self,
+        ct_left: &mut Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> Result<(), CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        self.unchecked_min_assign(ct_left, ct_right);
+        Ok(())
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let mut ct_left = cks.encrypt(msg_1);
+    /// let mut ct_right = cks.encrypt(msg_2);
+    ///
+    /// let ct_res = sks.smart_min(&mut ct_left, &mut ct_right);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg_1.min(msg_2), res);
+    /// ```
+    pub fn smart_min(&self, ct_left: &mut Ciphertext, ct_right: &mut Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_min(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// The result is stored in the `ct_left` ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let mut ct_left = cks.encrypt(msg_1);
+    /// let mut ct_right = cks.encrypt(msg_2);
+    ///
+    /// sks.smart_min_assign(&mut ct_left, &mut ct_right);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_left);
+    /// assert_eq!(msg_1.min(msg_2), res);
+    /// ```
+    pub fn smart_min_assign(&self, ct_left: &mut Ciphertext, ct_right: &mut Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_min_assign(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let ct_left = cks.encrypt(msg_1);
+    /// let ct_right = cks.encrypt(msg_2);
+    ///
+    /// let ct_res = sks.unchecked_max(&ct_left, &ct_right);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg_1.max(msg_2), res);
+    /// ```
+    pub fn unchecked_max(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_max(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts without checks.
+    ///
+    /// The result is assigned in the `ct_left` ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let mut ct_left = cks.encrypt(msg_1);
+    /// let ct_right = cks.encrypt(msg_2);
+    ///
+    /// sks.unchecked_max_assign(&mut ct_left, &ct_right);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_left);
+    /// assert_eq!(msg_1.max(msg_2), res);
+    /// ```
+    pub fn unchecked_max_assign(&self, ct_left: &mut Ciphertext, ct_right: &Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_max_assign(self, ct_left, ct_right)
+                .unwrap()
+        })
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let ct_left = cks.encrypt(msg_1);
+    /// let ct_right = cks.encrypt(msg_2);
+    ///
+    /// let ct_res = sks.checked_max(&ct_left, &ct_right);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(msg_1.max(msg_2), res);
+    /// ```
+    pub fn checked_max(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        let ct_result = self.unchecked_max(ct_left, ct_right);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct_left` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct_left`
+    /// is not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let mut ct_left = cks.encrypt(msg_1);
+    /// let ct_right = cks.encrypt(msg_2);
+    ///
+    /// let res = sks.checked_max_assign(&mut ct_left, &ct_right);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_left);
+    /// assert_eq!(msg_1.max(msg_2), res);
+    /// ```
+    pub fn checked_max_assign(
+        &self,
+        ct_left: &mut Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> Result<(), CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        self.unchecked_max_assign(ct_left, ct_right);
+        Ok(())
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let mut ct_left = cks.encrypt(msg_1);
+    /// let mut ct_right = cks.encrypt(msg_2);
+    ///
+    /// let ct_res = sks.smart_max(&mut ct_left, &mut ct_right);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg_1.max(msg_2), res);
+    /// ```
+    pub fn smart_max(&self, ct_left: &mut Ciphertext, ct_right: &mut Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_max(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// The result is stored in the `ct_left` ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg_1 = 2;
+    /// let msg_2 = 1;
+    ///
+    /// // Encrypt two messages:
+    /// let mut ct_left = cks.encrypt(msg_1);
+    /// let mut ct_right = cks.encrypt(msg_2);
+    ///
+    /// sks.smart_max_assign(&mut ct_left, &mut ct_right);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_left);
+    /// assert_eq!(msg_1.max(msg_2), res);
+    /// ```
+    pub fn smart_max_assign(&self, ct_left: &mut Ciphertext, ct_right: &mut Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_max_assign(self, ct_left, ct_right).unwrap()
+        })
+    }
+
+    /// Computes homomorphically the minimum of a ciphertext and a clear scalar without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_min(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.min(scalar as u64), res);
+    /// ```
+    pub fn unchecked_scalar_min(&self, ct: &Ciphertext, scalar: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_scalar_min(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically the minimum of a ciphertext and a clear scalar without checks.
+    ///
+    /// The result is assigned in the `ct` ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// sks.unchecked_scalar_min_assign(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg.min(scalar as u64), res);
+    /// ```
+    pub fn unchecked_scalar_min_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_min_assign(self, ct, scalar)
+                .unwrap()
+        })
+    }
+
+    /// Verifies if the minimum of a ciphertext and a clear scalar can be computed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(3);
+    ///
+    /// let res = sks.is_scalar_min_possible(&ct, 1);
+    /// assert_eq!(true, res);
+    /// ```
+    pub fn is_scalar_min_possible(&self, ct: &Ciphertext, scalar: u8) -> bool {
+        self.check_scalar_min_possible(ct, scalar).is_ok()
+    }
+
+    pub(crate) fn check_scalar_min_possible(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        let degree_after_pbs = ct.degree.after_pbs(|x| x.min(scalar as usize));
+        self.check_univariate_pbs_possible(ct, degree_after_pbs)
+    }
+
+    /// Computes homomorphically the minimum of a ciphertext and a clear scalar.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.checked_scalar_min(&ct, scalar);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(msg.min(scalar as u64), res);
+    /// ```
+    pub fn checked_scalar_min(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_scalar_min_possible(ct, scalar)?;
+        let ct_result = self.unchecked_scalar_min(ct, scalar);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically the minimum of a ciphertext and a clear scalar.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct` is
+    /// not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let res = sks.checked_scalar_min_assign(&mut ct, scalar);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg.min(scalar as u64), res);
+    /// ```
+    pub fn checked_scalar_min_assign(
+        &self,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        self.check_scalar_min_possible(ct, scalar)?;
+        self.unchecked_scalar_min_assign(ct, scalar);
+        Ok(())
+    }
+
+    /// Computes homomorphically the minimum of a ciphertext and a clear scalar.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_min(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.min(scalar as u64), res);
+    /// ```
+    pub fn smart_scalar_min(&self, ct: &mut Ciphertext, scalar: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_min(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically the minimum of a ciphertext and a clear scalar.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is stored in the `ct` ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let scalar = 1;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// sks.smart_scalar_min_assign(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg.min(scalar as u64), res);
+    /// ```
+    pub fn smart_scalar_min_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_min_assign(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically the maximum of a ciphertext and a clear scalar without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// The scalar has to be smaller than the message modulus, for the result to fit in the
+    /// plaintext space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 1;
+    /// let scalar = 2;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_max(&ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.max(scalar as u64), res);
+    /// ```
+    pub fn unchecked_scalar_max(&self, ct: &Ciphertext, scalar: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_scalar_max(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically the maximum of a ciphertext and a clear scalar without checks.
+    ///
+    /// The result is assigned in the `ct` ciphertext.
+    ///
+    /// The scalar has to be smaller than the message modulus, for the result to fit in the
+    /// plaintext space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 1;
+    /// let scalar = 2;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// sks.unchecked_scalar_max_assign(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg.max(scalar as u64), res);
+    /// ```
+    pub fn unchecked_scalar_max_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_max_assign(self, ct, scalar)
+                .unwrap()
+        })
+    }
+
+    /// Verifies if the maximum of a ciphertext and a clear scalar can be computed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(1);
+    ///
+    /// let res = sks.is_scalar_max_possible(&ct, 2);
+    /// assert_eq!(true, res);
+    /// ```
+    pub fn is_scalar_max_possible(&self, ct: &Ciphertext, scalar: u8) -> bool {
+        self.check_scalar_max_possible(ct, scalar).is_ok()
+    }
+
+    pub(crate) fn check_scalar_max_possible(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        let degree_after_pbs = ct.degree.after_pbs(|x| x.max(scalar as usize));
+        self.check_univariate_pbs_possible(ct, degree_after_pbs)
+    }
+
+    /// Computes homomorphically the maximum of a ciphertext and a clear scalar.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 1;
+    /// let scalar = 2;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.checked_scalar_max(&ct, scalar);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(msg.max(scalar as u64), res);
+    /// ```
+    pub fn checked_scalar_max(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_scalar_max_possible(ct, scalar)?;
+        let ct_result = self.unchecked_scalar_max(ct, scalar);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically the maximum of a ciphertext and a clear scalar.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct` is
+    /// not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 1;
+    /// let scalar = 2;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let res = sks.checked_scalar_max_assign(&mut ct, scalar);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg.max(scalar as u64), res);
+    /// ```
+    pub fn checked_scalar_max_assign(
+        &self,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        self.check_scalar_max_possible(ct, scalar)?;
+        self.unchecked_scalar_max_assign(ct, scalar);
+        Ok(())
+    }
+
+    /// Computes homomorphically the maximum of a ciphertext and a clear scalar.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// The scalar may not fit in the message, the result is then reduced modulo the message
+    /// modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 1;
+    /// let scalar = 2;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_max(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.max(scalar as u64), res);
+    /// ```
+    pub fn smart_scalar_max(&self, ct: &mut Ciphertext, scalar: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_max(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Computes homomorphically the maximum of a ciphertext and a clear scalar.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is stored in the `ct` ciphertext.
+    ///
+    /// The scalar may not fit in the message, the result is then reduced modulo the message
+    /// modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 1;
+    /// let scalar = 2;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// sks.smart_scalar_max_assign(&mut ct, scalar);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg.max(scalar as u64), res);
+    /// ```
+    pub fn smart_scalar_max_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_max_assign(self, ct, scalar).unwrap()
+        })
+    }
+}
@@ -5,34 +5,72 @@
 mod add;
 mod bitwise_op;
 mod comp_op;
+mod compressed;
 mod div_mod;
+mod min_max;
 mod mul;
 mod neg;
+mod rotate;
 mod scalar_add;
 mod scalar_mul;
 mod scalar_sub;
 mod shift;
 mod sub;
+mod try_op;
 
 #[cfg(test)]
 mod tests;
 
-use crate::ciphertext::Ciphertext;
+use crate::ciphertext::{Ciphertext, Degree, NoiseLevel};
 use crate::client_key::ClientKey;
-use crate::engine::ShortintEngine;
+use crate::engine::{EngineError, ShortintEngine};
 use crate::parameters::{CarryModulus, MessageModulus};
 use concrete_core::prelude::*;
+use rayon::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp;
 use std::fmt::{Debug, Display, Formatter};
 
+pub use compressed::CompressedServerKey;
+pub use div_mod::DivisionByZeroPolicy;
+
 /// Maximum value that the degree can reach.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct MaxDegree(pub usize);
 
-/// Error returned when the carry buffer is full.
+/// Maximum value that the noise level can reach.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct MaxNoiseLevel(pub usize);
+
+impl MaxNoiseLevel {
+    /// Returns the maximum noise level supported by the given moduli.
+    ///
+    /// It is the noise level reached when filling the carry buffer with additions of freshly
+    /// encrypted ciphertexts holding the largest message, which the parameters are designed for.
+    pub fn from_msg_carry_modulus(
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    ) -> MaxNoiseLevel {
+        MaxNoiseLevel((message_modulus.0 * carry_modulus.0 - 1) / (message_modulus.0 - 1))
+    }
+
+    /// Returns [CheckError::NoiseTooHigh] if the noise level exceeds the maximum.
+    pub fn validate(&self, noise_level: NoiseLevel) -> Result<(), CheckError> {
+        if noise_level.0 > self.0 {
+            Err(CheckError::NoiseTooHigh)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Error returned when an operation can not be computed safely.
 #[derive(Debug)]
 pub enum CheckError {
+    /// The result would exceed the capacity of the carry buffer.
     CarryFull,
+    /// The result would be too noisy to be decrypted or bootstrapped correctly.
+    NoiseTooHigh,
 }
 
 impl Display for CheckError {
@@ -41,12 +79,144 @@ impl Display for CheckError {
             CheckError::CarryFull => {
                 write!(f, "The carry buffer is full")
             }
+            CheckError::NoiseTooHigh => {
+                write!(f, "The noise level is too high")
+            }
         }
     }
 }
 
 impl std::error::Error for CheckError {}
 
+/// Error returned when a ciphertext can not be used with a [ServerKey].
+#[derive(Debug)]
+pub enum ValidationError {
+    /// The ciphertext was encrypted with moduli other than the ones of the key.
+    ParameterMismatch,
+    /// The LWE dimension of the ciphertext is not the input dimension of the keyswitching key.
+    DimensionMismatch,
+    /// The degree of the ciphertext does not fit in the plaintext space.
+    DegreeTooHigh,
+    /// The noise level of the ciphertext exceeds the maximum of the key.
+    NoiseTooHigh,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::ParameterMismatch => {
+                write!(f, "The ciphertext moduli do not match the ones of the key")
+            }
+            ValidationError::DimensionMismatch => {
+                write!(
+                    f,
+                    "The ciphertext dimension does not match the one of the key"
+                )
+            }
+            ValidationError::DegreeTooHigh => {
+                write!(
+                    f,
+                    "The ciphertext degree does not fit in the plaintext space"
+                )
+            }
+            ValidationError::NoiseTooHigh => {
+                write!(f, "The noise level is too high")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Error returned by the `try_*` operations of the [ServerKey].
+#[derive(Debug)]
+pub enum ShortintError {
+    /// A ciphertext was encrypted with moduli other than the ones of the key.
+    ParameterMismatch,
+    /// The LWE dimension of a ciphertext is not the one expected by the key.
+    DimensionMismatch,
+    /// The result would exceed the capacity of the carry buffer.
+    CarryFull,
+    /// The result would be too noisy to be decrypted or bootstrapped correctly.
+    NoiseTooHigh,
+    /// The degree of a ciphertext does not fit in its plaintext space.
+    DegreeTooHigh,
+    /// A scalar cannot be used by the operation, e.g. a zero divisor or a shift wider than a
+    /// message.
+    InvalidScalar,
+    /// Two slices given to the operation do not have the same length.
+    LengthMismatch,
+    /// An error was returned by the underlying engine.
+    Engine(EngineError),
+}
+
+impl Display for ShortintError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShortintError::ParameterMismatch => {
+                write!(f, "The ciphertext moduli do not match the ones of the key")
+            }
+            ShortintError::DimensionMismatch => {
+                write!(
+                    f,
+                    "The ciphertext dimension does not match the one of the key"
+                )
+            }
+            ShortintError::CarryFull => {
+                write!(f, "The carry buffer is full")
+            }
+            ShortintError::NoiseTooHigh => {
+                write!(f, "The noise level is too high")
+            }
+            ShortintError::DegreeTooHigh => {
+                write!(f, "The ciphertext degree exceeds its plaintext space")
+            }
+            ShortintError::InvalidScalar => {
+                write!(f, "The scalar is not valid for this operation")
+            }
+            ShortintError::LengthMismatch => {
+                write!(f, "The inputs do not have the same length")
+            }
+            ShortintError::Engine(error) => {
+                write!(f, "The engine failed: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShortintError {}
+
+impl From<ValidationError> for ShortintError {
+    fn from(error: ValidationError) -> Self {
+        match error {
+            ValidationError::ParameterMismatch => ShortintError::ParameterMismatch,
+            ValidationError::DimensionMismatch => ShortintError::DimensionMismatch,
+            ValidationError::DegreeTooHigh => ShortintError::DegreeTooHigh,
+            ValidationError::NoiseTooHigh => ShortintError::NoiseTooHigh,
+        }
+    }
+}
+
+impl From<CheckError> for ShortintError {
+    fn from(error: CheckError) -> Self {
+        match error {
+            CheckError::CarryFull => ShortintError::CarryFull,
+            CheckError::NoiseTooHigh => ShortintError::NoiseTooHigh,
+        }
+    }
+}
+
+/// A lookup table, built once from a univariate function and applied to as many ciphertexts as
+/// needed with [ServerKey::apply_lookup_table].
+///
+/// It holds the accumulator of the function, along with the degree of its outputs over the
+/// whole plaintext space.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LookupTable {
+    pub acc: GlweCiphertext64,
+    pub degree: Degree,
+}
+
 /// A structure containing the server public key.
 ///
 /// The server key is generated by the client and is meant to be published: the client
@@ -61,6 +231,8 @@ pub struct ServerKey {
     pub carry_modulus: CarryModulus,
     // Maximum number of operations that can be done before emptying the operation buffer
     pub max_degree: MaxDegree,
+    // Maximum noise level that can be reached before bootstrapping the ciphertext
+    pub max_noise_level: MaxNoiseLevel,
 }
 
 impl ServerKey {
@@ -125,6 +297,114 @@ impl ServerKey {
         })
     }
 
+    /// Constructs the lookup table of a univariate function.
+    ///
+    /// Building the accumulator is not free, so a lookup table applied in a loop should be
+    /// constructed once beforehand.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    ///
+    /// // Generate the lookup table for the function f: x -> x^3 mod 2^2
+    /// let lut = sks.generate_lookup_table(|x| x * x * x % modulus);
+    /// assert_eq!(lut.degree.0, 3);
+    ///
+    /// for msg in 0..modulus {
+    ///     let ct = cks.encrypt(msg);
+    ///     let ct_res = sks.apply_lookup_table(&ct, &lut);
+    ///
+    ///     let dec = cks.decrypt(&ct_res);
+    ///     assert_eq!(dec, msg * msg * msg % modulus);
+    /// }
+    /// ```
+    pub fn generate_lookup_table<F>(&self, f: F) -> LookupTable
+    where
+        F: Fn(u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.generate_lookup_table(self, f).unwrap()
+        })
+    }
+
+    /// Computes a keyswitch and a programmable bootstrap with the accumulator of the lookup
+    /// table, the degree of the result being the one of the table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Generate the lookup table for the function f: x -> x / 2
+    /// let lut = sks.generate_lookup_table(|x| x / 2);
+    /// let ct_res = sks.apply_lookup_table(&ct, &lut);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg / 2);
+    /// ```
+    pub fn apply_lookup_table(&self, ct_in: &Ciphertext, lut: &LookupTable) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.apply_lookup_table(self, ct_in, lut).unwrap()
+        })
+    }
+
+    pub fn apply_lookup_table_assign(&self, ct_in: &mut Ciphertext, lut: &LookupTable) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.apply_lookup_table_assign(self, ct_in, lut).unwrap()
+        })
+    }
+
+    /// Evaluates several lookup tables on the same ciphertext, returning one ciphertext per table.
+    ///
+    /// The key switch of the input is computed once and shared by all the bootstraps, so that
+    /// evaluating `n` tables costs a single key switch and `n` bootstraps.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Generate the lookup tables for the functions x -> x / 2 and x -> x % 2
+    /// let luts = [
+    ///     sks.generate_lookup_table(|x| x / 2),
+    ///     sks.generate_lookup_table(|x| x % 2),
+    /// ];
+    /// let cts_res = sks.apply_lookup_table_multi(&ct, &luts);
+    ///
+    /// assert_eq!(cks.decrypt(&cts_res[0]), msg / 2);
+    /// assert_eq!(cks.decrypt(&cts_res[1]), msg % 2);
+    /// ```
+    pub fn apply_lookup_table_multi(
+        &self,
+        ct_in: &Ciphertext,
+        luts: &[LookupTable],
+    ) -> Vec<Ciphertext> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.apply_lookup_table_multi(self, ct_in, luts).unwrap()
+        })
+    }
+
     /// Computes a keyswitch and a bootstrap, returning a new ciphertext with empty
     /// carry bits.
     ///
@@ -228,6 +508,98 @@ impl ServerKey {
         })
     }
 
+    /// Computes a keyswitch and a programmable bootstrap on each ciphertext of a batch, using the
+    /// accumulator of the same index.
+    ///
+    /// The bootstraps are spread over the rayon thread pool, each thread using the buffers of its
+    /// own `ShortintEngine`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the numbers of ciphertexts and accumulators differ.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msgs: [u64; 3] = [1, 2, 3];
+    /// let cts: Vec<_> = msgs.iter().map(|&msg| cks.encrypt(msg)).collect();
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    ///
+    /// // Generate the accumulators for the functions x -> x + i mod 2^2
+    /// let accs: Vec<_> = (0..3)
+    ///     .map(|i| sks.generate_accumulator(|x| (x + i) % modulus))
+    ///     .collect();
+    /// let cts_res = sks.keyswitch_programmable_bootstrap_many(&cts, &accs);
+    ///
+    /// for (i, ct_res) in cts_res.iter().enumerate() {
+    ///     let dec = cks.decrypt(ct_res);
+    ///     assert_eq!(dec, (msgs[i] + i as u64) % modulus);
+    /// }
+    /// ```
+    pub fn keyswitch_programmable_bootstrap_many(
+        &self,
+        cts_in: &[Ciphertext],
+        accs: &[GlweCiphertext64],
+    ) -> Vec<Ciphertext> {
+        assert_eq!(
+            cts_in.len(),
+            accs.len(),
+            "Each ciphertext needs its own accumulator"
+        );
+        cts_in
+            .par_iter()
+            .zip(accs.par_iter())
+            .map(|(ct_in, acc)| self.keyswitch_programmable_bootstrap(ct_in, acc))
+            .collect()
+    }
+
+    /// Applies the same univariate function to each ciphertext of a batch.
+    ///
+    /// The accumulator is generated once, and the bootstraps are spread over the rayon thread
+    /// pool, each thread using the buffers of its own `ShortintEngine`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msgs: [u64; 3] = [1, 2, 3];
+    /// let cts: Vec<_> = msgs.iter().map(|&msg| cks.encrypt(msg)).collect();
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    ///
+    /// // Apply the function f: x -> x^3 mod 2^2 to all the ciphertexts
+    /// let cts_res = sks.apply_lookup_table_many(&cts, |x| x * x * x % modulus);
+    ///
+    /// for (msg, ct_res) in msgs.iter().zip(cts_res.iter()) {
+    ///     let dec = cks.decrypt(ct_res);
+    ///     assert_eq!(dec, msg * msg * msg % modulus);
+    /// }
+    /// ```
+    pub fn apply_lookup_table_many<F>(&self, cts_in: &[Ciphertext], f: F) -> Vec<Ciphertext>
+    where
+        F: Fn(u64) -> u64 + Sync,
+    {
+        let acc = self.generate_accumulator(&f);
+        cts_in
+            .par_iter()
+            .map(|ct_in| {
+                let mut ct_res = self.keyswitch_programmable_bootstrap(ct_in, &acc);
+                ct_res.degree = ct_in.degree.after_pbs(|x| f(x as u64) as usize);
+                ct_res
+            })
+            .collect()
+    }
+
     /// Generic programmable bootstrap where messages are concatenated
     /// into one ciphertext to compute bivariate functions.
     /// This is used to apply many binary operations (comparisons, multiplications, division).
@@ -264,9 +636,145 @@ impl ServerKey {
 
     /// Verifies if a bivariate functional pbs can be applied on ct_left and ct_right.
     pub fn is_functional_bivariate_pbs_possible(&self, ct1: &Ciphertext, ct2: &Ciphertext) -> bool {
+        self.check_functional_bivariate_pbs_possible(ct1, ct2)
+            .is_ok()
+    }
+
+    pub(crate) fn check_functional_bivariate_pbs_possible(
+        &self,
+        ct1: &Ciphertext,
+        ct2: &Ciphertext,
+    ) -> Result<(), CheckError> {
         //product of the degree
         let final_degree = ct1.degree.0 * (ct2.degree.0 + 1) + ct2.degree.0;
-        final_degree < ct1.carry_modulus.0 * ct1.message_modulus.0
+        if final_degree >= ct1.carry_modulus.0 * ct1.message_modulus.0 {
+            return Err(CheckError::CarryFull);
+        }
+        // ct1 is shifted to the carry bits before adding ct2, as in the pbs itself
+        self.max_noise_level
+            .validate(ct1.noise_level * (ct2.degree.0 + 1) + ct2.noise_level)
+    }
+
+    /// Checks that `ct` was encrypted with the parameters of this key.
+    ///
+    /// The moduli of the ciphertext may differ from the ones of the key, as long as they are the
+    /// ones given by [ClientKey::encrypt_with_message_modulus]: the message is encoded with the
+    /// delta of the key, and the carry modulus is the largest one fitting in the plaintext space
+    /// of the key, e.g. 5 for a message modulus of 3 in a plaintext space of 16. A ciphertext
+    /// encrypted under another key with the same parameters can not be detected.
+    pub(crate) fn check_ciphertext_compatible(
+        &self,
+        ct: &Ciphertext,
+    ) -> Result<(), ValidationError> {
+        let plaintext_space = self.message_modulus.0 * self.carry_modulus.0;
+        let message_modulus = ct.message_modulus.0;
+        if message_modulus == 0
+            || message_modulus > plaintext_space
+            || ct.carry_modulus.0 != plaintext_space / message_modulus
+        {
+            return Err(ValidationError::ParameterMismatch);
+        }
+        if ct.ct.lwe_dimension() != self.key_switching_key.input_lwe_dimension() {
+            return Err(ValidationError::DimensionMismatch);
+        }
+        Ok(())
+    }
+
+    /// Verifies that a ciphertext received from an untrusted party can be used with this key.
+    ///
+    /// The moduli and the LWE dimension of the ciphertext have to match the ones of the key, or
+    /// the ones of a block encrypted with [ClientKey::encrypt_with_message_modulus], its degree
+    /// has to fit in the plaintext space and its noise level must not exceed the maximum.
+    ///
+    /// The degree can only be checked to be consistent: a ciphertext holding a larger value
+    /// than its degree can not be detected, see [ServerKey::deserialize_ciphertext] to not rely on
+    /// it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::ciphertext::Degree;
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use concrete_shortint::server_key::ValidationError;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let mut ct = cks.encrypt(3);
+    /// assert!(sks.validate(&ct).is_ok());
+    ///
+    /// // The plaintext space only holds 16 values
+    /// ct.degree = Degree(16);
+    /// assert!(matches!(
+    ///     sks.validate(&ct),
+    ///     Err(ValidationError::DegreeTooHigh)
+    /// ));
+    /// ```
+    pub fn validate(&self, ct: &Ciphertext) -> Result<(), ValidationError> {
+        self.check_ciphertext_compatible(ct)?;
+        if ct.degree.0 >= self.message_modulus.0 * self.carry_modulus.0 {
+            return Err(ValidationError::DegreeTooHigh);
+        }
+        if ct.noise_level.0 > self.max_noise_level.0 {
+            return Err(ValidationError::NoiseTooHigh);
+        }
+        Ok(())
+    }
+
+    /// Deserializes a ciphertext received from an untrusted party, and validates it against this
+    /// key with [ServerKey::validate].
+    ///
+    /// As the degree sent along with the ciphertext can not be trusted, it is replaced by the
+    /// largest value of the plaintext space, carries included. Clearing the carries with
+    /// [ServerKey::message_extract] brings it back to the largest message, and the `smart_*`
+    /// operations do it when needed. The noise level is also raised to
+    /// [NoiseLevel::NOMINAL] if it is lower.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bincode::Options;
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// // Serialization on the client side:
+    /// let ct = cks.encrypt(3);
+    /// let data = bincode::options().serialize(&ct).unwrap();
+    ///
+    /// // Deserialization on the server side:
+    /// let mut deserializer = bincode::Deserializer::from_slice(&data, bincode::options());
+    /// let mut ct = sks.deserialize_ciphertext(&mut deserializer).unwrap();
+    /// assert_eq!(ct.degree.0, 15);
+    ///
+    /// sks.message_extract_assign(&mut ct);
+    /// assert_eq!(ct.degree.0, 3);
+    /// assert_eq!(cks.decrypt(&ct), 3);
+    /// ```
+    pub fn deserialize_ciphertext<'de, D>(&self, deserializer: D) -> Result<Ciphertext, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut ct = Ciphertext::deserialize(deserializer)?;
+        self.validate(&ct).map_err(serde::de::Error::custom)?;
+        ct.degree = Degree(self.message_modulus.0 * self.carry_modulus.0 - 1);
+        ct.noise_level = cmp::max(ct.noise_level, NoiseLevel::NOMINAL);
+        Ok(ct)
+    }
+
+    /// Checks that a univariate pbs can be applied on `ct`, given the degree of its result.
+    pub(crate) fn check_univariate_pbs_possible(
+        &self,
+        ct: &Ciphertext,
+        degree_after_pbs: Degree,
+    ) -> Result<(), CheckError> {
+        if degree_after_pbs.0 > self.max_degree.0 {
+            return Err(CheckError::CarryFull);
+        }
+        self.max_noise_level.validate(ct.noise_level)
     }
 
     /// Replace the input encrypted message by the value of its carry buffer.
@@ -421,6 +929,44 @@ impl ServerKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.message_extract(self, ct).unwrap())
     }
 
+    /// Extracts both the message and the carry of a ciphertext, returned as two new ciphertexts
+    /// with cleared carry buffers.
+    ///
+    /// This gives the same results as [ServerKey::message_extract] and
+    /// [ServerKey::carry_extract], but both bootstraps share the key switch of the input.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let clear = 9;
+    ///
+    /// // Encrypt a message
+    /// let ct = cks.unchecked_encrypt(clear);
+    ///
+    /// // |       ct        |
+    /// // | carry | message |
+    /// // |-------|---------|
+    /// // |  1 0  |   0 1   |
+    ///
+    /// // Compute homomorphically the message and the carry extraction
+    /// let (ct_message, ct_carry) = sks.message_and_carry_extract(&ct);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(1, cks.decrypt(&ct_message));
+    /// assert_eq!(2, cks.decrypt(&ct_carry));
+    /// ```
+    pub fn message_and_carry_extract(&self, ct: &Ciphertext) -> (Ciphertext, Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.message_and_carry_extract(self, ct).unwrap()
+        })
+    }
+
     /// Computes a trivial shortint from a given value.
     ///
     /// # Example
@@ -461,6 +1007,8 @@ pub(super) struct SerializableServerKey {
     pub carry_modulus: CarryModulus,
     // Maximum number of operations that can be done before emptying the operation buffer
     pub max_degree: MaxDegree,
+    // Maximum noise level that can be reached before bootstrapping the ciphertext
+    pub max_noise_level: MaxNoiseLevel,
 }
 
 impl Serialize for ServerKey {
@@ -484,6 +1032,7 @@ impl Serialize for ServerKey {
             message_modulus: self.message_modulus,
             carry_modulus: self.carry_modulus,
             max_degree: self.max_degree,
+            max_noise_level: self.max_noise_level,
         }
         .serialize(serializer)
     }
@@ -509,6 +1058,7 @@ impl<'de> Deserialize<'de> for ServerKey {
             message_modulus: thing.message_modulus,
             carry_modulus: thing.carry_modulus,
             max_degree: thing.max_degree,
+            max_noise_level: thing.max_noise_level,
         })
     }
 }
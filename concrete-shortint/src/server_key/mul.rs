@@ -2,7 +2,6 @@ use super::ServerKey;
 use crate::ciphertext::Degree;
 use crate::engine::ShortintEngine;
 use crate::server_key::CheckError;
-use crate::server_key::CheckError::CarryFull;
 use crate::Ciphertext;
 
 impl ServerKey {
@@ -162,7 +161,15 @@ impl ServerKey {
     /// assert_eq!(true, res);
     /// ```
     pub fn is_mul_possible(&self, ct1: &Ciphertext, ct2: &Ciphertext) -> bool {
-        self.is_functional_bivariate_pbs_possible(ct1, ct2)
+        self.check_mul_possible(ct1, ct2).is_ok()
+    }
+
+    pub(crate) fn check_mul_possible(
+        &self,
+        ct1: &Ciphertext,
+        ct2: &Ciphertext,
+    ) -> Result<(), CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct1, ct2)
     }
 
     /// Multiplies two ciphertexts together with checks.
@@ -171,7 +178,7 @@ impl ServerKey {
     /// message_modulus.
     ///
     /// If the operation can be performed, a _new_ ciphertext with the result is returned.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -200,12 +207,9 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_mul_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_mul_lsb(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        self.check_mul_possible(ct_left, ct_right)?;
+        let ct_result = self.unchecked_mul_lsb(ct_left, ct_right);
+        Ok(ct_result)
     }
 
     /// Multiplies two ciphertexts together with checks.
@@ -215,7 +219,7 @@ impl ServerKey {
     ///
     /// If the operation can be performed, the result is assigned to the first ciphertext given
     /// as a parameter.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -243,12 +247,9 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
-        if self.is_mul_possible(ct_left, ct_right) {
-            self.unchecked_mul_lsb_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_mul_possible(ct_left, ct_right)?;
+        self.unchecked_mul_lsb_assign(ct_left, ct_right);
+        Ok(())
     }
 
     /// Multiplies two ciphertexts together without checks.
@@ -257,7 +258,7 @@ impl ServerKey {
     /// buffer.
     ///
     /// If the operation can be performed, a _new_ ciphertext with the result is returned.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -299,12 +300,9 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_mul_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_mul_msb(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        self.check_mul_possible(ct_left, ct_right)?;
+        let ct_result = self.unchecked_mul_msb(ct_left, ct_right);
+        Ok(ct_result)
     }
 
     /// Multiply two ciphertexts together using one bit of carry only.
@@ -396,10 +394,18 @@ impl ServerKey {
     /// assert_eq!(false, res);
     /// ```
     pub fn is_mul_small_carry_possible(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> bool {
-        // Check if an addition is possible
-        let b1 = self.is_add_possible(ct_left, ct_right);
-        let b2 = self.is_sub_possible(ct_left, ct_right);
-        b1 & b2
+        self.check_mul_small_carry_possible(ct_left, ct_right)
+            .is_ok()
+    }
+
+    pub(crate) fn check_mul_small_carry_possible(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> Result<(), CheckError> {
+        // Check if an addition and a subtraction are possible
+        self.check_add_possible(ct_left, ct_right)?;
+        self.check_sub_possible(ct_left, ct_right)
     }
 
     /// Computes homomorphically a multiplication between two ciphertexts encrypting integer values.
@@ -407,7 +413,8 @@ impl ServerKey {
     /// The operation is done using a small carry buffer.
     ///
     /// If the operation can be performed, a _new_ ciphertext with the result of the
-    /// multiplication is returned. Otherwise [CheckError::CarryFull] is returned.
+    /// multiplication is returned. Otherwise [CheckError::CarryFull] or
+    /// [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -440,13 +447,10 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &mut Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_mul_small_carry_possible(ct_left, ct_right) {
-            let mut ct_result = self.unchecked_mul_lsb_small_carry(ct_left, ct_right);
-            ct_result.degree = Degree(ct_left.degree.0 * 2);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        self.check_mul_small_carry_possible(ct_left, ct_right)?;
+        let mut ct_result = self.unchecked_mul_lsb_small_carry(ct_left, ct_right);
+        ct_result.degree = Degree(ct_left.degree.0 * 2);
+        Ok(ct_result)
     }
 
     /// Multiplies two ciphertexts.
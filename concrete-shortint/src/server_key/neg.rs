@@ -98,6 +98,10 @@ impl ServerKey {
     /// assert_eq!(can_be_negated, true);
     /// ```
     pub fn is_neg_possible(&self, ct: &Ciphertext) -> bool {
+        self.check_neg_possible(ct).is_ok()
+    }
+
+    pub(crate) fn check_neg_possible(&self, ct: &Ciphertext) -> Result<(), CheckError> {
         // z = ceil( degree / 2^p ) x 2^p
         let msg_mod = self.message_modulus.0;
         let mut z = (ct.degree.0 + msg_mod - 1) / msg_mod;
@@ -106,7 +110,10 @@ impl ServerKey {
         // counter = z / (2^p-1)
         let counter = z / (self.message_modulus.0 - 1);
 
-        counter <= self.max_degree.0
+        if counter > self.max_degree.0 {
+            return Err(CarryFull);
+        }
+        Ok(())
     }
 
     /// Computes homomorphically a negation of a ciphertext.
@@ -0,0 +1,952 @@
+use super::ServerKey;
+use crate::engine::{rotate_left, rotate_right, ShortintEngine};
+use crate::server_key::CheckError;
+use crate::Ciphertext;
+
+impl ServerKey {
+    /// Computes homomorphically a left rotation of the bits by a clear amount without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_rotate_left(&ct, 1);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(6, res);
+    /// ```
+    pub fn unchecked_scalar_rotate_left(&self, ct: &Ciphertext, amount: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_rotate_left(self, ct, amount)
+                .unwrap()
+        })
+    }
+
+    /// Computes homomorphically a left rotation of the bits by a clear amount without checks.
+    ///
+    /// The result is assigned in the `ct` ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    pub fn unchecked_scalar_rotate_left_assign(&self, ct: &mut Ciphertext, amount: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_rotate_left_assign(self, ct, amount)
+                .unwrap()
+        })
+    }
+
+    /// Verifies if a left rotation of the bits by a clear amount can be computed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let ct = cks.encrypt(3);
+    ///
+    /// let res = sks.is_scalar_rotate_left_possible(&ct, 1);
+    /// assert_eq!(true, res);
+    /// ```
+    pub fn is_scalar_rotate_left_possible(&self, ct: &Ciphertext, amount: u8) -> bool {
+        self.check_scalar_rotate_left_possible(ct, amount).is_ok()
+    }
+
+    pub(crate) fn check_scalar_rotate_left_possible(
+        &self,
+        ct: &Ciphertext,
+        amount: u8,
+    ) -> Result<(), CheckError> {
+        let message_modulus = self.message_modulus.0 as u64;
+        assert!(message_modulus.is_power_of_two());
+
+        let degree_after_pbs = ct
+            .degree
+            .after_pbs(|value| rotate_left(value as u64, amount as u64, message_modulus) as usize);
+        self.check_univariate_pbs_possible(ct, degree_after_pbs)
+    }
+
+    /// Computes homomorphically a left rotation of the bits by a clear amount.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.checked_scalar_rotate_left(&ct, 1);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(6, res);
+    /// ```
+    pub fn checked_scalar_rotate_left(
+        &self,
+        ct: &Ciphertext,
+        amount: u8,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_scalar_rotate_left_possible(ct, amount)?;
+        let ct_result = self.unchecked_scalar_rotate_left(ct, amount);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically a left rotation of the bits by a clear amount.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct` is
+    /// not modified.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let res = sks.checked_scalar_rotate_left_assign(&mut ct, 1);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(6, res);
+    /// ```
+    pub fn checked_scalar_rotate_left_assign(
+        &self,
+        ct: &mut Ciphertext,
+        amount: u8,
+    ) -> Result<(), CheckError> {
+        self.check_scalar_rotate_left_possible(ct, amount)?;
+        self.unchecked_scalar_rotate_left_assign(ct, amount);
+        Ok(())
+    }
+
+    /// Computes homomorphically a left rotation of the bits by a clear amount.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_rotate_left(&mut ct, 1);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(6, res);
+    /// ```
+    pub fn smart_scalar_rotate_left(&self, ct: &mut Ciphertext, amount: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_rotate_left(self, ct, amount).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a left rotation of the bits by a clear amount.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is stored in the `ct` ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// sks.smart_scalar_rotate_left_assign(&mut ct, 1);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(6, res);
+    /// ```
+    pub fn smart_scalar_rotate_left_assign(&self, ct: &mut Ciphertext, amount: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .smart_scalar_rotate_left_assign(self, ct, amount)
+                .unwrap()
+        })
+    }
+
+    /// Computes homomorphically a right rotation of the bits by a clear amount without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_scalar_rotate_right(&ct, 1);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn unchecked_scalar_rotate_right(&self, ct: &Ciphertext, amount: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_rotate_right(self, ct, amount)
+                .unwrap()
+        })
+    }
+
+    /// Computes homomorphically a right rotation of the bits by a clear amount without checks.
+    ///
+    /// The result is assigned in the `ct` ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    pub fn unchecked_scalar_rotate_right_assign(&self, ct: &mut Ciphertext, amount: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_scalar_rotate_right_assign(self, ct, amount)
+                .unwrap()
+        })
+    }
+
+    /// Verifies if a right rotation of the bits by a clear amount can be computed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let ct = cks.encrypt(3);
+    ///
+    /// let res = sks.is_scalar_rotate_right_possible(&ct, 1);
+    /// assert_eq!(true, res);
+    /// ```
+    pub fn is_scalar_rotate_right_possible(&self, ct: &Ciphertext, amount: u8) -> bool {
+        self.check_scalar_rotate_right_possible(ct, amount).is_ok()
+    }
+
+    pub(crate) fn check_scalar_rotate_right_possible(
+        &self,
+        ct: &Ciphertext,
+        amount: u8,
+    ) -> Result<(), CheckError> {
+        let message_modulus = self.message_modulus.0 as u64;
+        assert!(message_modulus.is_power_of_two());
+
+        let degree_after_pbs = ct
+            .degree
+            .after_pbs(|value| rotate_right(value as u64, amount as u64, message_modulus) as usize);
+        self.check_univariate_pbs_possible(ct, degree_after_pbs)
+    }
+
+    /// Computes homomorphically a right rotation of the bits by a clear amount.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.checked_scalar_rotate_right(&ct, 1);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn checked_scalar_rotate_right(
+        &self,
+        ct: &Ciphertext,
+        amount: u8,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_scalar_rotate_right_possible(ct, amount)?;
+        let ct_result = self.unchecked_scalar_rotate_right(ct, amount);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically a right rotation of the bits by a clear amount.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct` is
+    /// not modified.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let res = sks.checked_scalar_rotate_right_assign(&mut ct, 1);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn checked_scalar_rotate_right_assign(
+        &self,
+        ct: &mut Ciphertext,
+        amount: u8,
+    ) -> Result<(), CheckError> {
+        self.check_scalar_rotate_right_possible(ct, amount)?;
+        self.unchecked_scalar_rotate_right_assign(ct, amount);
+        Ok(())
+    }
+
+    /// Computes homomorphically a right rotation of the bits by a clear amount.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.smart_scalar_rotate_right(&mut ct, 1);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn smart_scalar_rotate_right(&self, ct: &mut Ciphertext, amount: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_scalar_rotate_right(self, ct, amount).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a right rotation of the bits by a clear amount.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffer is full,
+    /// then it is automatically cleared to allow the operation.
+    ///
+    /// The result is stored in the `ct` ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let mut ct = cks.encrypt(msg);
+    ///
+    /// sks.smart_scalar_rotate_right_assign(&mut ct, 1);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn smart_scalar_rotate_right_assign(&self, ct: &mut Ciphertext, amount: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .smart_scalar_rotate_right_assign(self, ct, amount)
+                .unwrap()
+        })
+    }
+
+    /// Computes homomorphically a left rotation of the bits by an encrypted amount without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.unchecked_rotate_left(&ct, &ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(6, res);
+    /// ```
+    pub fn unchecked_rotate_left(&self, ct: &Ciphertext, ct_amount: &Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_rotate_left(self, ct, ct_amount).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a left rotation of the bits by an encrypted amount without checks.
+    ///
+    /// The result is assigned in the `ct` ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// sks.unchecked_rotate_left_assign(&mut ct, &ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(6, res);
+    /// ```
+    pub fn unchecked_rotate_left_assign(&self, ct: &mut Ciphertext, ct_amount: &Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_rotate_left_assign(self, ct, ct_amount)
+                .unwrap()
+        })
+    }
+
+    /// Computes homomorphically a left rotation of the message bits by an encrypted amount.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.checked_rotate_left(&ct, &ct_amount);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(6, res);
+    /// ```
+    pub fn checked_rotate_left(
+        &self,
+        ct: &Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct, ct_amount)?;
+        let ct_result = self.unchecked_rotate_left(ct, ct_amount);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically a left rotation of the message bits by an encrypted amount.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct` is
+    /// not modified.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let res = sks.checked_rotate_left_assign(&mut ct, &ct_amount);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(6, res);
+    /// ```
+    pub fn checked_rotate_left_assign(
+        &self,
+        ct: &mut Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> Result<(), CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct, ct_amount)?;
+        self.unchecked_rotate_left_assign(ct, ct_amount);
+        Ok(())
+    }
+
+    /// Computes homomorphically a left rotation of the message bits by an encrypted amount.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let mut ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.smart_rotate_left(&mut ct, &mut ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(6, res);
+    /// ```
+    pub fn smart_rotate_left(&self, ct: &mut Ciphertext, ct_amount: &mut Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_rotate_left(self, ct, ct_amount).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a left rotation of the message bits by an encrypted amount.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let mut ct_amount = cks.encrypt(amount);
+    ///
+    /// sks.smart_rotate_left_assign(&mut ct, &mut ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(6, res);
+    /// ```
+    pub fn smart_rotate_left_assign(&self, ct: &mut Ciphertext, ct_amount: &mut Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .smart_rotate_left_assign(self, ct, ct_amount)
+                .unwrap()
+        })
+    }
+
+    /// Computes homomorphically a right rotation of the bits by an encrypted amount without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.unchecked_rotate_right(&ct, &ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn unchecked_rotate_right(&self, ct: &Ciphertext, ct_amount: &Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_rotate_right(self, ct, ct_amount).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a right rotation of the bits by an encrypted amount without checks.
+    ///
+    /// The result is assigned in the `ct` ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// sks.unchecked_rotate_right_assign(&mut ct, &ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn unchecked_rotate_right_assign(&self, ct: &mut Ciphertext, ct_amount: &Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_rotate_right_assign(self, ct, ct_amount)
+                .unwrap()
+        })
+    }
+
+    /// Computes homomorphically a right rotation of the message bits by an encrypted amount.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.checked_rotate_right(&ct, &ct_amount);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn checked_rotate_right(
+        &self,
+        ct: &Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct, ct_amount)?;
+        let ct_result = self.unchecked_rotate_right(ct, ct_amount);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically a right rotation of the message bits by an encrypted amount.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct` is
+    /// not modified.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let res = sks.checked_rotate_right_assign(&mut ct, &ct_amount);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn checked_rotate_right_assign(
+        &self,
+        ct: &mut Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> Result<(), CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct, ct_amount)?;
+        self.unchecked_rotate_right_assign(ct, ct_amount);
+        Ok(())
+    }
+
+    /// Computes homomorphically a right rotation of the message bits by an encrypted amount.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let mut ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.smart_rotate_right(&mut ct, &mut ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn smart_rotate_right(
+        &self,
+        ct: &mut Ciphertext,
+        ct_amount: &mut Ciphertext,
+    ) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_rotate_right(self, ct, ct_amount).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a right rotation of the message bits by an encrypted amount.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_3_CARRY_3;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_3_CARRY_3);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let mut ct_amount = cks.encrypt(amount);
+    ///
+    /// sks.smart_rotate_right_assign(&mut ct, &mut ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn smart_rotate_right_assign(&self, ct: &mut Ciphertext, ct_amount: &mut Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .smart_rotate_right_assign(self, ct, ct_amount)
+                .unwrap()
+        })
+    }
+}
@@ -90,9 +90,19 @@ impl ServerKey {
     /// assert_eq!(can_be_computed, true);
     /// ```
     pub fn is_scalar_add_possible(&self, ct: &Ciphertext, scalar: u8) -> bool {
-        let final_degree = scalar as usize + ct.degree.0;
+        self.check_scalar_add_possible(ct, scalar).is_ok()
+    }
 
-        final_degree <= self.max_degree.0
+    pub(crate) fn check_scalar_add_possible(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
+        let final_degree = scalar as usize + ct.degree.0;
+        if final_degree > self.max_degree.0 {
+            return Err(CarryFull);
+        }
+        Ok(())
     }
 
     /// Computes homomorphically an addition between a ciphertext and a scalar.
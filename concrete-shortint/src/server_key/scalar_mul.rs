@@ -86,16 +86,27 @@ impl ServerKey {
     /// assert_eq!(can_be_computed, true);
     /// ```
     pub fn is_scalar_mul_possible(&self, ct: &Ciphertext, scalar: u8) -> bool {
+        self.check_scalar_mul_possible(ct, scalar).is_ok()
+    }
+
+    pub(crate) fn check_scalar_mul_possible(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
         //scalar * ct.counter
         let final_degree = scalar as usize * ct.degree.0;
-
-        final_degree <= self.max_degree.0
+        if final_degree > self.max_degree.0 {
+            return Err(CarryFull);
+        }
+        self.max_noise_level
+            .validate(ct.noise_level * scalar as usize)
     }
 
     /// Computes homomorphically a multiplication of a ciphertext by a scalar.
     ///
     /// If the operation is possible, the result is returned in a _new_ ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// The operation is modulo the precision bits to the power of two.
     ///
@@ -127,18 +138,16 @@ impl ServerKey {
         scalar: u8,
     ) -> Result<Ciphertext, CheckError> {
         //If the ciphertext cannot be multiplied without exceeding the degree max
-        if self.is_scalar_mul_possible(ct, scalar) {
-            let ct_result = self.unchecked_scalar_mul(ct, scalar);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        self.check_scalar_mul_possible(ct, scalar)?;
+        let ct_result = self.unchecked_scalar_mul(ct, scalar);
+        Ok(ct_result)
     }
 
     /// Computes homomorphically a multiplication of a ciphertext by a scalar.
     ///
     /// If the operation is possible, the result is stored _in_ the input ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned and the ciphertext is not .
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned and the
+    /// ciphertext is not .
     ///
     /// The operation is modulo the precision bits to the power of two.
     ///
@@ -167,12 +176,9 @@ impl ServerKey {
         ct: &mut Ciphertext,
         scalar: u8,
     ) -> Result<(), CheckError> {
-        if self.is_scalar_mul_possible(ct, scalar) {
-            self.unchecked_scalar_mul_assign(ct, scalar);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_scalar_mul_possible(ct, scalar)?;
+        self.unchecked_scalar_mul_assign(ct, scalar);
+        Ok(())
     }
 
     /// Computes homomorphically a multiplication of a ciphertext by a scalar.
@@ -83,9 +83,20 @@ impl ServerKey {
     /// assert_eq!(can_be_computed, true);
     /// ```
     pub fn is_scalar_sub_possible(&self, ct: &Ciphertext, scalar: u8) -> bool {
+        self.check_scalar_sub_possible(ct, scalar).is_ok()
+    }
+
+    pub(crate) fn check_scalar_sub_possible(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
         let neg_scalar = u64::from(scalar.wrapping_neg()) % self.message_modulus.0 as u64;
         let final_degree = neg_scalar as usize + ct.degree.0;
-        final_degree <= self.max_degree.0
+        if final_degree > self.max_degree.0 {
+            return Err(CarryFull);
+        }
+        Ok(())
     }
 
     /// Computes homomorphically a subtraction of a ciphertext by a scalar.
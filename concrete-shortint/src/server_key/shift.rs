@@ -182,14 +182,26 @@ impl ServerKey {
     /// assert_eq!(false, res);
     /// ```
     pub fn is_scalar_left_shift_possible(&self, ct1: &Ciphertext, shift: u8) -> bool {
+        self.check_scalar_left_shift_possible(ct1, shift).is_ok()
+    }
+
+    pub(crate) fn check_scalar_left_shift_possible(
+        &self,
+        ct1: &Ciphertext,
+        shift: u8,
+    ) -> Result<(), CheckError> {
         let final_operation_count = ct1.degree.0 << shift as usize;
-        final_operation_count <= self.max_degree.0
+        if final_operation_count > self.max_degree.0 {
+            return Err(CarryFull);
+        }
+        self.max_noise_level
+            .validate(ct1.noise_level * (1 << shift))
     }
 
     /// Computes homomorphically a left shift of the bits.
     ///
     /// If the operation can be performed, a new ciphertext with the result is returned.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -235,12 +247,9 @@ impl ServerKey {
         ct: &Ciphertext,
         shift: u8,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_scalar_left_shift_possible(ct, shift) {
-            let ct_result = self.unchecked_scalar_left_shift(ct, shift);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        self.check_scalar_left_shift_possible(ct, shift)?;
+        let ct_result = self.unchecked_scalar_left_shift(ct, shift);
+        Ok(ct_result)
     }
 
     pub fn checked_scalar_left_shift_assign(
@@ -248,12 +257,9 @@ impl ServerKey {
         ct: &mut Ciphertext,
         shift: u8,
     ) -> Result<(), CheckError> {
-        if self.is_scalar_left_shift_possible(ct, shift) {
-            self.unchecked_scalar_left_shift_assign(ct, shift);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_scalar_left_shift_possible(ct, shift)?;
+        self.unchecked_scalar_left_shift_assign(ct, shift);
+        Ok(())
     }
 
     /// Computes homomorphically a left shift of the bits
@@ -303,4 +309,450 @@ impl ServerKey {
                 .unwrap()
         })
     }
+
+    /// Computes homomorphically a left shift of the bits by an encrypted amount without checks.
+    ///
+    /// The bits shifted out of the message space are dropped, so the carry of the result is
+    /// empty. This differs from [ServerKey::unchecked_scalar_left_shift], which moves them into
+    /// the carry.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.unchecked_shift_left(&ct, &ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(res, cks.decrypt_message_and_carry(&ct_res));
+    /// assert_eq!((msg << amount) % 4, res);
+    /// ```
+    pub fn unchecked_shift_left(&self, ct: &Ciphertext, ct_amount: &Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_shift_left(self, ct, ct_amount).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a left shift of the bits by an encrypted amount without checks.
+    ///
+    /// The bits shifted out of the message space are dropped, so the carry of the result is
+    /// empty. This differs from [ServerKey::unchecked_scalar_left_shift], which moves them into
+    /// the carry.
+    ///
+    /// The result is assigned in the `ct` ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// sks.unchecked_shift_left_assign(&mut ct, &ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(res, cks.decrypt_message_and_carry(&ct));
+    /// assert_eq!((msg << amount) % 4, res);
+    /// ```
+    pub fn unchecked_shift_left_assign(&self, ct: &mut Ciphertext, ct_amount: &Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_shift_left_assign(self, ct, ct_amount)
+                .unwrap()
+        })
+    }
+
+    /// Computes homomorphically a left shift of the message bits by an encrypted amount.
+    ///
+    /// The bits shifted out of the message space are dropped, so the carry of the result is
+    /// empty. This differs from [ServerKey::unchecked_scalar_left_shift], which moves them into
+    /// the carry.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.checked_shift_left(&ct, &ct_amount);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!((msg << amount) % 4, res);
+    /// ```
+    pub fn checked_shift_left(
+        &self,
+        ct: &Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct, ct_amount)?;
+        let ct_result = self.unchecked_shift_left(ct, ct_amount);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically a left shift of the message bits by an encrypted amount.
+    ///
+    /// The bits shifted out of the message space are dropped, so the carry of the result is
+    /// empty. This differs from [ServerKey::unchecked_scalar_left_shift], which moves them into
+    /// the carry.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct` is
+    /// not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let res = sks.checked_shift_left_assign(&mut ct, &ct_amount);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!((msg << amount) % 4, res);
+    /// ```
+    pub fn checked_shift_left_assign(
+        &self,
+        ct: &mut Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> Result<(), CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct, ct_amount)?;
+        self.unchecked_shift_left_assign(ct, ct_amount);
+        Ok(())
+    }
+
+    /// Computes homomorphically a left shift of the message bits by an encrypted amount.
+    ///
+    /// The bits shifted out of the message space are dropped, so the carry of the result is
+    /// empty. This differs from [ServerKey::unchecked_scalar_left_shift], which moves them into
+    /// the carry.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let mut ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.smart_shift_left(&mut ct, &mut ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!((msg << amount) % 4, res);
+    /// ```
+    pub fn smart_shift_left(&self, ct: &mut Ciphertext, ct_amount: &mut Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_shift_left(self, ct, ct_amount).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a left shift of the message bits by an encrypted amount.
+    ///
+    /// The bits shifted out of the message space are dropped, so the carry of the result is
+    /// empty. This differs from [ServerKey::unchecked_scalar_left_shift], which moves them into
+    /// the carry.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 3;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let mut ct_amount = cks.encrypt(amount);
+    ///
+    /// sks.smart_shift_left_assign(&mut ct, &mut ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!((msg << amount) % 4, res);
+    /// ```
+    pub fn smart_shift_left_assign(&self, ct: &mut Ciphertext, ct_amount: &mut Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_shift_left_assign(self, ct, ct_amount).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a right shift of the bits by an encrypted amount without checks.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.unchecked_shift_right(&ct, &ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg >> amount, res);
+    /// ```
+    pub fn unchecked_shift_right(&self, ct: &Ciphertext, ct_amount: &Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_shift_right(self, ct, ct_amount).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a right shift of the bits by an encrypted amount without checks.
+    ///
+    /// The result is assigned in the `ct` ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// sks.unchecked_shift_right_assign(&mut ct, &ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg >> amount, res);
+    /// ```
+    pub fn unchecked_shift_right_assign(&self, ct: &mut Ciphertext, ct_amount: &Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .unchecked_shift_right_assign(self, ct, ct_amount)
+                .unwrap()
+        })
+    }
+
+    /// Computes homomorphically a right shift of the message bits by an encrypted amount.
+    ///
+    /// If the operation can be performed, the result is returned in a _new_ ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.checked_shift_right(&ct, &ct_amount);
+    /// assert!(ct_res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res.unwrap());
+    /// assert_eq!(msg >> amount, res);
+    /// ```
+    pub fn checked_shift_right(
+        &self,
+        ct: &Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> Result<Ciphertext, CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct, ct_amount)?;
+        let ct_result = self.unchecked_shift_right(ct, ct_amount);
+        Ok(ct_result)
+    }
+
+    /// Computes homomorphically a right shift of the message bits by an encrypted amount.
+    ///
+    /// If the operation can be performed, the result is stored in the `ct` ciphertext.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct` is
+    /// not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let ct_amount = cks.encrypt(amount);
+    ///
+    /// let res = sks.checked_shift_right_assign(&mut ct, &ct_amount);
+    /// assert!(res.is_ok());
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg >> amount, res);
+    /// ```
+    pub fn checked_shift_right_assign(
+        &self,
+        ct: &mut Ciphertext,
+        ct_amount: &Ciphertext,
+    ) -> Result<(), CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct, ct_amount)?;
+        self.unchecked_shift_right_assign(ct, ct_amount);
+        Ok(())
+    }
+
+    /// Computes homomorphically a right shift of the message bits by an encrypted amount.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let mut ct_amount = cks.encrypt(amount);
+    ///
+    /// let ct_res = sks.smart_shift_right(&mut ct, &mut ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg >> amount, res);
+    /// ```
+    pub fn smart_shift_right(&self, ct: &mut Ciphertext, ct_amount: &mut Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.smart_shift_right(self, ct, ct_amount).unwrap()
+        })
+    }
+
+    /// Computes homomorphically a right shift of the message bits by an encrypted amount.
+    ///
+    /// This checks that the operation is possible. In the case where the carry buffers are full,
+    /// then they are automatically cleared to allow the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_shortint::gen_keys;
+    /// use concrete_shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2);
+    ///
+    /// let msg = 2;
+    /// let amount = 1;
+    ///
+    /// // Encrypt the message and the amount:
+    /// let mut ct = cks.encrypt(msg);
+    /// let mut ct_amount = cks.encrypt(amount);
+    ///
+    /// sks.smart_shift_right_assign(&mut ct, &mut ct_amount);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct);
+    /// assert_eq!(msg >> amount, res);
+    /// ```
+    pub fn smart_shift_right_assign(&self, ct: &mut Ciphertext, ct_amount: &mut Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .smart_shift_right_assign(self, ct, ct_amount)
+                .unwrap()
+        })
+    }
 }
@@ -96,20 +96,31 @@ impl ServerKey {
     /// assert_eq!(true, can_be_subtracted);
     /// ```
     pub fn is_sub_possible(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> bool {
+        self.check_sub_possible(ct_left, ct_right).is_ok()
+    }
+
+    pub(crate) fn check_sub_possible(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> Result<(), CheckError> {
         // z = ceil( degree / 2^p ) x 2^p
         let msg_mod = self.message_modulus.0;
         let mut z = (ct_right.degree.0 + msg_mod - 1) / msg_mod;
         z = z.wrapping_mul(msg_mod);
 
         let final_operation_count = ct_left.degree.0 + z;
-
-        final_operation_count <= self.max_degree.0
+        if final_operation_count > self.max_degree.0 {
+            return Err(CarryFull);
+        }
+        self.max_noise_level
+            .validate(ct_left.noise_level + ct_right.noise_level)
     }
 
     /// Computes homomorphically a subtraction between two ciphertexts encrypting integer values.
     ///
     /// If the operation can be performed, the result is returned a _new_ ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned.
     ///
     /// # Example
     ///
@@ -138,18 +149,16 @@ impl ServerKey {
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
         // If the ciphertexts cannot be subtracted without exceeding the degree max
-        if self.is_sub_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_sub(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        self.check_sub_possible(ct_left, ct_right)?;
+        let ct_result = self.unchecked_sub(ct_left, ct_right);
+        Ok(ct_result)
     }
 
     /// Computes homomorphically a subtraction between two ciphertexts.
     ///
     /// If the operation can be performed, the result is stored in the `ct_left` ciphertext.
-    /// Otherwise [CheckError::CarryFull] is returned, and `ct_left` is not modified.
+    /// Otherwise [CheckError::CarryFull] or [CheckError::NoiseTooHigh] is returned, and `ct_left`
+    /// is not modified.
     ///
     /// # Example
     ///
@@ -178,12 +187,9 @@ impl ServerKey {
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
         // If the ciphertexts cannot be subtracted without exceeding the degree max
-        if self.is_sub_possible(ct_left, ct_right) {
-            self.unchecked_sub_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_sub_possible(ct_left, ct_right)?;
+        self.unchecked_sub_assign(ct_left, ct_right);
+        Ok(())
     }
 
     /// Computes homomorphically a subtraction between two ciphertexts.
@@ -1,5 +1,8 @@
+use crate::ciphertext::{Degree, NoiseLevel};
 use crate::keycache::KEY_CACHE;
 use crate::parameters::*;
+use crate::server_key::{CheckError, DivisionByZeroPolicy, ShortintError, ValidationError};
+use crate::{CompressedCiphertext, CompressedServerKey, ServerKey};
 use paste::paste;
 use rand::Rng;
 
@@ -92,10 +95,19 @@ macro_rules! create_parametrized_test_bivariate_pbs_compliant{
 create_parametrized_test!(shortint_encrypt_decrypt);
 create_parametrized_test!(shortint_encrypt_with_message_modulus_decrypt);
 create_parametrized_test!(shortint_encrypt_decrypt_without_padding);
+create_parametrized_test!(shortint_encrypt_compressed_decrypt);
+create_parametrized_test!(shortint_compressed_server_key);
+create_parametrized_test!(shortint_noise_level);
+create_parametrized_test!(shortint_smart_add_noise_level);
 create_parametrized_test!(shortint_keyswitch_bootstrap);
 create_parametrized_test!(shortint_keyswitch_programmable_bootstrap);
+create_parametrized_test!(shortint_keyswitch_programmable_bootstrap_many);
+create_parametrized_test!(shortint_apply_lookup_table_many);
+create_parametrized_test!(shortint_apply_lookup_table);
 create_parametrized_test!(shortint_carry_extract);
 create_parametrized_test!(shortint_message_extract);
+create_parametrized_test!(shortint_message_and_carry_extract);
+create_parametrized_test!(shortint_apply_lookup_table_multi);
 create_parametrized_test!(shortint_generate_accumulator);
 create_parametrized_test!(shortint_unchecked_add);
 create_parametrized_test!(shortint_smart_add);
@@ -140,6 +152,51 @@ create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_greater_
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_div);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_scalar_div);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mod);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_rem);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_rem);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_div_rem);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_div_rem_with_policy);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_shift_left);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_shift_right);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_shift_left);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_rotate_left);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_rotate_right);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_rotate_left);
+create_parametrized_test!(shortint_unchecked_scalar_rotate_left);
+create_parametrized_test!(shortint_unchecked_scalar_rotate_right);
+create_parametrized_test!(shortint_checked_scalar_rotate);
+create_parametrized_test!(shortint_smart_scalar_rotate);
+create_parametrized_test!(shortint_unchecked_scalar_bitand);
+create_parametrized_test!(shortint_unchecked_scalar_bitor);
+create_parametrized_test!(shortint_unchecked_scalar_bitxor);
+create_parametrized_test!(shortint_smart_scalar_bitand);
+create_parametrized_test!(shortint_smart_scalar_bitor);
+create_parametrized_test!(shortint_smart_scalar_bitxor);
+create_parametrized_test!(shortint_smart_scalar_max);
+create_parametrized_test!(shortint_checked_scalar_out_of_message);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_min);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_max);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_min);
+create_parametrized_test!(shortint_unchecked_scalar_min);
+create_parametrized_test!(shortint_unchecked_scalar_max);
+create_parametrized_test!(shortint_try_smart_add);
+create_parametrized_test!(shortint_try_checked_add);
+create_parametrized_test!(shortint_try_ciphertext_mismatch {
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_try_malformed_input {
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_try_crt_block {
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_div_rem_with_policy_crt_block {
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_validate_ciphertext {
+    PARAM_MESSAGE_2_CARRY_2
+});
+create_parametrized_test!(shortint_deserialize_ciphertext);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mul_lsb);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mul_msb);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_mul_msb);
@@ -216,6 +273,142 @@ fn shortint_encrypt_decrypt_without_padding(param: Parameters) {
     }
 }
 
+/// test encryption of compressed ciphertexts with the LWE client key
+fn shortint_encrypt_compressed_decrypt(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let compressed_ct = cks.encrypt_compressed(clear);
+
+        // the compressed ciphertext is smaller, and survives a serialization round trip
+        let serialized = bincode::serialize(&compressed_ct).unwrap();
+        let ct = compressed_ct.decompress();
+        assert!(serialized.len() < bincode::serialize(&ct).unwrap().len());
+        let compressed_ct: CompressedCiphertext = bincode::deserialize(&serialized).unwrap();
+
+        let ct = compressed_ct.decompress();
+        assert_eq!(ct.degree, cks.encrypt(clear).degree);
+
+        // decryption of ct
+        let dec = cks.decrypt(&ct);
+
+        // assert
+        assert_eq!(clear, dec);
+    }
+}
+
+/// test the decompression of the compressed server key
+fn shortint_compressed_server_key(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+    let compressed_sks = CompressedServerKey::new(cks);
+
+    // the compressed server key is smaller, and survives a serialization round trip
+    let serialized = bincode::serialize(&compressed_sks).unwrap();
+    assert!(serialized.len() < bincode::serialize(keys.server_key()).unwrap().len());
+    let compressed_sks: CompressedServerKey = bincode::deserialize(&serialized).unwrap();
+
+    let sks = ServerKey::from(compressed_sks);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of two integers
+        let ctxt_0 = cks.encrypt_compressed(clear_0).decompress();
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // add the two ciphertexts, then clean the carry with a bootstrap
+        let ct_res = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        let ct_res = sks.keyswitch_bootstrap(&ct_res);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!((clear_0 + clear_1) % modulus, dec_res);
+    }
+}
+
+fn shortint_noise_level(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let ctxt_0 = cks.encrypt(0);
+    let ctxt_1 = cks.encrypt(1);
+    assert_eq!(ctxt_0.noise_level, NoiseLevel::NOMINAL);
+    assert_eq!(sks.create_trivial(1).noise_level, NoiseLevel::ZERO);
+
+    // linear operations accumulate the noise
+    let ct_res = sks.unchecked_add(&ctxt_0, &ctxt_1);
+    assert_eq!(ct_res.noise_level, NoiseLevel(2));
+    let ct_res = sks.unchecked_scalar_mul(&ct_res, 3);
+    assert_eq!(ct_res.noise_level, NoiseLevel(6));
+    let ct_res = sks.unchecked_scalar_add(&ct_res, 1);
+    assert_eq!(ct_res.noise_level, NoiseLevel(6));
+
+    // a bootstrap resets it
+    let ct_res = sks.keyswitch_bootstrap(&ct_res);
+    assert_eq!(ct_res.noise_level, NoiseLevel::NOMINAL);
+
+    // a ciphertext with a low degree but too much noise can't be used
+    let mut ct_noisy = sks.create_trivial(0);
+    ct_noisy.noise_level = NoiseLevel(sks.max_noise_level.0);
+    assert!(!sks.is_add_possible(&ct_noisy, &ctxt_1));
+    assert!(matches!(
+        sks.checked_add(&ct_noisy, &ctxt_1),
+        Err(CheckError::NoiseTooHigh)
+    ));
+    assert!(matches!(
+        sks.checked_scalar_mul(&ct_noisy, 2),
+        Err(CheckError::NoiseTooHigh)
+    ));
+}
+
+fn shortint_smart_add_noise_level(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // the first ciphertext claims to have reached the maximum noise level
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        ctxt_0.noise_level = NoiseLevel(sks.max_noise_level.0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        // the smart addition bootstraps it before adding
+        let ct_res = sks.smart_add(&mut ctxt_0, &mut ctxt_1);
+        assert_eq!(ct_res.noise_level, NoiseLevel(2));
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!((clear_0 + clear_1) % modulus, dec_res);
+    }
+}
+
 fn shortint_keyswitch_bootstrap(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
@@ -276,6 +469,92 @@ fn shortint_keyswitch_programmable_bootstrap(param: Parameters) {
     }
 }
 
+fn shortint_keyswitch_programmable_bootstrap_many(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    let clears: Vec<u64> = (0..NB_TEST).map(|_| rng.gen::<u64>() % modulus).collect();
+
+    // encryption of the integers
+    let ctxts: Vec<_> = clears.iter().map(|&clear| cks.encrypt(clear)).collect();
+
+    // one accumulator per ciphertext, adding its index
+    let accs: Vec<_> = (0..NB_TEST as u64)
+        .map(|i| sks.generate_accumulator(|n| (n + i) % modulus))
+        .collect();
+    let cts_res = sks.keyswitch_programmable_bootstrap_many(&ctxts, &accs);
+
+    assert_eq!(cts_res.len(), NB_TEST);
+    for (i, (clear, ct_res)) in clears.iter().zip(cts_res.iter()).enumerate() {
+        // decryption of ct_res
+        let dec_res = cks.decrypt(ct_res);
+
+        // assert
+        assert_eq!((clear + i as u64) % modulus, dec_res);
+    }
+}
+
+fn shortint_apply_lookup_table_many(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    let clears: Vec<u64> = (0..NB_TEST).map(|_| rng.gen::<u64>() % modulus).collect();
+
+    // encryption of the integers
+    let ctxts: Vec<_> = clears.iter().map(|&clear| cks.encrypt(clear)).collect();
+
+    let f = |n: u64| (n * n) % modulus;
+    let cts_res = sks.apply_lookup_table_many(&ctxts, f);
+
+    assert_eq!(cts_res.len(), NB_TEST);
+    for (clear, ct_res) in clears.iter().zip(cts_res.iter()) {
+        // decryption of ct_res
+        let dec_res = cks.decrypt(ct_res);
+
+        // assert
+        assert_eq!(f(*clear), dec_res);
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+fn shortint_apply_lookup_table(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    let f = |n: u64| (n * n) % modulus;
+    let lut = sks.generate_lookup_table(f);
+    assert!(lut.degree.0 < cks.parameters.message_modulus.0);
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt = cks.encrypt(clear);
+
+        // the same lookup table is applied at each iteration
+        let ct_res = sks.apply_lookup_table(&ctxt, &lut);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(f(clear), dec_res);
+        assert_eq!(ct_res.degree, lut.degree);
+    }
+}
+
 /// test extraction of a carry
 fn shortint_carry_extract(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -339,6 +618,68 @@ fn shortint_message_extract(param: Parameters) {
     }
 }
 
+fn shortint_message_and_carry_extract(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus_sup = (param.message_modulus.0 * param.carry_modulus.0) as u64;
+
+    let modulus = param.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus_sup;
+
+        // encryption of an integer
+        let ctxt = cks.unchecked_encrypt(clear);
+
+        // fused extraction
+        let (ct_msg, ct_carry) = sks.message_and_carry_extract(&ctxt);
+
+        // same degrees as the separate extractions
+        assert_eq!(ct_msg.degree, sks.message_extract(&ctxt).degree);
+        assert_eq!(ct_carry.degree, sks.carry_extract(&ctxt).degree);
+
+        // assert
+        assert_eq!(clear % modulus, cks.decrypt_message_and_carry(&ct_msg));
+        assert_eq!(clear / modulus, cks.decrypt_message_and_carry(&ct_carry));
+    }
+}
+
+fn shortint_apply_lookup_table_multi(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0 as u64;
+
+    let luts = [
+        sks.generate_lookup_table(|x| x * x % modulus),
+        sks.generate_lookup_table(|x| (x + 1) % modulus),
+        sks.generate_lookup_table(|x| x / 2),
+    ];
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt = cks.encrypt(clear);
+
+        let cts_res = sks.apply_lookup_table_multi(&ctxt, &luts);
+        assert_eq!(cts_res.len(), luts.len());
+
+        // assert
+        assert_eq!(clear * clear % modulus, cks.decrypt(&cts_res[0]));
+        assert_eq!((clear + 1) % modulus, cks.decrypt(&cts_res[1]));
+        assert_eq!(clear / 2, cks.decrypt(&cts_res[2]));
+        for (ct_res, lut) in cts_res.iter().zip(luts.iter()) {
+            assert_eq!(ct_res.degree, lut.degree);
+        }
+    }
+}
+
 /// test multiplication with the LWE server key
 fn shortint_generate_accumulator(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
@@ -1204,8 +1545,8 @@ fn shortint_unchecked_mod(param: Parameters) {
     }
 }
 
-/// test LSB multiplication with the LWE server key
-fn shortint_unchecked_mul_lsb(param: Parameters) {
+/// test remainder with the LWE server key
+fn shortint_unchecked_rem(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
@@ -1215,7 +1556,7 @@ fn shortint_unchecked_mul_lsb(param: Parameters) {
 
     for _ in 0..NB_TEST {
         let clear_0 = rng.gen::<u64>() % modulus;
-        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_1 = (rng.gen::<u64>() % (modulus - 1)) + 1;
 
         // encryption of an integer
         let ctxt_0 = cks.encrypt(clear_0);
@@ -1223,19 +1564,19 @@ fn shortint_unchecked_mul_lsb(param: Parameters) {
         // encryption of an integer
         let ctxt_1 = cks.encrypt(clear_1);
 
-        // add the two ciphertexts
-        let ct_res = sks.unchecked_mul_lsb(&ctxt_0, &ctxt_1);
+        // compute the remainder
+        let ct_res = sks.unchecked_rem(&ctxt_0, &ctxt_1);
 
         // decryption of ct_res
         let dec_res = cks.decrypt(&ct_res);
 
         // assert
-        assert_eq!((clear_0 * clear_1) % modulus, dec_res);
+        assert_eq!(clear_0 % clear_1, dec_res);
     }
 }
 
-/// test MSB multiplication with the LWE server key
-fn shortint_unchecked_mul_msb(param: Parameters) {
+/// test smart remainder with the LWE server key
+fn shortint_smart_rem(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
@@ -1245,27 +1586,27 @@ fn shortint_unchecked_mul_msb(param: Parameters) {
 
     for _ in 0..NB_TEST {
         let clear_0 = rng.gen::<u64>() % modulus;
-        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_1 = (rng.gen::<u64>() % (modulus - 1)) + 1;
 
         // encryption of an integer
-        let ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_0 = cks.encrypt(clear_0);
 
         // encryption of an integer
-        let ctxt_1 = cks.encrypt(clear_1);
+        let mut ctxt_1 = cks.encrypt(clear_1);
 
-        // add the two ciphertexts
-        let ct_res = sks.unchecked_mul_msb(&ctxt_0, &ctxt_1);
+        // compute the remainder
+        let ct_res = sks.smart_rem(&mut ctxt_0, &mut ctxt_1);
 
         // decryption of ct_res
         let dec_res = cks.decrypt(&ct_res);
 
         // assert
-        assert_eq!((clear_0 * clear_1) / modulus, dec_res);
+        assert_eq!(clear_0 % clear_1, dec_res);
     }
 }
 
-/// test LSB multiplication with the LWE server key
-fn shortint_smart_mul_lsb(param: Parameters) {
+/// test combined division and remainder with the LWE server key
+fn shortint_unchecked_div_rem(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
@@ -1273,38 +1614,27 @@ fn shortint_smart_mul_lsb(param: Parameters) {
 
     let modulus = cks.parameters.message_modulus.0 as u64;
 
-    for _ in 0..10 {
+    for _ in 0..NB_TEST {
         let clear_0 = rng.gen::<u64>() % modulus;
-
-        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_1 = (rng.gen::<u64>() % (modulus - 1)) + 1;
 
         // encryption of an integer
-        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_0 = cks.encrypt(clear_0);
 
         // encryption of an integer
-        let mut ctxt_1 = cks.encrypt(clear_1);
-
-        // add the two ciphertexts
-        let mut ct_res = sks.smart_mul_lsb(&mut ctxt_0, &mut ctxt_1);
-
-        let mut clear = clear_0 * clear_1;
-
-        //add multiple times to raise the degree
-        for _ in 0..30 {
-            ct_res = sks.smart_mul_lsb(&mut ct_res, &mut ctxt_0);
-            clear = (clear * clear_0) % modulus;
+        let ctxt_1 = cks.encrypt(clear_1);
 
-            // decryption of ct_res
-            let dec_res = cks.decrypt(&ct_res);
+        // compute the quotient and the remainder
+        let (ct_q, ct_r) = sks.unchecked_div_rem(&ctxt_0, &ctxt_1);
 
-            // assert
-            assert_eq!(clear, dec_res);
-        }
+        // assert
+        assert_eq!(clear_0 / clear_1, cks.decrypt(&ct_q));
+        assert_eq!(clear_0 % clear_1, cks.decrypt(&ct_r));
     }
 }
 
-/// test MSB multiplication with the LWE server key
-fn shortint_smart_mul_msb(param: Parameters) {
+/// test the division by zero policies with the LWE server key
+fn shortint_unchecked_div_rem_with_policy(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
@@ -1312,42 +1642,72 @@ fn shortint_smart_mul_msb(param: Parameters) {
 
     let modulus = cks.parameters.message_modulus.0 as u64;
 
-    for _ in 0..10 {
+    for _ in 0..NB_TEST {
         let clear_0 = rng.gen::<u64>() % modulus;
-
-        let clear_1 = rng.gen::<u64>() % modulus;
+        // the divisor is zero one time out of two
+        let clear_1 = (rng.gen::<u64>() % (modulus - 1) + 1) * rng.gen_range(0..2);
 
         // encryption of an integer
-        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_0 = cks.encrypt(clear_0);
 
         // encryption of an integer
-        let mut ctxt_1 = cks.encrypt(clear_1);
-
-        // add the two ciphertexts
-        let mut ct_res = sks.smart_mul_msb(&mut ctxt_0, &mut ctxt_1);
-
-        let mut clear = (clear_0 * clear_1) / modulus;
-
-        // let dec_res = cks.decrypt(&ct_res);
-        // println!("clear_0 = {}, clear_1 = {}, dec = {}, clear = {}", clear_0, clear_1, dec_res,
-        // clear);
+        let ctxt_1 = cks.encrypt(clear_1);
 
-        //add multiple times to raise the degree
-        for _ in 0..30 {
-            ct_res = sks.smart_mul_msb(&mut ct_res, &mut ctxt_0);
-            clear = (clear * clear_0) / modulus;
+        for (policy, on_zero) in [
+            (DivisionByZeroPolicy::Zero, 0),
+            (DivisionByZeroPolicy::Max, modulus - 1),
+            (DivisionByZeroPolicy::ErrorFlag, 0),
+        ] {
+            let (ct_q, ct_r, flag) = sks.unchecked_div_rem_with_policy(&ctxt_0, &ctxt_1, policy);
 
-            // decryption of ct_res
-            let dec_res = cks.decrypt(&ct_res);
+            let (expected_q, expected_r) = if clear_1 == 0 {
+                (on_zero, on_zero)
+            } else {
+                (clear_0 / clear_1, clear_0 % clear_1)
+            };
 
             // assert
-            assert_eq!(clear % modulus, dec_res);
+            assert_eq!(expected_q, cks.decrypt(&ct_q));
+            assert_eq!(expected_r, cks.decrypt(&ct_r));
+            assert!(ct_q.degree.0 < cks.parameters.message_modulus.0);
+            assert!(ct_r.degree.0 < cks.parameters.message_modulus.0);
+            match flag {
+                Some(flag) => {
+                    assert_eq!(policy, DivisionByZeroPolicy::ErrorFlag);
+                    assert_eq!((clear_1 == 0) as u64, cks.decrypt(&flag));
+                }
+                None => assert_ne!(policy, DivisionByZeroPolicy::ErrorFlag),
+            }
         }
     }
 }
 
-/// test unchecked negation
-fn shortint_unchecked_neg(param: Parameters) {
+/// test that the division by zero policies return the largest message of a block encrypted with
+/// another message modulus
+fn shortint_div_rem_with_policy_crt_block(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let ct_0 = cks.encrypt_with_message_modulus(2, MessageModulus(3));
+    let ct_zero = cks.encrypt_with_message_modulus(0, MessageModulus(3));
+
+    let (ct_q, ct_r, _) =
+        sks.unchecked_div_rem_with_policy(&ct_0, &ct_zero, DivisionByZeroPolicy::Max);
+    assert_eq!(cks.decrypt_message_and_carry(&ct_q), 2);
+    assert_eq!(cks.decrypt_message_and_carry(&ct_r), 2);
+
+    // the accumulators of another message modulus are not reused
+    let ct_1 = cks.encrypt(2);
+    let ct_zero = cks.encrypt(0);
+    let (ct_q, ct_r, _) =
+        sks.unchecked_div_rem_with_policy(&ct_1, &ct_zero, DivisionByZeroPolicy::Max);
+    let max_message = cks.parameters.message_modulus.0 as u64 - 1;
+    assert_eq!(cks.decrypt(&ct_q), max_message);
+    assert_eq!(cks.decrypt(&ct_r), max_message);
+}
+
+/// test left shift by an encrypted amount
+fn shortint_unchecked_shift_left(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
@@ -1356,27 +1716,848 @@ fn shortint_unchecked_neg(param: Parameters) {
     let modulus = cks.parameters.message_modulus.0 as u64;
 
     for _ in 0..NB_TEST {
-        // Define the cleartexts
-        let clear = rng.gen::<u64>() % modulus;
-
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of the amount
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.unchecked_shift_left(&ctxt_0, &ctxt_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            (clear_0 % modulus).checked_shl(clear_1 as u32).unwrap_or(0) % modulus,
+            dec_res
+        );
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test right shift by an encrypted amount
+fn shortint_unchecked_shift_right(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of the amount
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.unchecked_shift_right(&ctxt_0, &ctxt_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(clear_0.checked_shr(clear_1 as u32).unwrap_or(0), dec_res);
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test smart left shift by an encrypted amount
+fn shortint_smart_shift_left(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of the amount
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        // fill the carry buffer of the shifted ciphertext
+        let mut ctxt_0 = sks.unchecked_add(&ctxt_0, &ctxt_0);
+        let clear_0 = 2 * clear_0;
+
+        let ct_res = sks.smart_shift_left(&mut ctxt_0, &mut ctxt_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            (clear_0 % modulus).checked_shl(clear_1 as u32).unwrap_or(0) % modulus,
+            dec_res
+        );
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test left rotation by an encrypted amount
+fn shortint_unchecked_rotate_left(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let nb_bits = modulus.trailing_zeros() as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of the amount
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.unchecked_rotate_left(&ctxt_0, &ctxt_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            ((clear_0 << (clear_1 % nb_bits)) | (clear_0 >> (nb_bits - clear_1 % nb_bits)))
+                % modulus,
+            dec_res
+        );
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test smart left rotation by an encrypted amount
+fn shortint_smart_rotate_left(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let nb_bits = modulus.trailing_zeros() as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of the amount
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        // fill the carry buffer of the rotated ciphertext
+        let mut ctxt_0 = sks.unchecked_add(&ctxt_0, &ctxt_0);
+        let clear_0 = (2 * clear_0) % modulus;
+
+        let ct_res = sks.smart_rotate_left(&mut ctxt_0, &mut ctxt_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            ((clear_0 << (clear_1 % nb_bits)) | (clear_0 >> (nb_bits - clear_1 % nb_bits)))
+                % modulus,
+            dec_res
+        );
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test right rotation by an encrypted amount
+fn shortint_unchecked_rotate_right(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let nb_bits = modulus.trailing_zeros() as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of the amount
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.unchecked_rotate_right(&ctxt_0, &ctxt_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            ((clear_0 >> (clear_1 % nb_bits)) | (clear_0 << (nb_bits - clear_1 % nb_bits)))
+                % modulus,
+            dec_res
+        );
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test left rotation by a clear amount
+fn shortint_unchecked_scalar_rotate_left(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let nb_bits = modulus.trailing_zeros() as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // rotate by a clear amount
+        let ct_res = sks.unchecked_scalar_rotate_left(&ctxt_0, clear_1 as u8);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            ((clear_0 << (clear_1 % nb_bits)) | (clear_0 >> (nb_bits - clear_1 % nb_bits)))
+                % modulus,
+            dec_res
+        );
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test right rotation by a clear amount
+fn shortint_unchecked_scalar_rotate_right(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let nb_bits = modulus.trailing_zeros() as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // rotate by a clear amount
+        let ct_res = sks.unchecked_scalar_rotate_right(&ctxt_0, clear_1 as u8);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(
+            ((clear_0 >> (clear_1 % nb_bits)) | (clear_0 << (nb_bits - clear_1 % nb_bits)))
+                % modulus,
+            dec_res
+        );
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test checked left and right rotations by a clear amount
+fn shortint_checked_scalar_rotate(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let nb_bits = modulus.trailing_zeros() as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let amount = clear_1 % nb_bits;
+
+        // encryption of an integer
+        let mut ctxt_0 = cks.encrypt(clear_0);
+
+        // rotate by a clear amount
+        let ct_res = sks
+            .checked_scalar_rotate_left(&ctxt_0, clear_1 as u8)
+            .unwrap();
+        assert_eq!(
+            ((clear_0 << amount) | (clear_0 >> (nb_bits - amount))) % modulus,
+            cks.decrypt(&ct_res)
+        );
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+
+        sks.checked_scalar_rotate_right_assign(&mut ctxt_0, clear_1 as u8)
+            .unwrap();
+        assert_eq!(
+            ((clear_0 >> amount) | (clear_0 << (nb_bits - amount))) % modulus,
+            cks.decrypt(&ctxt_0)
+        );
+        assert!(ctxt_0.degree.0 < cks.parameters.message_modulus.0);
+    }
+
+    // a ciphertext too noisy to be bootstrapped is refused
+    let mut ct_noisy = cks.encrypt(1);
+    ct_noisy.noise_level = NoiseLevel(sks.max_noise_level.0 + 1);
+    assert!(!sks.is_scalar_rotate_left_possible(&ct_noisy, 1));
+    assert!(matches!(
+        sks.checked_scalar_rotate_left(&ct_noisy, 1),
+        Err(CheckError::NoiseTooHigh)
+    ));
+    assert!(matches!(
+        sks.checked_scalar_rotate_right_assign(&mut ct_noisy, 1),
+        Err(CheckError::NoiseTooHigh)
+    ));
+}
+
+/// test smart left and right rotations by a clear amount of a ciphertext with a non empty carry
+/// buffer
+fn shortint_smart_scalar_rotate(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let nb_bits = modulus.trailing_zeros() as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let amount = clear_1 % nb_bits;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // fill the carry buffer of the rotated ciphertext, and claim it is too noisy to be
+        // bootstrapped
+        let mut ctxt_0 = sks.unchecked_add(&ctxt_0, &ctxt_0);
+        ctxt_0.noise_level = NoiseLevel(sks.max_noise_level.0 + 1);
+        let clear_0 = (2 * clear_0) % modulus;
+
+        // rotate by a clear amount
+        let ct_res = sks.smart_scalar_rotate_left(&mut ctxt_0, clear_1 as u8);
+        assert_eq!(
+            ((clear_0 << amount) | (clear_0 >> (nb_bits - amount))) % modulus,
+            cks.decrypt(&ct_res)
+        );
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+
+        sks.smart_scalar_rotate_right_assign(&mut ctxt_0, clear_1 as u8);
+        assert_eq!(
+            ((clear_0 >> amount) | (clear_0 << (nb_bits - amount))) % modulus,
+            cks.decrypt(&ctxt_0)
+        );
+        assert!(ctxt_0.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test LSB multiplication with the LWE server key
+fn shortint_unchecked_mul_lsb(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of an integer
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // add the two ciphertexts
+        let ct_res = sks.unchecked_mul_lsb(&ctxt_0, &ctxt_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!((clear_0 * clear_1) % modulus, dec_res);
+    }
+}
+
+/// test MSB multiplication with the LWE server key
+fn shortint_unchecked_mul_msb(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of an integer
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // add the two ciphertexts
+        let ct_res = sks.unchecked_mul_msb(&ctxt_0, &ctxt_1);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!((clear_0 * clear_1) / modulus, dec_res);
+    }
+}
+
+/// test LSB multiplication with the LWE server key
+fn shortint_smart_mul_lsb(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..10 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let mut ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of an integer
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        // add the two ciphertexts
+        let mut ct_res = sks.smart_mul_lsb(&mut ctxt_0, &mut ctxt_1);
+
+        let mut clear = clear_0 * clear_1;
+
+        //add multiple times to raise the degree
+        for _ in 0..30 {
+            ct_res = sks.smart_mul_lsb(&mut ct_res, &mut ctxt_0);
+            clear = (clear * clear_0) % modulus;
+
+            // decryption of ct_res
+            let dec_res = cks.decrypt(&ct_res);
+
+            // assert
+            assert_eq!(clear, dec_res);
+        }
+    }
+}
+
+/// test MSB multiplication with the LWE server key
+fn shortint_smart_mul_msb(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..10 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let mut ctxt_0 = cks.encrypt(clear_0);
+
+        // encryption of an integer
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        // add the two ciphertexts
+        let mut ct_res = sks.smart_mul_msb(&mut ctxt_0, &mut ctxt_1);
+
+        let mut clear = (clear_0 * clear_1) / modulus;
+
+        // let dec_res = cks.decrypt(&ct_res);
+        // println!("clear_0 = {}, clear_1 = {}, dec = {}, clear = {}", clear_0, clear_1, dec_res,
+        // clear);
+
+        //add multiple times to raise the degree
+        for _ in 0..30 {
+            ct_res = sks.smart_mul_msb(&mut ct_res, &mut ctxt_0);
+            clear = (clear * clear_0) / modulus;
+
+            // decryption of ct_res
+            let dec_res = cks.decrypt(&ct_res);
+
+            // assert
+            assert_eq!(clear % modulus, dec_res);
+        }
+    }
+}
+
+/// test unchecked negation
+fn shortint_unchecked_neg(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        // Define the cleartexts
+        let clear = rng.gen::<u64>() % modulus;
+
+        // Encrypt the integers
+        let ctxt = cks.encrypt(clear);
+
+        // Negates the ctxt
+        let ct_tmp = sks.unchecked_neg(&ctxt);
+
+        // Decrypt the result
+        let dec = cks.decrypt(&ct_tmp);
+
+        // Check the correctness
+        let clear_result = clear.wrapping_neg() % modulus;
+
+        assert_eq!(clear_result, dec);
+    }
+}
+
+/// test smart negation
+fn shortint_smart_neg(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..10 {
+        let clear1 = rng.gen::<u64>() % modulus;
+
+        let mut ct1 = cks.encrypt(clear1);
+
+        let mut ct_res = sks.smart_neg(&mut ct1);
+
+        let mut clear_result = clear1.wrapping_neg() % modulus;
+
+        for _ in 0..30 {
+            // scalar multiplication
+            ct_res = sks.smart_neg(&mut ct_res);
+
+            clear_result = clear_result.wrapping_neg() % modulus;
+
+            // decryption of ct_res
+            let dec_res = cks.decrypt(&ct_res);
+
+            // assert
+            assert_eq!(clear_result, dec_res);
+        }
+    }
+}
+
+/// test scalar add
+fn shortint_unchecked_scalar_add(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = param.message_modulus.0 as u8;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u8>() % message_modulus as u8;
+
+        let scalar = rng.gen::<u8>() % message_modulus as u8;
+
+        // encryption of an integer
+        let ct = cks.encrypt(clear as u64);
+
+        // add the two ciphertexts
+        let ct_res = sks.unchecked_scalar_add(&ct, scalar);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!((clear + scalar) % message_modulus, dec_res as u8);
+    }
+}
+
+/// test smart scalar add
+fn shortint_smart_scalar_add(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u8;
+
+    for _ in 0..10 {
+        let clear_0 = rng.gen::<u8>() % modulus;
+
+        let clear_1 = rng.gen::<u8>() % modulus;
+
+        // encryption of an integer
+        let mut ctxt_0 = cks.encrypt(clear_0 as u64);
+
+        // add the two ciphertexts
+        let mut ct_res = sks.smart_scalar_add(&mut ctxt_0, clear_1);
+
+        let mut clear = (clear_0 + clear_1) % modulus;
+
+        //add multiple times to raise the degree
+        for _ in 0..30 {
+            ct_res = sks.smart_scalar_add(&mut ct_res, clear_1);
+            clear = (clear + clear_1) % modulus;
+
+            // decryption of ct_res
+            let dec_res = cks.decrypt(&ct_res);
+
+            assert_eq!(clear, dec_res as u8);
+        }
+    }
+}
+
+/// test unchecked scalar sub
+fn shortint_unchecked_scalar_sub(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = param.message_modulus.0 as u8;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u8>() % message_modulus;
+
+        let scalar = rng.gen::<u8>() % message_modulus;
+
+        // encryption of an integer
+        let ct = cks.encrypt(clear as u64);
+
+        // add the two ciphertexts
+        let ct_res = sks.unchecked_scalar_sub(&ct, scalar);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!((clear - scalar) % message_modulus, dec_res as u8);
+    }
+}
+
+fn shortint_smart_scalar_sub(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u8;
+
+    for _ in 0..10 {
+        let clear_0 = rng.gen::<u8>() % modulus;
+
+        let clear_1 = rng.gen::<u8>() % modulus;
+
+        // encryption of an integer
+        let mut ctxt_0 = cks.encrypt(clear_0 as u64);
+
+        // add the two ciphertexts
+        let mut ct_res = sks.smart_scalar_sub(&mut ctxt_0, clear_1);
+
+        let mut clear = (clear_0 - clear_1) % modulus;
+
+        // let dec_res = cks.decrypt(&ct_res);
+        // println!("clear_0 = {}, clear_1 = {}, dec = {}, clear = {}", clear_0, clear_1, dec_res,
+        // clear);
+
+        //add multiple times to raise the degree
+        for _ in 0..30 {
+            ct_res = sks.smart_scalar_sub(&mut ct_res, clear_1);
+            clear = (clear - clear_1) % modulus;
+
+            // decryption of ct_res
+            let dec_res = cks.decrypt(&ct_res);
+
+            // println!("clear_1 = {}, dec = {}, clear = {}", clear_1, dec_res, clear);
+            // assert
+            assert_eq!(clear, dec_res as u8);
+        }
+    }
+}
+
+/// test scalar multiplication with the LWE server key
+fn shortint_unchecked_scalar_mul(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = param.message_modulus.0 as u8;
+    let carry_modulus = param.carry_modulus.0 as u8;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u8>() % message_modulus;
+
+        let scalar = rng.gen::<u8>() % carry_modulus;
+
+        // encryption of an integer
+        let ct = cks.encrypt(clear as u64);
+
+        // add the two ciphertexts
+        let ct_res = sks.unchecked_scalar_mul(&ct, scalar);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!((clear * scalar) % message_modulus, dec_res as u8);
+    }
+}
+
+/// test smart scalar multiplication with the LWE server key
+fn shortint_smart_scalar_mul(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u8;
+
+    let scalar_modulus = cks.parameters.carry_modulus.0 as u8;
+
+    for _ in 0..10 {
+        let clear = rng.gen::<u8>() % modulus;
+
+        let scalar = rng.gen::<u8>() % scalar_modulus;
+
+        // encryption of an integer
+        let mut ct = cks.encrypt(clear as u64);
+
+        let mut ct_res = sks.smart_scalar_mul(&mut ct, scalar);
+
+        let mut clear_res = clear * scalar;
+        for _ in 0..10 {
+            // scalar multiplication
+            ct_res = sks.smart_scalar_mul(&mut ct_res, scalar);
+            clear_res = (clear_res * scalar) % modulus;
+        }
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(clear_res, dec_res as u8);
+    }
+}
+
+/// test unchecked '>>' operation
+fn shortint_unchecked_right_shift(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let shift = rng.gen::<u64>() % 2;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // add the two ciphertexts
+        let ct_res = sks.unchecked_scalar_right_shift(&ctxt_0, shift as u8);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(clear_0 >> shift, dec_res);
+    }
+}
+
+/// test '<<' operation
+fn shortint_unchecked_left_shift(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let shift = rng.gen::<u64>() % 2;
+
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        // add the two ciphertexts
+        let ct_res = sks.unchecked_scalar_left_shift(&ctxt_0, shift as u8);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!((clear_0 << shift) % modulus, dec_res);
+    }
+}
+
+/// test unchecked subtraction
+fn shortint_unchecked_sub(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    for _ in 0..NB_TEST {
+        // Define the cleartexts
+        let clear1 = rng.gen::<u64>() % modulus;
+        let clear2 = rng.gen::<u64>() % modulus;
+
         // Encrypt the integers
-        let ctxt = cks.encrypt(clear);
+        let ctxt_1 = cks.encrypt(clear1);
+        let ctxt_2 = cks.encrypt(clear2);
 
-        // Negates the ctxt
-        let ct_tmp = sks.unchecked_neg(&ctxt);
+        // Add the ciphertext 1 and 2
+        let ct_tmp = sks.unchecked_sub(&ctxt_1, &ctxt_2);
 
         // Decrypt the result
         let dec = cks.decrypt(&ct_tmp);
 
         // Check the correctness
-        let clear_result = clear.wrapping_neg() % modulus;
-
-        assert_eq!(clear_result, dec);
+        let clear_result = (clear1 - clear2) % modulus;
+        assert_eq!(clear_result, dec % modulus);
     }
 }
 
-/// test smart negation
-fn shortint_smart_neg(param: Parameters) {
+fn shortint_smart_sub(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
@@ -1386,226 +2567,424 @@ fn shortint_smart_neg(param: Parameters) {
 
     for _ in 0..10 {
         let clear1 = rng.gen::<u64>() % modulus;
+        let clear2 = rng.gen::<u64>() % modulus;
 
         let mut ct1 = cks.encrypt(clear1);
+        let mut ct2 = cks.encrypt(clear2);
 
-        let mut ct_res = sks.smart_neg(&mut ct1);
-
-        let mut clear_result = clear1.wrapping_neg() % modulus;
+        let mut ct_res = sks.smart_sub(&mut ct1, &mut ct2);
 
-        for _ in 0..30 {
+        let mut clear_res = (clear1 - clear2) % modulus;
+        for _ in 0..10 {
             // scalar multiplication
-            ct_res = sks.smart_neg(&mut ct_res);
+            ct_res = sks.smart_sub(&mut ct_res, &mut ct2);
+            clear_res = (clear_res - clear2) % modulus;
+        }
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
 
-            clear_result = clear_result.wrapping_neg() % modulus;
+        // assert
+        assert_eq!(clear_res, dec_res);
+    }
+}
 
-            // decryption of ct_res
-            let dec_res = cks.decrypt(&ct_res);
+/// test multiplication
+fn shortint_mul_small_carry(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
 
-            // assert
-            assert_eq!(clear_result, dec_res);
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..50 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer
+        let mut ctxt_zero = cks.encrypt(clear_0);
+
+        // encryption of an integer
+        let mut ctxt_one = cks.encrypt(clear_1);
+
+        // multiply together the two ciphertexts
+        let ct_res = sks.unchecked_mul_lsb_small_carry(&mut ctxt_zero, &mut ctxt_one);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!((clear_0 * clear_1) % modulus, dec_res % modulus);
+    }
+}
+
+/// test encryption and decryption with the LWE client key
+fn shortint_encrypt_with_message_modulus_smart_add_and_mul(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let full_mod = (cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0) / 3;
+
+    for _ in 0..NB_TEST {
+        let mut modulus = rng.gen::<u64>() % full_mod as u64;
+        while modulus == 0 {
+            modulus = rng.gen::<u64>() % full_mod as u64;
         }
+
+        let clear1 = rng.gen::<u64>() % modulus;
+        let clear2 = rng.gen::<u64>() % modulus;
+
+        let mut ct1 = cks.encrypt_with_message_modulus(clear1, MessageModulus(modulus as usize));
+        let mut ct2 = cks.encrypt_with_message_modulus(clear2, MessageModulus(modulus as usize));
+
+        println!(
+            "MUL SMALL CARRY:: clear1 = {}, clear2 = {}, mod = {}",
+            clear1, clear2, modulus
+        );
+        let ct_res = sks.unchecked_mul_lsb_small_carry(&mut ct1, &mut ct2);
+        assert_eq!(
+            (clear1 * clear2) % modulus,
+            cks.decrypt_message_and_carry(&ct_res) % modulus
+        );
+
+        println!(
+            "ADD:: clear1 = {}, clear2 = {}, mod = {}",
+            clear1, clear2, modulus
+        );
+        let ct_res = sks.unchecked_add(&ct1, &ct2);
+        assert_eq!((clear1 + clear2), cks.decrypt_message_and_carry(&ct_res));
     }
 }
 
-/// test scalar add
-fn shortint_unchecked_scalar_add(param: Parameters) {
+/// test bitwise 'and' between a ciphertext and a clear scalar
+fn shortint_unchecked_scalar_bitand(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+        let scalar = (rng.gen::<u64>() % modulus) as u8;
+
+        // encryption of an integer
+        let ctxt = cks.encrypt(clear);
+
+        // and with a clear scalar
+        let ct_res = sks.unchecked_scalar_bitand(&ctxt, scalar);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
 
+        // assert
+        assert_eq!(clear & scalar as u64, dec_res);
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test bitwise 'or' between a ciphertext and a clear scalar
+fn shortint_unchecked_scalar_bitor(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
     let mut rng = rand::thread_rng();
 
-    let message_modulus = param.message_modulus.0 as u8;
+    let modulus = cks.parameters.message_modulus.0 as u64;
 
     for _ in 0..NB_TEST {
-        let clear = rng.gen::<u8>() % message_modulus as u8;
+        let clear = rng.gen::<u64>() % modulus;
+        let scalar = (rng.gen::<u64>() % modulus) as u8;
+
+        // encryption of an integer
+        let ctxt = cks.encrypt(clear);
+
+        // or with a clear scalar
+        let ct_res = sks.unchecked_scalar_bitor(&ctxt, scalar);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(clear | scalar as u64, dec_res);
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test bitwise 'xor' between a ciphertext and a clear scalar
+fn shortint_unchecked_scalar_bitxor(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+        let scalar = (rng.gen::<u64>() % modulus) as u8;
+
+        // encryption of an integer
+        let ctxt = cks.encrypt(clear);
+
+        // xor with a clear scalar
+        let ct_res = sks.unchecked_scalar_bitxor(&ctxt, scalar);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!(clear ^ scalar as u64, dec_res);
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
+    }
+}
+
+/// test bitwise 'and' between a ciphertext with a non empty carry buffer and a clear scalar
+fn shortint_smart_scalar_bitand(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let mod_scalar = cks.parameters.carry_modulus.0 as u8;
+
+    for _ in 0..NB_TEST {
+        let mut clear = rng.gen::<u64>() % modulus;
+        let scalar_mul = rng.gen::<u8>() % mod_scalar;
+        let scalar = (rng.gen::<u64>() % modulus) as u8;
+
+        // encryption of an integer
+        let mut ctxt = cks.encrypt(clear);
+
+        sks.unchecked_scalar_mul_assign(&mut ctxt, scalar_mul);
+        clear *= scalar_mul as u64;
+
+        // and with a clear scalar
+        let ct_res = sks.smart_scalar_bitand(&mut ctxt, scalar);
+
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
+
+        // assert
+        assert_eq!((clear & scalar as u64) % modulus, dec_res);
+    }
+}
+
+/// test bitwise 'or' between a ciphertext with a non empty carry buffer and any clear scalar
+fn shortint_smart_scalar_bitor(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let mod_scalar = cks.parameters.carry_modulus.0 as u8;
 
-        let scalar = rng.gen::<u8>() % message_modulus as u8;
+    for _ in 0..NB_TEST {
+        let mut clear = rng.gen::<u64>() % modulus;
+        let scalar_mul = rng.gen::<u8>() % mod_scalar;
+        // the scalar may not fit in the message
+        let scalar = rng.gen::<u8>();
 
         // encryption of an integer
-        let ct = cks.encrypt(clear as u64);
+        let mut ctxt = cks.encrypt(clear);
 
-        // add the two ciphertexts
-        let ct_res = sks.unchecked_scalar_add(&ct, scalar);
+        sks.unchecked_scalar_mul_assign(&mut ctxt, scalar_mul);
+        clear *= scalar_mul as u64;
+
+        // or with a clear scalar
+        let ct_res = sks.smart_scalar_bitor(&mut ctxt, scalar);
 
         // decryption of ct_res
         let dec_res = cks.decrypt(&ct_res);
 
         // assert
-        assert_eq!((clear + scalar) % message_modulus, dec_res as u8);
+        assert_eq!((clear | scalar as u64) % modulus, dec_res);
     }
 }
 
-/// test smart scalar add
-fn shortint_smart_scalar_add(param: Parameters) {
+/// test bitwise 'xor' between a ciphertext with a non empty carry buffer and any clear scalar
+fn shortint_smart_scalar_bitxor(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
     let mut rng = rand::thread_rng();
 
-    let modulus = cks.parameters.message_modulus.0 as u8;
-
-    for _ in 0..10 {
-        let clear_0 = rng.gen::<u8>() % modulus;
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let mod_scalar = cks.parameters.carry_modulus.0 as u8;
 
-        let clear_1 = rng.gen::<u8>() % modulus;
+    for _ in 0..NB_TEST {
+        let mut clear = rng.gen::<u64>() % modulus;
+        let scalar_mul = rng.gen::<u8>() % mod_scalar;
+        // the scalar may not fit in the message
+        let scalar = rng.gen::<u8>();
 
         // encryption of an integer
-        let mut ctxt_0 = cks.encrypt(clear_0 as u64);
-
-        // add the two ciphertexts
-        let mut ct_res = sks.smart_scalar_add(&mut ctxt_0, clear_1);
+        let mut ctxt = cks.encrypt(clear);
 
-        let mut clear = (clear_0 + clear_1) % modulus;
+        sks.unchecked_scalar_mul_assign(&mut ctxt, scalar_mul);
+        clear *= scalar_mul as u64;
 
-        //add multiple times to raise the degree
-        for _ in 0..30 {
-            ct_res = sks.smart_scalar_add(&mut ct_res, clear_1);
-            clear = (clear + clear_1) % modulus;
+        // xor with a clear scalar
+        let ct_res = sks.smart_scalar_bitxor(&mut ctxt, scalar);
 
-            // decryption of ct_res
-            let dec_res = cks.decrypt(&ct_res);
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
 
-            assert_eq!(clear, dec_res as u8);
-        }
+        // assert
+        assert_eq!((clear ^ scalar as u64) % modulus, dec_res);
     }
 }
 
-/// test unchecked scalar sub
-fn shortint_unchecked_scalar_sub(param: Parameters) {
+/// test maximum between a ciphertext and any clear scalar
+fn shortint_smart_scalar_max(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
-
+    //RNG
     let mut rng = rand::thread_rng();
 
-    let message_modulus = param.message_modulus.0 as u8;
+    let modulus = cks.parameters.message_modulus.0 as u64;
 
     for _ in 0..NB_TEST {
-        let clear = rng.gen::<u8>() % message_modulus;
-
-        let scalar = rng.gen::<u8>() % message_modulus;
+        let clear = rng.gen::<u64>() % modulus;
+        // the scalar may not fit in the message
+        let scalar = rng.gen::<u8>();
 
         // encryption of an integer
-        let ct = cks.encrypt(clear as u64);
+        let mut ctxt = cks.encrypt(clear);
 
-        // add the two ciphertexts
-        let ct_res = sks.unchecked_scalar_sub(&ct, scalar);
+        // maximum with a clear scalar
+        let ct_res = sks.smart_scalar_max(&mut ctxt, scalar);
 
         // decryption of ct_res
         let dec_res = cks.decrypt(&ct_res);
 
         // assert
-        assert_eq!((clear - scalar) % message_modulus, dec_res as u8);
+        assert_eq!(clear.max(scalar as u64) % modulus, dec_res);
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
     }
 }
 
-fn shortint_smart_scalar_sub(param: Parameters) {
+/// test that the checked scalar operations refuse the scalars whose result would not fit in the
+/// plaintext space
+fn shortint_checked_scalar_out_of_message(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
     let mut rng = rand::thread_rng();
 
-    let modulus = cks.parameters.message_modulus.0 as u8;
-
-    for _ in 0..10 {
-        let clear_0 = rng.gen::<u8>() % modulus;
+    let modulus = cks.parameters.message_modulus.0 as u64;
+    let max_message = modulus as usize - 1;
 
-        let clear_1 = rng.gen::<u8>() % modulus;
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+        // the scalar may not fit in the message
+        let scalar = rng.gen::<u8>();
 
         // encryption of an integer
-        let mut ctxt_0 = cks.encrypt(clear_0 as u64);
-
-        // add the two ciphertexts
-        let mut ct_res = sks.smart_scalar_sub(&mut ctxt_0, clear_1);
-
-        let mut clear = (clear_0 - clear_1) % modulus;
-
-        // let dec_res = cks.decrypt(&ct_res);
-        // println!("clear_0 = {}, clear_1 = {}, dec = {}, clear = {}", clear_0, clear_1, dec_res,
-        // clear);
-
-        //add multiple times to raise the degree
-        for _ in 0..30 {
-            ct_res = sks.smart_scalar_sub(&mut ct_res, clear_1);
-            clear = (clear - clear_1) % modulus;
-
-            // decryption of ct_res
-            let dec_res = cks.decrypt(&ct_res);
+        let ctxt = cks.encrypt(clear);
 
-            // println!("clear_1 = {}, dec = {}, clear = {}", clear_1, dec_res, clear);
-            // assert
-            assert_eq!(clear, dec_res as u8);
+        let results = [
+            (
+                sks.checked_scalar_bitor(&ctxt, scalar),
+                clear | scalar as u64,
+                max_message | scalar as usize,
+            ),
+            (
+                sks.checked_scalar_bitxor(&ctxt, scalar),
+                clear ^ scalar as u64,
+                (0..=max_message)
+                    .map(|x| x ^ scalar as usize)
+                    .max()
+                    .unwrap(),
+            ),
+            (
+                sks.checked_scalar_max(&ctxt, scalar),
+                clear.max(scalar as u64),
+                max_message.max(scalar as usize),
+            ),
+        ];
+        for (result, expected, degree) in results {
+            match result {
+                Ok(ct_res) => {
+                    assert!(degree <= sks.max_degree.0);
+                    assert_eq!(expected % modulus, cks.decrypt(&ct_res));
+                }
+                Err(error) => {
+                    assert!(degree > sks.max_degree.0);
+                    assert!(matches!(error, CheckError::CarryFull));
+                }
+            }
         }
     }
 }
 
-/// test scalar multiplication with the LWE server key
-fn shortint_unchecked_scalar_mul(param: Parameters) {
+/// test minimum between two ciphertexts
+fn shortint_unchecked_min(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
-
+    //RNG
     let mut rng = rand::thread_rng();
 
-    let message_modulus = param.message_modulus.0 as u8;
-    let carry_modulus = param.carry_modulus.0 as u8;
+    let modulus = cks.parameters.message_modulus.0 as u64;
 
     for _ in 0..NB_TEST {
-        let clear = rng.gen::<u8>() % message_modulus;
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
 
-        let scalar = rng.gen::<u8>() % carry_modulus;
+        // encryption of an integer
+        let ctxt_0 = cks.encrypt(clear_0);
 
         // encryption of an integer
-        let ct = cks.encrypt(clear as u64);
+        let ctxt_1 = cks.encrypt(clear_1);
 
-        // add the two ciphertexts
-        let ct_res = sks.unchecked_scalar_mul(&ct, scalar);
+        // minimum of the two ciphertexts
+        let ct_res = sks.unchecked_min(&ctxt_0, &ctxt_1);
 
         // decryption of ct_res
         let dec_res = cks.decrypt(&ct_res);
 
         // assert
-        assert_eq!((clear * scalar) % message_modulus, dec_res as u8);
+        assert_eq!(clear_0.min(clear_1), dec_res);
     }
 }
 
-/// test smart scalar multiplication with the LWE server key
-fn shortint_smart_scalar_mul(param: Parameters) {
+/// test maximum between two ciphertexts
+fn shortint_unchecked_max(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
     let mut rng = rand::thread_rng();
 
-    let modulus = cks.parameters.message_modulus.0 as u8;
-
-    let scalar_modulus = cks.parameters.carry_modulus.0 as u8;
-
-    for _ in 0..10 {
-        let clear = rng.gen::<u8>() % modulus;
+    let modulus = cks.parameters.message_modulus.0 as u64;
 
-        let scalar = rng.gen::<u8>() % scalar_modulus;
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
 
         // encryption of an integer
-        let mut ct = cks.encrypt(clear as u64);
+        let ctxt_0 = cks.encrypt(clear_0);
 
-        let mut ct_res = sks.smart_scalar_mul(&mut ct, scalar);
+        // encryption of an integer
+        let ctxt_1 = cks.encrypt(clear_1);
 
-        let mut clear_res = clear * scalar;
-        for _ in 0..10 {
-            // scalar multiplication
-            ct_res = sks.smart_scalar_mul(&mut ct_res, scalar);
-            clear_res = (clear_res * scalar) % modulus;
-        }
+        // maximum of the two ciphertexts
+        let ct_res = sks.unchecked_max(&ctxt_0, &ctxt_1);
 
         // decryption of ct_res
         let dec_res = cks.decrypt(&ct_res);
 
         // assert
-        assert_eq!(clear_res, dec_res as u8);
+        assert_eq!(clear_0.max(clear_1), dec_res);
     }
 }
 
-/// test unchecked '>>' operation
-fn shortint_unchecked_right_shift(param: Parameters) {
+/// test minimum between two ciphertexts
+fn shortint_smart_min(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
@@ -1615,24 +2994,27 @@ fn shortint_unchecked_right_shift(param: Parameters) {
 
     for _ in 0..NB_TEST {
         let clear_0 = rng.gen::<u64>() % modulus;
-        let shift = rng.gen::<u64>() % 2;
+        let clear_1 = rng.gen::<u64>() % modulus;
 
         // encryption of an integer
-        let ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_0 = cks.encrypt(clear_0);
 
-        // add the two ciphertexts
-        let ct_res = sks.unchecked_scalar_right_shift(&ctxt_0, shift as u8);
+        // encryption of an integer
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        // minimum of the two ciphertexts
+        let ct_res = sks.smart_min(&mut ctxt_0, &mut ctxt_1);
 
         // decryption of ct_res
         let dec_res = cks.decrypt(&ct_res);
 
         // assert
-        assert_eq!(clear_0 >> shift, dec_res);
+        assert_eq!(clear_0.min(clear_1), dec_res);
     }
 }
 
-/// test '<<' operation
-fn shortint_unchecked_left_shift(param: Parameters) {
+/// test minimum between a ciphertext and a clear scalar
+fn shortint_unchecked_scalar_min(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
@@ -1641,149 +3023,310 @@ fn shortint_unchecked_left_shift(param: Parameters) {
     let modulus = cks.parameters.message_modulus.0 as u64;
 
     for _ in 0..NB_TEST {
-        let clear_0 = rng.gen::<u64>() % modulus;
-        let shift = rng.gen::<u64>() % 2;
+        let clear = rng.gen::<u64>() % modulus;
+        let scalar = (rng.gen::<u64>() % modulus) as u8;
 
         // encryption of an integer
-        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt = cks.encrypt(clear);
 
-        // add the two ciphertexts
-        let ct_res = sks.unchecked_scalar_left_shift(&ctxt_0, shift as u8);
+        // minimum with a clear scalar
+        let ct_res = sks.unchecked_scalar_min(&ctxt, scalar);
 
         // decryption of ct_res
         let dec_res = cks.decrypt(&ct_res);
 
         // assert
-        assert_eq!((clear_0 << shift) % modulus, dec_res);
+        assert_eq!(clear.min(scalar as u64), dec_res);
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
     }
 }
 
-/// test unchecked subtraction
-fn shortint_unchecked_sub(param: Parameters) {
+/// test maximum between a ciphertext and a clear scalar
+fn shortint_unchecked_scalar_max(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
     //RNG
     let mut rng = rand::thread_rng();
 
     let modulus = cks.parameters.message_modulus.0 as u64;
+
     for _ in 0..NB_TEST {
-        // Define the cleartexts
-        let clear1 = rng.gen::<u64>() % modulus;
-        let clear2 = rng.gen::<u64>() % modulus;
+        let clear = rng.gen::<u64>() % modulus;
+        let scalar = (rng.gen::<u64>() % modulus) as u8;
 
-        // Encrypt the integers
-        let ctxt_1 = cks.encrypt(clear1);
-        let ctxt_2 = cks.encrypt(clear2);
+        // encryption of an integer
+        let ctxt = cks.encrypt(clear);
 
-        // Add the ciphertext 1 and 2
-        let ct_tmp = sks.unchecked_sub(&ctxt_1, &ctxt_2);
+        // maximum with a clear scalar
+        let ct_res = sks.unchecked_scalar_max(&ctxt, scalar);
 
-        // Decrypt the result
-        let dec = cks.decrypt(&ct_tmp);
+        // decryption of ct_res
+        let dec_res = cks.decrypt(&ct_res);
 
-        // Check the correctness
-        let clear_result = (clear1 - clear2) % modulus;
-        assert_eq!(clear_result, dec % modulus);
+        // assert
+        assert_eq!(clear.max(scalar as u64), dec_res);
+        assert!(ct_res.degree.0 < cks.parameters.message_modulus.0);
     }
 }
 
-fn shortint_smart_sub(param: Parameters) {
+/// test the fallible addition with the LWE server key
+fn shortint_try_smart_add(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
+
     //RNG
     let mut rng = rand::thread_rng();
 
     let modulus = cks.parameters.message_modulus.0 as u64;
 
-    for _ in 0..10 {
-        let clear1 = rng.gen::<u64>() % modulus;
-        let clear2 = rng.gen::<u64>() % modulus;
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
 
-        let mut ct1 = cks.encrypt(clear1);
-        let mut ct2 = cks.encrypt(clear2);
+        // encryption of an integer
+        let mut ctxt_0 = cks.encrypt(clear_0);
 
-        let mut ct_res = sks.smart_sub(&mut ct1, &mut ct2);
+        // encryption of an integer
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        // add the two ciphertexts
+        let ct_res = sks.try_smart_add(&mut ctxt_0, &mut ctxt_1).unwrap();
 
-        let mut clear_res = (clear1 - clear2) % modulus;
-        for _ in 0..10 {
-            // scalar multiplication
-            ct_res = sks.smart_sub(&mut ct_res, &mut ct2);
-            clear_res = (clear_res - clear2) % modulus;
-        }
         // decryption of ct_res
         let dec_res = cks.decrypt(&ct_res);
 
         // assert
-        assert_eq!(clear_res, dec_res);
+        assert_eq!((clear_0 + clear_1) % modulus, dec_res);
     }
 }
 
-/// test multiplication
-fn shortint_mul_small_carry(param: Parameters) {
+/// test that the fallible checked operations report a full carry buffer
+fn shortint_try_checked_add(param: Parameters) {
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
-    //RNG
-    let mut rng = rand::thread_rng();
 
-    let modulus = cks.parameters.message_modulus.0 as u64;
+    let ctxt_0 = cks.encrypt(1);
+    let mut ct_full = cks.encrypt(1);
+    ct_full.degree.0 = sks.max_degree.0;
 
-    for _ in 0..50 {
-        let clear_0 = rng.gen::<u64>() % modulus;
+    assert!(sks.try_checked_add(&ctxt_0, &ctxt_0).is_ok());
+    assert!(matches!(
+        sks.try_checked_add(&ct_full, &ctxt_0),
+        Err(ShortintError::CarryFull)
+    ));
+}
 
-        let clear_1 = rng.gen::<u64>() % modulus;
+/// test that ciphertexts not produced for the key are refused instead of panicking
+fn shortint_try_ciphertext_mismatch(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let other_keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1);
+    let other_cks = other_keys.client_key();
+
+    let ctxt = cks.encrypt(1);
+
+    // same dimension, other moduli
+    let mut ct_other_moduli = ctxt.clone();
+    ct_other_moduli.message_modulus.0 *= 2;
+    assert!(matches!(
+        sks.try_unchecked_add(&ctxt, &ct_other_moduli),
+        Err(ShortintError::ParameterMismatch)
+    ));
+
+    // same moduli, other dimension
+    let mut ct_other_dimension = other_cks.encrypt(1);
+    ct_other_dimension.message_modulus = ctxt.message_modulus;
+    ct_other_dimension.carry_modulus = ctxt.carry_modulus;
+    assert!(matches!(
+        sks.try_smart_bitand(&mut ct_other_dimension.clone(), &mut ctxt.clone()),
+        Err(ShortintError::DimensionMismatch)
+    ));
+    assert!(matches!(
+        sks.try_keyswitch_bootstrap(&ct_other_dimension),
+        Err(ShortintError::DimensionMismatch)
+    ));
+}
 
-        // encryption of an integer
-        let mut ctxt_zero = cks.encrypt(clear_0);
+/// test that the blocks encrypted with another message modulus are accepted
+fn shortint_try_crt_block(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
 
-        // encryption of an integer
-        let mut ctxt_one = cks.encrypt(clear_1);
+    let ct_0 = cks.encrypt_with_message_modulus(2, MessageModulus(3));
+    let ct_1 = cks.encrypt_with_message_modulus(1, MessageModulus(3));
 
-        // multiply together the two ciphertexts
-        let ct_res = sks.unchecked_mul_lsb_small_carry(&mut ctxt_zero, &mut ctxt_one);
+    let ct_res = sks.try_unchecked_add(&ct_0, &ct_1).unwrap();
+    assert_eq!(cks.decrypt_message_and_carry(&ct_res) % 3, 0);
 
-        // decryption of ct_res
-        let dec_res = cks.decrypt(&ct_res);
+    let ct_res = sks.try_checked_add(&ct_0, &ct_1).unwrap();
+    assert_eq!(cks.decrypt_message_and_carry(&ct_res) % 3, 0);
+}
 
-        // assert
-        assert_eq!((clear_0 * clear_1) % modulus, dec_res % modulus);
-    }
+/// test that the errors can be moved across threads, e.g. returned from a spawned thread
+#[test]
+fn test_shortint_error_send_sync() {
+    fn assert_send_sync<T: Send + Sync + std::error::Error + 'static>() {}
+    assert_send_sync::<ShortintError>();
 }
 
-/// test encryption and decryption with the LWE client key
-fn shortint_encrypt_with_message_modulus_smart_add_and_mul(param: Parameters) {
+/// test that malformed ciphertexts and scalars are refused instead of panicking
+fn shortint_try_malformed_input(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let ctxt = cks.encrypt(1);
+
+    let mut ct_huge_degree = ctxt.clone();
+    ct_huge_degree.degree = Degree(usize::MAX);
+    assert!(matches!(
+        sks.try_unchecked_bitand(&ctxt, &ct_huge_degree),
+        Err(ShortintError::DegreeTooHigh)
+    ));
+    assert!(matches!(
+        sks.try_smart_add(&mut ctxt.clone(), &mut ct_huge_degree.clone()),
+        Err(ShortintError::DegreeTooHigh)
+    ));
+    assert!(matches!(
+        sks.try_keyswitch_programmable_bootstrap_many(
+            &[ctxt.clone(), ct_huge_degree],
+            &[
+                sks.generate_accumulator(|x| x),
+                sks.generate_accumulator(|x| x)
+            ]
+        ),
+        Err(ShortintError::DegreeTooHigh)
+    ));
+
+    let mut ct_noisy = ctxt.clone();
+    ct_noisy.noise_level = NoiseLevel(usize::MAX);
+    assert!(matches!(
+        sks.try_smart_mul_lsb(&mut ctxt.clone(), &mut ct_noisy),
+        Err(ShortintError::NoiseTooHigh)
+    ));
+
+    assert!(matches!(
+        sks.try_unchecked_scalar_div(&ctxt, 0),
+        Err(ShortintError::InvalidScalar)
+    ));
+    assert!(matches!(
+        sks.try_unchecked_scalar_mod_assign(&mut ctxt.clone(), 0),
+        Err(ShortintError::InvalidScalar)
+    ));
+    assert!(matches!(
+        sks.try_smart_scalar_left_shift(&mut ctxt.clone(), u8::MAX),
+        Err(ShortintError::InvalidScalar)
+    ));
+    assert!(matches!(
+        sks.try_unchecked_scalar_right_shift(&ctxt, 64),
+        Err(ShortintError::InvalidScalar)
+    ));
+    assert!(matches!(
+        sks.try_unchecked_scalar_bitor(&ctxt, 4),
+        Err(ShortintError::InvalidScalar)
+    ));
+    assert!(matches!(
+        sks.try_checked_scalar_max_assign(&mut ctxt.clone(), u8::MAX),
+        Err(ShortintError::InvalidScalar)
+    ));
+
+    assert!(matches!(
+        sks.try_keyswitch_programmable_bootstrap_many(
+            &[ctxt.clone(), ctxt.clone()],
+            &[sks.generate_accumulator(|x| x)]
+        ),
+        Err(ShortintError::LengthMismatch)
+    ));
+
+    // a well-formed input still goes through
+    let ct_res = sks.try_unchecked_scalar_div(&ctxt, 1).unwrap();
+    assert_eq!(cks.decrypt(&ct_res), 1);
+
+    // the smart operations reduce the result modulo the message modulus instead
+    let ct_res = sks
+        .try_smart_scalar_bitxor(&mut ctxt.clone(), u8::MAX)
+        .unwrap();
+    assert_eq!(cks.decrypt(&ct_res), (1 ^ u8::MAX as u64) % 4);
+}
+
+fn shortint_validate_ciphertext(param: Parameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let other_keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1);
+    let other_cks = other_keys.client_key();
+
+    let ctxt = cks.encrypt(1);
+    assert!(sks.validate(&ctxt).is_ok());
+
+    // a CRT block, whose carry modulus is 16 / 3 = 5
+    let ct_crt_block = cks.encrypt_with_message_modulus(2, MessageModulus(3));
+    assert!(sks.validate(&ct_crt_block).is_ok());
+
+    let mut ct_other_moduli = ctxt.clone();
+    ct_other_moduli.carry_modulus.0 = usize::MAX;
+    assert!(matches!(
+        sks.validate(&ct_other_moduli),
+        Err(ValidationError::ParameterMismatch)
+    ));
+
+    let mut ct_other_dimension = other_cks.encrypt(1);
+    ct_other_dimension.message_modulus = ctxt.message_modulus;
+    ct_other_dimension.carry_modulus = ctxt.carry_modulus;
+    assert!(matches!(
+        sks.validate(&ct_other_dimension),
+        Err(ValidationError::DimensionMismatch)
+    ));
+
+    let mut ct_huge_degree = ctxt.clone();
+    ct_huge_degree.degree = Degree(usize::MAX);
+    assert!(matches!(
+        sks.validate(&ct_huge_degree),
+        Err(ValidationError::DegreeTooHigh)
+    ));
+
+    let mut ct_noisy = ctxt;
+    ct_noisy.noise_level = NoiseLevel(sks.max_noise_level.0 + 1);
+    assert!(matches!(
+        sks.validate(&ct_noisy),
+        Err(ValidationError::NoiseTooHigh)
+    ));
+}
+
+fn shortint_deserialize_ciphertext(param: Parameters) {
+    use bincode::Options;
+
     let keys = KEY_CACHE.get_from_param(param);
     let (cks, sks) = (keys.client_key(), keys.server_key());
 
     let mut rng = rand::thread_rng();
-    let full_mod = (cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0) / 3;
 
-    for _ in 0..NB_TEST {
-        let mut modulus = rng.gen::<u64>() % full_mod as u64;
-        while modulus == 0 {
-            modulus = rng.gen::<u64>() % full_mod as u64;
-        }
+    let modulus = cks.parameters.message_modulus.0 as u64;
 
-        let clear1 = rng.gen::<u64>() % modulus;
-        let clear2 = rng.gen::<u64>() % modulus;
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
 
-        let mut ct1 = cks.encrypt_with_message_modulus(clear1, MessageModulus(modulus as usize));
-        let mut ct2 = cks.encrypt_with_message_modulus(clear2, MessageModulus(modulus as usize));
+        // a lying client claims the ciphertext is empty
+        let mut ctxt = cks.encrypt(clear);
+        ctxt.degree = Degree(0);
+        ctxt.noise_level = NoiseLevel::ZERO;
+        let data = bincode::options().serialize(&ctxt).unwrap();
 
-        println!(
-            "MUL SMALL CARRY:: clear1 = {}, clear2 = {}, mod = {}",
-            clear1, clear2, modulus
-        );
-        let ct_res = sks.unchecked_mul_lsb_small_carry(&mut ct1, &mut ct2);
+        let mut deserializer = bincode::Deserializer::from_slice(&data, bincode::options());
+        let mut ct_res = sks.deserialize_ciphertext(&mut deserializer).unwrap();
         assert_eq!(
-            (clear1 * clear2) % modulus,
-            cks.decrypt_message_and_carry(&ct_res) % modulus
+            ct_res.degree.0,
+            cks.parameters.message_modulus.0 * cks.parameters.carry_modulus.0 - 1
         );
+        assert_eq!(ct_res.noise_level, NoiseLevel::NOMINAL);
 
-        println!(
-            "ADD:: clear1 = {}, clear2 = {}, mod = {}",
-            clear1, clear2, modulus
-        );
-        let ct_res = sks.unchecked_add(&ct1, &ct2);
-        assert_eq!((clear1 + clear2), cks.decrypt_message_and_carry(&ct_res));
+        let ct_other = cks.encrypt(clear);
+        sks.smart_add_assign(&mut ct_res, &mut ct_other.clone());
+        assert_eq!(cks.decrypt(&ct_res), (clear + clear) % modulus);
     }
+
+    // an invalid ciphertext is refused
+    let mut ctxt = cks.encrypt(0);
+    ctxt.degree = Degree(usize::MAX);
+    let data = bincode::options().serialize(&ctxt).unwrap();
+    let mut deserializer = bincode::Deserializer::from_slice(&data, bincode::options());
+    assert!(sks.deserialize_ciphertext(&mut deserializer).is_err());
 }
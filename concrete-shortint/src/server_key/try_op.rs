@@ -0,0 +1,822 @@
+use super::{ServerKey, ShortintError};
+use crate::ciphertext::Degree;
+use crate::engine::ShortintEngine;
+use crate::server_key::DivisionByZeroPolicy;
+use crate::{Ciphertext, LookupTable};
+use concrete_core::prelude::*;
+use rayon::prelude::*;
+
+// # Note:
+// The `try_*` operations validate their ciphertexts with [ServerKey::validate] before handing them
+// to the engine, and forward the errors of the engine instead of panicking on them.
+//
+// Most of them only differ by their signature, and are generated by `impl_try_ops`. Each entry
+// reads `try_op = op(ciphertexts; other arguments) -> output`, optionally followed by
+// `=> engine_op` when the engine names the operation differently, and by
+// `where |server_key| condition` when some scalars must be refused with
+// [ShortintError::InvalidScalar], the key being bound to `server_key` in the condition. The
+// operations of the `engine` section call the engine with the server key, the ones of the
+// `keyless` section without it, and the ones of the `checked` section read
+// `=> check_op, try_unchecked_op`.
+macro_rules! impl_try_ops {
+    (
+        $($section:ident {
+            $(
+                $try_op:ident = $op:ident(
+                    $($ct:ident: $ct_ty:ty),* $(; $($arg:ident: $arg_ty:ty),*)?
+                ) -> $ret:ty $(=> $($target:ident),+)? $(where |$key:tt| $valid:expr)?;
+            )*
+        })*
+    ) => {
+        impl ServerKey {
+            $($(
+                impl_try_ops!(
+                    @$section $try_op, $op, [$($ct: $ct_ty),*], [$($($arg: $arg_ty),*)?], $ret,
+                    [$($($target),+)?], [$(|$key| $valid)?]
+                );
+            )*)*
+        }
+    };
+    (@$section:ident $try_op:ident, $op:ident, $cts:tt, $args:tt, $ret:ty, [], $valid:tt) => {
+        impl_try_ops!(@$section $try_op, $op, $cts, $args, $ret, [$op], $valid);
+    };
+    (
+        @engine $try_op:ident, $op:ident,
+        [$($ct:ident: $ct_ty:ty),*], [$($arg:ident: $arg_ty:ty),*], $ret:ty,
+        [$engine_op:ident], [$(|$key:tt| $valid:expr)?]
+    ) => {
+        #[doc = concat!("Fallible version of [ServerKey::", stringify!($op), "].")]
+        pub fn $try_op(
+            &self,
+            $($ct: $ct_ty,)*
+            $($arg: $arg_ty,)*
+        ) -> Result<$ret, ShortintError> {
+            $(
+                let $key = self;
+                if !($valid) {
+                    return Err(ShortintError::InvalidScalar);
+                }
+            )?
+            $(self.validate($ct)?;)*
+            ShortintEngine::with_thread_local_mut(|engine| {
+                engine.$engine_op(self, $($ct,)* $($arg,)*)
+            })
+            .map_err(ShortintError::Engine)
+        }
+    };
+    (
+        @keyless $try_op:ident, $op:ident,
+        [$($ct:ident: $ct_ty:ty),*], [$($arg:ident: $arg_ty:ty),*], $ret:ty,
+        [$engine_op:ident], [$(|$key:tt| $valid:expr)?]
+    ) => {
+        #[doc = concat!("Fallible version of [ServerKey::", stringify!($op), "].")]
+        pub fn $try_op(
+            &self,
+            $($ct: $ct_ty,)*
+            $($arg: $arg_ty,)*
+        ) -> Result<$ret, ShortintError> {
+            $(
+                let $key = self;
+                if !($valid) {
+                    return Err(ShortintError::InvalidScalar);
+                }
+            )?
+            $(self.validate($ct)?;)*
+            ShortintEngine::with_thread_local_mut(|engine| {
+                engine.$engine_op($($ct,)* $($arg,)*)
+            })
+            .map_err(ShortintError::Engine)
+        }
+    };
+    (
+        @checked $try_op:ident, $op:ident,
+        [$($ct:ident: $ct_ty:ty),*], [$($arg:ident: $arg_ty:ty),*], $ret:ty,
+        [$check_op:ident, $unchecked_op:ident], [$(|$key:tt| $valid:expr)?]
+    ) => {
+        #[doc = concat!("Fallible version of [ServerKey::", stringify!($op), "].")]
+        pub fn $try_op(
+            &self,
+            $($ct: $ct_ty,)*
+            $($arg: $arg_ty,)*
+        ) -> Result<$ret, ShortintError> {
+            $(
+                let $key = self;
+                if !($valid) {
+                    return Err(ShortintError::InvalidScalar);
+                }
+            )?
+            $(self.validate($ct)?;)*
+            self.$check_op($($ct,)* $($arg,)*)?;
+            self.$unchecked_op($($ct,)* $($arg,)*)
+        }
+    };
+}
+
+impl ServerKey {
+    /// Fallible version of [ServerKey::generate_accumulator].
+    pub fn try_generate_accumulator<F>(&self, f: F) -> Result<GlweCiphertext64, ShortintError>
+    where
+        F: Fn(u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| engine.generate_accumulator(self, f))
+            .map_err(ShortintError::Engine)
+    }
+
+    /// Fallible version of [ServerKey::generate_lookup_table].
+    pub fn try_generate_lookup_table<F>(&self, f: F) -> Result<LookupTable, ShortintError>
+    where
+        F: Fn(u64) -> u64,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| engine.generate_lookup_table(self, f))
+            .map_err(ShortintError::Engine)
+    }
+
+    /// Fallible version of [ServerKey::keyswitch_programmable_bootstrap_many].
+    ///
+    /// Returns [ShortintError::LengthMismatch] if the number of accumulators is not the number of
+    /// ciphertexts.
+    pub fn try_keyswitch_programmable_bootstrap_many(
+        &self,
+        cts_in: &[Ciphertext],
+        accs: &[GlweCiphertext64],
+    ) -> Result<Vec<Ciphertext>, ShortintError> {
+        if cts_in.len() != accs.len() {
+            return Err(ShortintError::LengthMismatch);
+        }
+        cts_in.iter().try_for_each(|ct_in| self.validate(ct_in))?;
+        cts_in
+            .par_iter()
+            .zip(accs.par_iter())
+            .map(|(ct_in, acc)| self.try_keyswitch_programmable_bootstrap(ct_in, acc))
+            .collect()
+    }
+
+    /// Fallible version of [ServerKey::apply_lookup_table_many].
+    pub fn try_apply_lookup_table_many<F>(
+        &self,
+        cts_in: &[Ciphertext],
+        f: F,
+    ) -> Result<Vec<Ciphertext>, ShortintError>
+    where
+        F: Fn(u64) -> u64 + Sync,
+    {
+        cts_in.iter().try_for_each(|ct_in| self.validate(ct_in))?;
+        let acc = self.try_generate_accumulator(&f)?;
+        cts_in
+            .par_iter()
+            .map(|ct_in| {
+                let mut ct_res = self.try_keyswitch_programmable_bootstrap(ct_in, &acc)?;
+                ct_res.degree = ct_in.degree.after_pbs(|x| f(x as u64) as usize);
+                Ok(ct_res)
+            })
+            .collect()
+    }
+
+    /// Fallible version of [ServerKey::unchecked_functional_bivariate_pbs].
+    pub fn try_unchecked_functional_bivariate_pbs<F>(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+        f: F,
+    ) -> Result<Ciphertext, ShortintError>
+    where
+        F: Fn(u64) -> u64,
+    {
+        self.validate(ct_left)?;
+        self.validate(ct_right)?;
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_functional_bivariate_pbs(self, ct_left, ct_right, f)
+        })
+        .map_err(ShortintError::Engine)
+    }
+
+    /// Fallible version of [ServerKey::unchecked_functional_bivariate_pbs_assign].
+    pub fn try_unchecked_functional_bivariate_pbs_assign<F>(
+        &self,
+        ct_left: &mut Ciphertext,
+        ct_right: &Ciphertext,
+        f: F,
+    ) -> Result<(), ShortintError>
+    where
+        F: Fn(u64) -> u64,
+    {
+        self.validate(ct_left)?;
+        self.validate(ct_right)?;
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_functional_bivariate_pbs_assign(self, ct_left, ct_right, f)
+        })
+        .map_err(ShortintError::Engine)
+    }
+
+    /// Fallible version of [ServerKey::unchecked_div_rem].
+    pub fn try_unchecked_div_rem(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> Result<(Ciphertext, Ciphertext), ShortintError> {
+        let (quotient, remainder, _) =
+            self.try_unchecked_div_rem_with_policy(ct_left, ct_right, DivisionByZeroPolicy::Zero)?;
+        Ok((quotient, remainder))
+    }
+
+    /// Fallible version of [ServerKey::smart_div_rem].
+    pub fn try_smart_div_rem(
+        &self,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+    ) -> Result<(Ciphertext, Ciphertext), ShortintError> {
+        let (quotient, remainder, _) =
+            self.try_smart_div_rem_with_policy(ct_left, ct_right, DivisionByZeroPolicy::Zero)?;
+        Ok((quotient, remainder))
+    }
+
+    /// Fallible version of [ServerKey::checked_mul_lsb_with_small_carry].
+    pub fn try_checked_mul_lsb_with_small_carry(
+        &self,
+        ct_left: &mut Ciphertext,
+        ct_right: &mut Ciphertext,
+    ) -> Result<Ciphertext, ShortintError> {
+        self.validate(ct_left)?;
+        self.validate(ct_right)?;
+        self.check_mul_small_carry_possible(ct_left, ct_right)?;
+        let mut ct_result = self.try_unchecked_mul_lsb_small_carry(ct_left, ct_right)?;
+        ct_result.degree = Degree(ct_left.degree.0 * 2);
+        Ok(ct_result)
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_apply_lookup_table = apply_lookup_table(ct_in: &Ciphertext; lut: &LookupTable)
+            -> Ciphertext;
+        try_apply_lookup_table_assign = apply_lookup_table_assign(
+            ct_in: &mut Ciphertext; lut: &LookupTable
+        ) -> ();
+        try_apply_lookup_table_multi = apply_lookup_table_multi(
+            ct_in: &Ciphertext; luts: &[LookupTable]
+        ) -> Vec<Ciphertext>;
+        try_keyswitch_bootstrap = keyswitch_bootstrap(ct_in: &Ciphertext) -> Ciphertext;
+        try_keyswitch_bootstrap_assign = keyswitch_bootstrap_assign(ct_in: &mut Ciphertext) -> ();
+        try_keyswitch_programmable_bootstrap = keyswitch_programmable_bootstrap(
+            ct_in: &Ciphertext; acc: &GlweCiphertext64
+        ) -> Ciphertext => programmable_bootstrap_keyswitch;
+        try_keyswitch_programmable_bootstrap_assign = keyswitch_programmable_bootstrap_assign(
+            ct_in: &mut Ciphertext; acc: &GlweCiphertext64
+        ) -> () => programmable_bootstrap_keyswitch_assign;
+        try_carry_extract_assign = carry_extract_assign(ct: &mut Ciphertext) -> ();
+        try_carry_extract = carry_extract(ct: &Ciphertext) -> Ciphertext;
+        try_message_extract_assign = message_extract_assign(ct: &mut Ciphertext) -> ();
+        try_message_extract = message_extract(ct: &Ciphertext) -> Ciphertext;
+        try_message_and_carry_extract = message_and_carry_extract(ct: &Ciphertext)
+            -> (Ciphertext, Ciphertext);
+        try_create_trivial = create_trivial(; value: u8) -> Ciphertext;
+        try_create_trivial_assign = create_trivial_assign(ct: &mut Ciphertext; value: u8) -> ();
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_smart_add = smart_add(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_add_assign = smart_add_assign(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> ();
+    }
+    keyless {
+        try_unchecked_add = unchecked_add(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_add_assign = unchecked_add_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> ();
+    }
+    checked {
+        try_checked_add = checked_add(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_add_possible, try_unchecked_add;
+        try_checked_add_assign = checked_add_assign(ct_left: &mut Ciphertext, ct_right: &Ciphertext)
+            -> () => check_add_possible, try_unchecked_add_assign;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_unchecked_bitand = unchecked_bitand(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_bitand_assign = unchecked_bitand_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> ();
+        try_smart_bitand = smart_bitand(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_bitand_assign = smart_bitand_assign(
+            ct_left: &mut Ciphertext, ct_right: &mut Ciphertext
+        ) -> ();
+        try_unchecked_bitxor = unchecked_bitxor(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_bitxor_assign = unchecked_bitxor_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> ();
+        try_smart_bitxor = smart_bitxor(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_bitxor_assign = smart_bitxor_assign(
+            ct_left: &mut Ciphertext, ct_right: &mut Ciphertext
+        ) -> ();
+        try_unchecked_bitor = unchecked_bitor(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_bitor_assign = unchecked_bitor_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> ();
+        try_smart_bitor = smart_bitor(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_bitor_assign = smart_bitor_assign(
+            ct_left: &mut Ciphertext, ct_right: &mut Ciphertext
+        ) -> ();
+        try_unchecked_scalar_bitand = unchecked_scalar_bitand(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext;
+        try_unchecked_scalar_bitand_assign = unchecked_scalar_bitand_assign(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> ();
+        try_smart_scalar_bitand = smart_scalar_bitand(ct: &mut Ciphertext; scalar: u8)
+            -> Ciphertext;
+        try_smart_scalar_bitand_assign = smart_scalar_bitand_assign(ct: &mut Ciphertext; scalar: u8)
+            -> ();
+        try_unchecked_scalar_bitxor = unchecked_scalar_bitxor(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+        try_unchecked_scalar_bitxor_assign = unchecked_scalar_bitxor_assign(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> () where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+        try_smart_scalar_bitxor = smart_scalar_bitxor(ct: &mut Ciphertext; scalar: u8)
+            -> Ciphertext;
+        try_smart_scalar_bitxor_assign = smart_scalar_bitxor_assign(ct: &mut Ciphertext; scalar: u8)
+            -> ();
+        try_unchecked_scalar_bitor = unchecked_scalar_bitor(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+        try_unchecked_scalar_bitor_assign = unchecked_scalar_bitor_assign(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> () where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+        try_smart_scalar_bitor = smart_scalar_bitor(ct: &mut Ciphertext; scalar: u8) -> Ciphertext;
+        try_smart_scalar_bitor_assign = smart_scalar_bitor_assign(ct: &mut Ciphertext; scalar: u8)
+            -> ();
+    }
+    checked {
+        try_checked_bitand = checked_bitand(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_bitand;
+        try_checked_bitand_assign = checked_bitand_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> () => check_functional_bivariate_pbs_possible, try_unchecked_bitand_assign;
+        try_checked_bitxor = checked_bitxor(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_bitxor;
+        try_checked_bitxor_assign = checked_bitxor_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> () => check_functional_bivariate_pbs_possible, try_unchecked_bitxor_assign;
+        try_checked_bitor = checked_bitor(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_bitor;
+        try_checked_bitor_assign = checked_bitor_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> () => check_functional_bivariate_pbs_possible, try_unchecked_bitor_assign;
+        try_checked_scalar_bitand = checked_scalar_bitand(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext => check_scalar_bitand_possible, try_unchecked_scalar_bitand;
+        try_checked_scalar_bitand_assign = checked_scalar_bitand_assign(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> () => check_scalar_bitand_possible, try_unchecked_scalar_bitand_assign;
+        try_checked_scalar_bitxor = checked_scalar_bitxor(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext => check_scalar_bitxor_possible, try_unchecked_scalar_bitxor
+            where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+        try_checked_scalar_bitxor_assign = checked_scalar_bitxor_assign(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> () => check_scalar_bitxor_possible, try_unchecked_scalar_bitxor_assign
+            where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+        try_checked_scalar_bitor = checked_scalar_bitor(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext => check_scalar_bitor_possible, try_unchecked_scalar_bitor
+            where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+        try_checked_scalar_bitor_assign = checked_scalar_bitor_assign(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> () => check_scalar_bitor_possible, try_unchecked_scalar_bitor_assign
+            where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_unchecked_greater = unchecked_greater(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_smart_greater = smart_greater(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_unchecked_greater_or_equal = unchecked_greater_or_equal(
+            ct_left: &Ciphertext, ct_right: &Ciphertext
+        ) -> Ciphertext;
+        try_smart_greater_or_equal = smart_greater_or_equal(
+            ct_left: &mut Ciphertext, ct_right: &mut Ciphertext
+        ) -> Ciphertext;
+        try_unchecked_less = unchecked_less(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_smart_less = smart_less(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_unchecked_less_or_equal = unchecked_less_or_equal(
+            ct_left: &Ciphertext, ct_right: &Ciphertext
+        ) -> Ciphertext;
+        try_smart_less_or_equal = smart_less_or_equal(
+            ct_left: &mut Ciphertext, ct_right: &mut Ciphertext
+        ) -> Ciphertext;
+        try_unchecked_equal = unchecked_equal(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_smart_equal = smart_equal(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_scalar_equal = smart_scalar_equal(ct_left: &Ciphertext; scalar: u8) -> Ciphertext;
+        try_smart_scalar_greater_or_equal = smart_scalar_greater_or_equal(
+            ct_left: &Ciphertext; scalar: u8
+        ) -> Ciphertext;
+        try_smart_scalar_less_or_equal = smart_scalar_less_or_equal(
+            ct_left: &Ciphertext; scalar: u8
+        ) -> Ciphertext;
+        try_smart_scalar_greater = smart_scalar_greater(ct_left: &Ciphertext; scalar: u8)
+            -> Ciphertext;
+        try_smart_scalar_less = smart_scalar_less(ct_left: &Ciphertext; scalar: u8) -> Ciphertext;
+    }
+    checked {
+        try_checked_greater = checked_greater(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_greater;
+        try_checked_greater_or_equal = checked_greater_or_equal(
+            ct_left: &Ciphertext, ct_right: &Ciphertext
+        ) -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_greater_or_equal;
+        try_checked_less = checked_less(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_less;
+        try_checked_less_or_equal = checked_less_or_equal(
+            ct_left: &Ciphertext, ct_right: &Ciphertext
+        ) -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_less_or_equal;
+        try_checked_equal = checked_equal(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_equal;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_unchecked_div = unchecked_div(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_div_assign = unchecked_div_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> ();
+        try_smart_div = smart_div(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_div_assign = smart_div_assign(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> ();
+        try_unchecked_rem = unchecked_rem(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_rem_assign = unchecked_rem_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> ();
+        try_smart_rem = smart_rem(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_rem_assign = smart_rem_assign(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> ();
+        try_unchecked_div_rem_with_policy = unchecked_div_rem_with_policy(
+            ct_left: &Ciphertext, ct_right: &Ciphertext; policy: DivisionByZeroPolicy
+        ) -> (Ciphertext, Ciphertext, Option<Ciphertext>);
+        try_smart_div_rem_with_policy = smart_div_rem_with_policy(
+            ct_left: &mut Ciphertext, ct_right: &mut Ciphertext; policy: DivisionByZeroPolicy
+        ) -> (Ciphertext, Ciphertext, Option<Ciphertext>);
+        try_unchecked_scalar_div = unchecked_scalar_div(ct_left: &Ciphertext; scalar: u8)
+            -> Ciphertext where |_| scalar != 0;
+        try_unchecked_scalar_div_assign = unchecked_scalar_div_assign(
+            ct_left: &mut Ciphertext; scalar: u8
+        ) -> () where |_| scalar != 0;
+        try_unchecked_scalar_mod = unchecked_scalar_mod(ct_left: &Ciphertext; modulus: u8)
+            -> Ciphertext where |_| modulus != 0;
+        try_unchecked_scalar_mod_assign = unchecked_scalar_mod_assign(
+            ct_left: &mut Ciphertext; modulus: u8
+        ) -> () where |_| modulus != 0;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_unchecked_min = unchecked_min(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_min_assign = unchecked_min_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> ();
+        try_smart_min = smart_min(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_min_assign = smart_min_assign(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> ();
+        try_unchecked_max = unchecked_max(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_max_assign = unchecked_max_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> ();
+        try_smart_max = smart_max(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_max_assign = smart_max_assign(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> ();
+        try_unchecked_scalar_min = unchecked_scalar_min(ct: &Ciphertext; scalar: u8) -> Ciphertext;
+        try_unchecked_scalar_min_assign = unchecked_scalar_min_assign(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> ();
+        try_smart_scalar_min = smart_scalar_min(ct: &mut Ciphertext; scalar: u8) -> Ciphertext;
+        try_smart_scalar_min_assign = smart_scalar_min_assign(ct: &mut Ciphertext; scalar: u8)
+            -> ();
+        try_unchecked_scalar_max = unchecked_scalar_max(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+        try_unchecked_scalar_max_assign = unchecked_scalar_max_assign(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> () where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+        try_smart_scalar_max = smart_scalar_max(ct: &mut Ciphertext; scalar: u8) -> Ciphertext;
+        try_smart_scalar_max_assign = smart_scalar_max_assign(ct: &mut Ciphertext; scalar: u8)
+            -> ();
+    }
+    checked {
+        try_checked_min = checked_min(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_min;
+        try_checked_min_assign = checked_min_assign(ct_left: &mut Ciphertext, ct_right: &Ciphertext)
+            -> () => check_functional_bivariate_pbs_possible, try_unchecked_min_assign;
+        try_checked_max = checked_max(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_max;
+        try_checked_max_assign = checked_max_assign(ct_left: &mut Ciphertext, ct_right: &Ciphertext)
+            -> () => check_functional_bivariate_pbs_possible, try_unchecked_max_assign;
+        try_checked_scalar_min = checked_scalar_min(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext => check_scalar_min_possible, try_unchecked_scalar_min;
+        try_checked_scalar_min_assign = checked_scalar_min_assign(ct: &mut Ciphertext; scalar: u8)
+            -> () => check_scalar_min_possible, try_unchecked_scalar_min_assign;
+        try_checked_scalar_max = checked_scalar_max(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext => check_scalar_max_possible, try_unchecked_scalar_max
+            where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+        try_checked_scalar_max_assign = checked_scalar_max_assign(ct: &mut Ciphertext; scalar: u8)
+            -> () => check_scalar_max_possible, try_unchecked_scalar_max_assign
+            where |server_key| usize::from(scalar) < server_key.message_modulus.0;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_unchecked_mul_lsb = unchecked_mul_lsb(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_mul_lsb_assign = unchecked_mul_lsb_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> ();
+        try_unchecked_mul_msb = unchecked_mul_msb(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_mul_msb_assign = unchecked_mul_msb_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> ();
+        try_unchecked_mul_lsb_small_carry = unchecked_mul_lsb_small_carry(
+            ct_left: &mut Ciphertext, ct_right: &mut Ciphertext
+        ) -> Ciphertext => unchecked_mul_lsb_small_carry_modulus;
+        try_unchecked_mul_lsb_small_carry_assign = unchecked_mul_lsb_small_carry_assign(
+            ct_left: &mut Ciphertext, ct_right: &mut Ciphertext
+        ) -> () => unchecked_mul_lsb_small_carry_modulus_assign;
+        try_smart_mul_lsb_assign = smart_mul_lsb_assign(
+            ct_left: &mut Ciphertext, ct_right: &mut Ciphertext
+        ) -> ();
+        try_smart_mul_msb_assign = smart_mul_msb_assign(
+            ct_left: &mut Ciphertext, ct_right: &mut Ciphertext
+        ) -> ();
+        try_smart_mul_lsb = smart_mul_lsb(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_mul_msb = smart_mul_msb(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+    }
+    checked {
+        try_checked_mul_lsb = checked_mul_lsb(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_mul_possible, try_unchecked_mul_lsb;
+        try_checked_mul_lsb_assign = checked_mul_lsb_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> () => check_mul_possible, try_unchecked_mul_lsb_assign;
+        try_checked_mul_msb = checked_mul_msb(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_mul_possible, try_unchecked_mul_msb;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_unchecked_neg = unchecked_neg(ct: &Ciphertext) -> Ciphertext;
+        try_unchecked_neg_with_z = unchecked_neg_with_z(ct: &Ciphertext) -> (Ciphertext, u64);
+        try_unchecked_neg_assign = unchecked_neg_assign(ct: &mut Ciphertext) -> ();
+        try_unchecked_neg_assign_with_z = unchecked_neg_assign_with_z(ct: &mut Ciphertext) -> u64;
+        try_smart_neg = smart_neg(ct: &mut Ciphertext) -> Ciphertext;
+        try_smart_neg_assign = smart_neg_assign(ct: &mut Ciphertext) -> ();
+    }
+    checked {
+        try_checked_neg = checked_neg(ct: &Ciphertext)
+            -> Ciphertext => check_neg_possible, try_unchecked_neg;
+        try_checked_neg_assign = checked_neg_assign(ct: &mut Ciphertext)
+            -> () => check_neg_possible, try_unchecked_neg_assign;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_unchecked_scalar_rotate_left = unchecked_scalar_rotate_left(ct: &Ciphertext; amount: u8)
+            -> Ciphertext;
+        try_unchecked_scalar_rotate_left_assign = unchecked_scalar_rotate_left_assign(
+            ct: &mut Ciphertext; amount: u8
+        ) -> ();
+        try_unchecked_scalar_rotate_right = unchecked_scalar_rotate_right(
+            ct: &Ciphertext; amount: u8
+        ) -> Ciphertext;
+        try_unchecked_scalar_rotate_right_assign = unchecked_scalar_rotate_right_assign(
+            ct: &mut Ciphertext; amount: u8
+        ) -> ();
+        try_smart_scalar_rotate_left = smart_scalar_rotate_left(ct: &mut Ciphertext; amount: u8)
+            -> Ciphertext;
+        try_smart_scalar_rotate_left_assign = smart_scalar_rotate_left_assign(
+            ct: &mut Ciphertext; amount: u8
+        ) -> ();
+        try_smart_scalar_rotate_right = smart_scalar_rotate_right(ct: &mut Ciphertext; amount: u8)
+            -> Ciphertext;
+        try_smart_scalar_rotate_right_assign = smart_scalar_rotate_right_assign(
+            ct: &mut Ciphertext; amount: u8
+        ) -> ();
+        try_unchecked_rotate_left = unchecked_rotate_left(ct: &Ciphertext, ct_amount: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_rotate_left_assign = unchecked_rotate_left_assign(
+            ct: &mut Ciphertext, ct_amount: &Ciphertext
+        ) -> ();
+        try_smart_rotate_left = smart_rotate_left(ct: &mut Ciphertext, ct_amount: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_rotate_left_assign = smart_rotate_left_assign(
+            ct: &mut Ciphertext, ct_amount: &mut Ciphertext
+        ) -> ();
+        try_unchecked_rotate_right = unchecked_rotate_right(ct: &Ciphertext, ct_amount: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_rotate_right_assign = unchecked_rotate_right_assign(
+            ct: &mut Ciphertext, ct_amount: &Ciphertext
+        ) -> ();
+        try_smart_rotate_right = smart_rotate_right(ct: &mut Ciphertext, ct_amount: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_rotate_right_assign = smart_rotate_right_assign(
+            ct: &mut Ciphertext, ct_amount: &mut Ciphertext
+        ) -> ();
+    }
+    checked {
+        try_checked_rotate_left = checked_rotate_left(ct: &Ciphertext, ct_amount: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_rotate_left;
+        try_checked_rotate_left_assign = checked_rotate_left_assign(
+            ct: &mut Ciphertext, ct_amount: &Ciphertext
+        ) -> () => check_functional_bivariate_pbs_possible, try_unchecked_rotate_left_assign;
+        try_checked_rotate_right = checked_rotate_right(ct: &Ciphertext, ct_amount: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_rotate_right;
+        try_checked_rotate_right_assign = checked_rotate_right_assign(
+            ct: &mut Ciphertext, ct_amount: &Ciphertext
+        ) -> () => check_functional_bivariate_pbs_possible, try_unchecked_rotate_right_assign;
+        try_checked_scalar_rotate_left = checked_scalar_rotate_left(ct: &Ciphertext; amount: u8)
+            -> Ciphertext => check_scalar_rotate_left_possible, try_unchecked_scalar_rotate_left;
+        try_checked_scalar_rotate_left_assign = checked_scalar_rotate_left_assign(
+            ct: &mut Ciphertext; amount: u8
+        ) -> () => check_scalar_rotate_left_possible, try_unchecked_scalar_rotate_left_assign;
+        try_checked_scalar_rotate_right = checked_scalar_rotate_right(ct: &Ciphertext; amount: u8)
+            -> Ciphertext => check_scalar_rotate_right_possible, try_unchecked_scalar_rotate_right;
+        try_checked_scalar_rotate_right_assign = checked_scalar_rotate_right_assign(
+            ct: &mut Ciphertext; amount: u8
+        ) -> () => check_scalar_rotate_right_possible, try_unchecked_scalar_rotate_right_assign;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_unchecked_scalar_add_assign_crt = unchecked_scalar_add_assign_crt(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> ();
+        try_smart_scalar_add = smart_scalar_add(ct: &mut Ciphertext; scalar: u8) -> Ciphertext;
+        try_smart_scalar_add_assign = smart_scalar_add_assign(ct: &mut Ciphertext; scalar: u8)
+            -> ();
+    }
+    keyless {
+        try_unchecked_scalar_add = unchecked_scalar_add(ct: &Ciphertext; scalar: u8) -> Ciphertext;
+        try_unchecked_scalar_add_assign = unchecked_scalar_add_assign(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> ();
+    }
+    checked {
+        try_checked_scalar_add = checked_scalar_add(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext => check_scalar_add_possible, try_unchecked_scalar_add;
+        try_checked_scalar_add_assign = checked_scalar_add_assign(ct: &mut Ciphertext; scalar: u8)
+            -> () => check_scalar_add_possible, try_unchecked_scalar_add_assign;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_smart_scalar_mul = smart_scalar_mul(ct: &mut Ciphertext; scalar: u8) -> Ciphertext;
+        try_smart_scalar_mul_assign = smart_scalar_mul_assign(ct: &mut Ciphertext; scalar: u8)
+            -> ();
+    }
+    keyless {
+        try_unchecked_scalar_mul = unchecked_scalar_mul(ct: &Ciphertext; scalar: u8) -> Ciphertext;
+        try_unchecked_scalar_mul_assign = unchecked_scalar_mul_assign(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> ();
+    }
+    checked {
+        try_checked_scalar_mul = checked_scalar_mul(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext => check_scalar_mul_possible, try_unchecked_scalar_mul;
+        try_checked_scalar_mul_assign = checked_scalar_mul_assign(ct: &mut Ciphertext; scalar: u8)
+            -> () => check_scalar_mul_possible, try_unchecked_scalar_mul_assign;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_smart_scalar_sub = smart_scalar_sub(ct: &mut Ciphertext; scalar: u8) -> Ciphertext;
+        try_smart_scalar_sub_assign = smart_scalar_sub_assign(ct: &mut Ciphertext; scalar: u8)
+            -> ();
+    }
+    keyless {
+        try_unchecked_scalar_sub = unchecked_scalar_sub(ct: &Ciphertext; scalar: u8) -> Ciphertext;
+        try_unchecked_scalar_sub_assign = unchecked_scalar_sub_assign(
+            ct: &mut Ciphertext; scalar: u8
+        ) -> ();
+    }
+    checked {
+        try_checked_scalar_sub = checked_scalar_sub(ct: &Ciphertext; scalar: u8)
+            -> Ciphertext => check_scalar_sub_possible, try_unchecked_scalar_sub;
+        try_checked_scalar_sub_assign = checked_scalar_sub_assign(ct: &mut Ciphertext; scalar: u8)
+            -> () => check_scalar_sub_possible, try_unchecked_scalar_sub_assign;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_unchecked_scalar_right_shift = unchecked_scalar_right_shift(ct: &Ciphertext; shift: u8)
+            -> Ciphertext where |_| u32::from(shift) < u8::BITS;
+        try_unchecked_scalar_right_shift_assign = unchecked_scalar_right_shift_assign(
+            ct: &mut Ciphertext; shift: u8
+        ) -> () where |_| u32::from(shift) < u8::BITS;
+        try_smart_scalar_left_shift = smart_scalar_left_shift(ct: &mut Ciphertext; shift: u8)
+            -> Ciphertext where |_| u32::from(shift) < u8::BITS;
+        try_smart_scalar_left_shift_assign = smart_scalar_left_shift_assign(
+            ct: &mut Ciphertext; shift: u8
+        ) -> () where |_| u32::from(shift) < u8::BITS;
+        try_unchecked_shift_left = unchecked_shift_left(ct: &Ciphertext, ct_amount: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_shift_left_assign = unchecked_shift_left_assign(
+            ct: &mut Ciphertext, ct_amount: &Ciphertext
+        ) -> ();
+        try_smart_shift_left = smart_shift_left(ct: &mut Ciphertext, ct_amount: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_shift_left_assign = smart_shift_left_assign(
+            ct: &mut Ciphertext, ct_amount: &mut Ciphertext
+        ) -> ();
+        try_unchecked_shift_right = unchecked_shift_right(ct: &Ciphertext, ct_amount: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_shift_right_assign = unchecked_shift_right_assign(
+            ct: &mut Ciphertext, ct_amount: &Ciphertext
+        ) -> ();
+        try_smart_shift_right = smart_shift_right(ct: &mut Ciphertext, ct_amount: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_shift_right_assign = smart_shift_right_assign(
+            ct: &mut Ciphertext, ct_amount: &mut Ciphertext
+        ) -> ();
+    }
+    keyless {
+        try_unchecked_scalar_left_shift = unchecked_scalar_left_shift(ct: &Ciphertext; shift: u8)
+            -> Ciphertext where |_| u32::from(shift) < u8::BITS;
+        try_unchecked_scalar_left_shift_assign = unchecked_scalar_left_shift_assign(
+            ct: &mut Ciphertext; shift: u8
+        ) -> () where |_| u32::from(shift) < u8::BITS;
+    }
+    checked {
+        try_checked_scalar_left_shift = checked_scalar_left_shift(ct: &Ciphertext; shift: u8)
+            -> Ciphertext => check_scalar_left_shift_possible, try_unchecked_scalar_left_shift
+            where |_| u32::from(shift) < u8::BITS;
+        try_checked_scalar_left_shift_assign = checked_scalar_left_shift_assign(
+            ct: &mut Ciphertext; shift: u8
+        ) -> () => check_scalar_left_shift_possible, try_unchecked_scalar_left_shift_assign
+            where |_| u32::from(shift) < u8::BITS;
+        try_checked_shift_left = checked_shift_left(ct: &Ciphertext, ct_amount: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_shift_left;
+        try_checked_shift_left_assign = checked_shift_left_assign(
+            ct: &mut Ciphertext, ct_amount: &Ciphertext
+        ) -> () => check_functional_bivariate_pbs_possible, try_unchecked_shift_left_assign;
+        try_checked_shift_right = checked_shift_right(ct: &Ciphertext, ct_amount: &Ciphertext)
+            -> Ciphertext => check_functional_bivariate_pbs_possible, try_unchecked_shift_right;
+        try_checked_shift_right_assign = checked_shift_right_assign(
+            ct: &mut Ciphertext, ct_amount: &Ciphertext
+        ) -> () => check_functional_bivariate_pbs_possible, try_unchecked_shift_right_assign;
+    }
+}
+
+impl_try_ops! {
+    engine {
+        try_unchecked_sub = unchecked_sub(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext;
+        try_unchecked_sub_assign = unchecked_sub_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> ();
+        try_smart_sub = smart_sub(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> Ciphertext;
+        try_smart_sub_assign = smart_sub_assign(ct_left: &mut Ciphertext, ct_right: &mut Ciphertext)
+            -> ();
+        try_unchecked_sub_with_correcting_term = unchecked_sub_with_correcting_term(
+            ct_left: &Ciphertext, ct_right: &Ciphertext
+        ) -> (Ciphertext, u64) => unchecked_sub_with_z;
+        try_unchecked_sub_with_correcting_term_assign = unchecked_sub_with_correcting_term_assign(
+            ct_left: &mut Ciphertext, ct_right: &Ciphertext
+        ) -> u64 => unchecked_sub_assign_with_z;
+        try_smart_sub_with_correcting_term = smart_sub_with_correcting_term(
+            ct_left: &mut Ciphertext, ct_right: &mut Ciphertext
+        ) -> (Ciphertext, u64) => smart_sub_with_z;
+    }
+    checked {
+        try_checked_sub = checked_sub(ct_left: &Ciphertext, ct_right: &Ciphertext)
+            -> Ciphertext => check_sub_possible, try_unchecked_sub;
+        try_checked_sub_assign = checked_sub_assign(ct_left: &mut Ciphertext, ct_right: &Ciphertext)
+            -> () => check_sub_possible, try_unchecked_sub_assign;
+    }
+}
@@ -245,6 +245,113 @@ where
         }
     }
 
+    pub(crate) fn smart_scalar_bitand(
+        &self,
+        lhs: &GenericShortInt<P>,
+        rhs: u8,
+    ) -> GenericShortInt<P> {
+        let ciphertext = self
+            .key
+            .smart_scalar_bitand(&mut lhs.ciphertext.borrow_mut(), rhs);
+        GenericShortInt {
+            ciphertext: RefCell::new(ciphertext),
+            id: lhs.id,
+        }
+    }
+
+    pub(crate) fn smart_scalar_bitand_assign(&self, lhs: &mut GenericShortInt<P>, rhs: u8) {
+        self.key
+            .smart_scalar_bitand_assign(&mut lhs.ciphertext.borrow_mut(), rhs)
+    }
+
+    pub(crate) fn smart_scalar_bitor(
+        &self,
+        lhs: &GenericShortInt<P>,
+        rhs: u8,
+    ) -> GenericShortInt<P> {
+        let ciphertext = self
+            .key
+            .smart_scalar_bitor(&mut lhs.ciphertext.borrow_mut(), rhs);
+        GenericShortInt {
+            ciphertext: RefCell::new(ciphertext),
+            id: lhs.id,
+        }
+    }
+
+    pub(crate) fn smart_scalar_bitor_assign(&self, lhs: &mut GenericShortInt<P>, rhs: u8) {
+        self.key
+            .smart_scalar_bitor_assign(&mut lhs.ciphertext.borrow_mut(), rhs)
+    }
+
+    pub(crate) fn smart_scalar_bitxor(
+        &self,
+        lhs: &GenericShortInt<P>,
+        rhs: u8,
+    ) -> GenericShortInt<P> {
+        let ciphertext = self
+            .key
+            .smart_scalar_bitxor(&mut lhs.ciphertext.borrow_mut(), rhs);
+        GenericShortInt {
+            ciphertext: RefCell::new(ciphertext),
+            id: lhs.id,
+        }
+    }
+
+    pub(crate) fn smart_scalar_bitxor_assign(&self, lhs: &mut GenericShortInt<P>, rhs: u8) {
+        self.key
+            .smart_scalar_bitxor_assign(&mut lhs.ciphertext.borrow_mut(), rhs)
+    }
+
+    pub(crate) fn smart_min(
+        &self,
+        lhs: &GenericShortInt<P>,
+        rhs: &GenericShortInt<P>,
+    ) -> GenericShortInt<P> {
+        let ciphertext = self.key.smart_min(
+            &mut lhs.ciphertext.borrow_mut(),
+            &mut rhs.ciphertext.borrow_mut(),
+        );
+        GenericShortInt {
+            ciphertext: RefCell::new(ciphertext),
+            id: lhs.id,
+        }
+    }
+
+    pub(crate) fn smart_scalar_min(&self, lhs: &GenericShortInt<P>, rhs: u8) -> GenericShortInt<P> {
+        let ciphertext = self
+            .key
+            .smart_scalar_min(&mut lhs.ciphertext.borrow_mut(), rhs);
+        GenericShortInt {
+            ciphertext: RefCell::new(ciphertext),
+            id: lhs.id,
+        }
+    }
+
+    pub(crate) fn smart_max(
+        &self,
+        lhs: &GenericShortInt<P>,
+        rhs: &GenericShortInt<P>,
+    ) -> GenericShortInt<P> {
+        let ciphertext = self.key.smart_max(
+            &mut lhs.ciphertext.borrow_mut(),
+            &mut rhs.ciphertext.borrow_mut(),
+        );
+        GenericShortInt {
+            ciphertext: RefCell::new(ciphertext),
+            id: lhs.id,
+        }
+    }
+
+    pub(crate) fn smart_scalar_max(&self, lhs: &GenericShortInt<P>, rhs: u8) -> GenericShortInt<P> {
+        let ciphertext = self
+            .key
+            .smart_scalar_max(&mut lhs.ciphertext.borrow_mut(), rhs);
+        GenericShortInt {
+            ciphertext: RefCell::new(ciphertext),
+            id: lhs.id,
+        }
+    }
+
     pub(crate) fn smart_less(
         &self,
         lhs: &GenericShortInt<P>,
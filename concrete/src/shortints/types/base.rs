@@ -227,6 +227,52 @@ where
         self.id
             .with_unwrapped_global_mut(|server_key| server_key.bivariate_pbs(self, other, func))
     }
+
+    /// Returns the minimum between `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "shortints")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete::prelude::*;
+    /// use concrete::{generate_keys, set_server_key, ConfigBuilder, FheUint2};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_uint2().build();
+    /// let (keys, server_keys) = generate_keys(config);
+    /// set_server_key(server_keys);
+    ///
+    /// let a = FheUint2::try_encrypt(1, &keys)?;
+    /// let b = FheUint2::try_encrypt(3, &keys)?;
+    ///
+    /// let c = a.min(&b);
+    /// let decrypted: u8 = c.decrypt(&keys);
+    /// assert_eq!(decrypted, 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn min(&self, other: &Self) -> Self {
+        self.id
+            .with_unwrapped_global_mut(|server_key| server_key.smart_min(self, other))
+    }
+
+    /// Returns the maximum between `self` and `other`.
+    pub fn max(&self, other: &Self) -> Self {
+        self.id
+            .with_unwrapped_global_mut(|server_key| server_key.smart_max(self, other))
+    }
+
+    /// Returns the minimum between `self` and a clear scalar.
+    pub fn scalar_min(&self, scalar: u8) -> Self {
+        self.id
+            .with_unwrapped_global_mut(|server_key| server_key.smart_scalar_min(self, scalar))
+    }
+
+    /// Returns the maximum between `self` and a clear scalar.
+    pub fn scalar_max(&self, scalar: u8) -> Self {
+        self.id
+            .with_unwrapped_global_mut(|server_key| server_key.smart_scalar_max(self, scalar))
+    }
 }
 
 impl<P> FheOrd<u8> for GenericShortInt<P>
@@ -732,7 +778,13 @@ short_int_impl_scalar_operation!(Div(div) => unchecked_scalar_div);
 short_int_impl_scalar_operation!(Rem(rem) => unchecked_scalar_mod);
 short_int_impl_scalar_operation!(Shl(shl) => smart_scalar_left_shift);
 short_int_impl_scalar_operation!(Shr(shr) => unchecked_scalar_right_shift);
+short_int_impl_scalar_operation!(BitAnd(bitand) => smart_scalar_bitand);
+short_int_impl_scalar_operation!(BitOr(bitor) => smart_scalar_bitor);
+short_int_impl_scalar_operation!(BitXor(bitxor) => smart_scalar_bitxor);
 
 short_int_impl_scalar_operation_assign!(AddAssign(add_assign) => smart_scalar_add_assign);
 short_int_impl_scalar_operation_assign!(SubAssign(sub_assign) => smart_scalar_sub_assign);
 short_int_impl_scalar_operation_assign!(MulAssign(mul_assign) => smart_scalar_mul_assign);
+short_int_impl_scalar_operation_assign!(BitAndAssign(bitand_assign) => smart_scalar_bitand_assign);
+short_int_impl_scalar_operation_assign!(BitOrAssign(bitor_assign) => smart_scalar_bitor_assign);
+short_int_impl_scalar_operation_assign!(BitXorAssign(bitxor_assign) => smart_scalar_bitxor_assign);
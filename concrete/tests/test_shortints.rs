@@ -189,6 +189,41 @@ fn test_branch_less_min_max() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_min_max() -> Result<(), Box<dyn std::error::Error>> {
+    let config = ConfigBuilder::all_disabled().enable_default_uint3().build();
+    let (keys, server_keys) = generate_keys(config);
+    set_server_key(server_keys);
+
+    let x = FheUint3::try_encrypt(6, &keys)?;
+    let y = FheUint3::try_encrypt(3, &keys)?;
+
+    assert_eq!(x.min(&y).decrypt(&keys), 3);
+    assert_eq!(x.max(&y).decrypt(&keys), 6);
+    assert_eq!(x.scalar_min(4).decrypt(&keys), 4);
+    assert_eq!(y.scalar_max(4).decrypt(&keys), 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_scalar_bitwise_fhe_uint3() -> Result<(), Box<dyn std::error::Error>> {
+    let config = ConfigBuilder::all_disabled().enable_default_uint3().build();
+    let (keys, server_keys) = generate_keys(config);
+    set_server_key(server_keys);
+
+    let mut a = FheUint3::try_encrypt(6, &keys)?;
+
+    assert_eq!((&a & 3u8).decrypt(&keys), 6 & 3);
+    assert_eq!((&a | 3u8).decrypt(&keys), 6 | 3);
+    assert_eq!((&a ^ 3u8).decrypt(&keys), 6 ^ 3);
+
+    a ^= 5u8;
+    assert_eq!(a.decrypt(&keys), 6 ^ 5);
+
+    Ok(())
+}
+
 mod dynamic {
     use concrete::prelude::*;
     use concrete::{generate_keys, set_server_key, ConfigBuilder, FheUint3Parameters};